@@ -0,0 +1,49 @@
+//! Times `Interval::contains` against the comparison it replaced, which
+//! built a temporary `BoundPoint::at(value)` and compared whole points.
+//!
+//! Run with `cargo bench --bench contains`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use my_interval::{BoundPoint, Interval, IntervalType};
+
+fn contains_via_bound_point(interval: &Interval<u64>, value: u64) -> bool {
+    let point = BoundPoint::at(value);
+    *interval.start_point() <= point && *interval.end_point() >= point
+}
+
+fn hits(values: &[u64], f: impl Fn(u64) -> bool) -> u64 {
+    values.iter().map(|&value| f(value) as u64).sum()
+}
+
+fn bench_contains(c: &mut Criterion) {
+    let values: Vec<u64> = (0..1000).collect();
+    let intervals = [
+        (
+            "[250, 750)",
+            Interval::from_to(250, 750, IntervalType::EndOpen).unwrap(),
+        ),
+        (
+            "(250, 750]",
+            Interval::from_to(250, 750, IntervalType::StartOpen).unwrap(),
+        ),
+        ("[250, +inf)", Interval::since_inclusive(250)),
+        ("(-inf, 750)", Interval::until_exclusive(750)),
+    ];
+    for (name, interval) in &intervals {
+        let mut group = c.benchmark_group(*name);
+        group.bench_function("contains", |b| {
+            b.iter(|| hits(black_box(&values), |value| interval.contains(value)))
+        });
+        group.bench_function("via BoundPoint", |b| {
+            b.iter(|| {
+                hits(black_box(&values), |value| {
+                    contains_via_bound_point(interval, value)
+                })
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_contains);
+criterion_main!(benches);
@@ -0,0 +1,176 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::Interval;
+use std::fmt::{self, Display, Formatter};
+
+/// Error returned by [`bins_from_breakpoints`] and [`Binner::new`] when
+/// there are fewer than two breakpoints or they are not strictly
+/// increasing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidBreakpointsError;
+
+impl Display for InvalidBreakpointsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "need at least two strictly increasing breakpoints")
+    }
+}
+
+impl std::error::Error for InvalidBreakpointsError {}
+
+/// Builds the histogram bins `[b0, b1)`, `[b1, b2)`, …, `[b(n-1), bn]`
+/// between consecutive breakpoints, the last one closed so that the
+/// last breakpoint falls into a bin.
+pub fn bins_from_breakpoints<T: Ord + Clone>(
+    breakpoints: Vec<T>,
+) -> Result<Vec<Interval<T>>, InvalidBreakpointsError> {
+    if breakpoints.len() < 2 || breakpoints.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(InvalidBreakpointsError);
+    }
+    let mut bins = Vec::with_capacity(breakpoints.len() - 1);
+    let mut breakpoints = breakpoints.into_iter().peekable();
+    while let (Some(start), Some(end)) = (breakpoints.next(), breakpoints.peek()) {
+        bins.push(Interval {
+            start: BoundPoint::at(start),
+            end: BoundPoint::before(end.clone()),
+        });
+    }
+    if let Some(last) = bins.last_mut() {
+        last.end = BoundPoint::at(last.end.finite_value().unwrap().clone());
+    }
+    Ok(bins)
+}
+
+/// Maps values to the histogram bins of [`bins_from_breakpoints`] in
+/// `O(log n)`.
+#[derive(Debug, Clone)]
+pub struct Binner<T>
+where
+    T: Ord,
+{
+    bins: Vec<Interval<T>>,
+}
+
+/// Where a value falls relative to the bins.
+enum Position {
+    Below,
+    In(usize),
+    Above,
+}
+
+impl<T: Ord + Clone> Binner<T> {
+    pub fn new(breakpoints: Vec<T>) -> Result<Self, InvalidBreakpointsError> {
+        Ok(Binner {
+            bins: bins_from_breakpoints(breakpoints)?,
+        })
+    }
+}
+
+impl<T: Ord> Binner<T> {
+    /// Returns the bins, in order.
+    pub fn bins(&self) -> &[Interval<T>] {
+        &self.bins
+    }
+
+    /// Returns the index of the bin containing `value`, or `None` if it
+    /// is below the first or above the last breakpoint.
+    pub fn bin_index(&self, value: &T) -> Option<usize> {
+        match self.position(value) {
+            Position::In(index) => Some(index),
+            Position::Below | Position::Above => None,
+        }
+    }
+
+    /// Counts the values falling into each bin, ignoring those outside
+    /// all bins.
+    pub fn bin_counts<I: IntoIterator<Item = T>>(&self, values: I) -> Vec<usize> {
+        self.bin_counts_with_overflow(values).1
+    }
+
+    /// Counts the values falling into each bin, along with the number of
+    /// values below the first breakpoint and above the last one, as
+    /// `(below, counts, above)`.
+    pub fn bin_counts_with_overflow<I: IntoIterator<Item = T>>(
+        &self,
+        values: I,
+    ) -> (usize, Vec<usize>, usize) {
+        let mut below = 0;
+        let mut counts = vec![0; self.bins.len()];
+        let mut above = 0;
+        for value in values {
+            match self.position(&value) {
+                Position::Below => below += 1,
+                Position::In(index) => counts[index] += 1,
+                Position::Above => above += 1,
+            }
+        }
+        (below, counts, above)
+    }
+
+    fn position(&self, value: &T) -> Position {
+        let index = self
+            .bins
+            .partition_point(|bin| bin.end.cmp_value(value).is_lt());
+        match self.bins.get(index) {
+            None => Position::Above,
+            Some(bin) if bin.contains_value(value) => Position::In(index),
+            // the bins leave no gaps, so only the first can be missed
+            Some(_) => Position::Below,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::end_open;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![1, 2], vec![Interval::from_to(1, 2, IntervalType::Close).unwrap()])]
+    #[case(vec![0, 10, 20, 30], vec![end_open(0, 10), end_open(10, 20), Interval::from_to(20, 30, IntervalType::Close).unwrap()])]
+    fn test_bins_from_breakpoints(
+        #[case] breakpoints: Vec<i32>,
+        #[case] expected: Vec<Interval<i32>>,
+    ) {
+        assert_eq!(bins_from_breakpoints(breakpoints), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec![1])]
+    #[case(vec![0, 10, 10, 20])]
+    #[case(vec![0, 20, 10])]
+    #[case(vec![5, 5])]
+    fn test_invalid_breakpoints(#[case] breakpoints: Vec<i32>) {
+        assert_eq!(
+            bins_from_breakpoints(breakpoints.clone()),
+            Err(InvalidBreakpointsError)
+        );
+        assert!(Binner::new(breakpoints).is_err());
+    }
+
+    #[rstest]
+    #[case(-1, None)]
+    #[case(0, Some(0))]
+    #[case(9, Some(0))]
+    #[case(10, Some(1))]
+    #[case(25, Some(2))]
+    // the last bin is closed
+    #[case(30, Some(2))]
+    #[case(31, None)]
+    fn test_bin_index(#[case] value: i32, #[case] expected: Option<usize>) {
+        let binner = Binner::new(vec![0, 10, 20, 30]).unwrap();
+        assert_eq!(binner.bin_index(&value), expected);
+    }
+
+    #[test]
+    fn test_bin_counts() {
+        let binner = Binner::new(vec![0, 10, 20, 30]).unwrap();
+        let values = vec![-5, 0, 3, 10, 19, 20, 30, 30, 31, 100];
+        assert_eq!(binner.bin_counts(values.clone()), vec![2, 2, 3]);
+        assert_eq!(
+            binner.bin_counts_with_overflow(values),
+            (1, vec![2, 2, 3], 2)
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::interval::Interval;
+
+/// Groups intervals into clusters of transitively overlapping intervals.
+///
+/// Two intervals are connected when they [`overlap`](Interval::overlaps),
+/// so `[1, 3]` and `[3, 5]` share a cluster while `[1, 3)` and `[3, 5]`
+/// do not. Each input interval ends up in exactly one cluster. Clusters
+/// are returned in axis order and hold their intervals sorted by start
+/// bound, ties keeping their input order.
+pub fn cluster_overlapping<T: Ord>(mut intervals: Vec<Interval<T>>) -> Vec<Vec<Interval<T>>> {
+    intervals.sort_by(|a, b| a.start.cmp(&b.start));
+
+    let mut clusters: Vec<Vec<Interval<T>>> = Vec::new();
+    // index into the current cluster of the interval reaching furthest
+    let mut furthest = 0;
+    for interval in intervals {
+        match clusters.last_mut() {
+            Some(cluster) if interval.start <= cluster[furthest].end => {
+                if interval.end > cluster[furthest].end {
+                    furthest = cluster.len();
+                }
+                cluster.push(interval);
+            }
+            _ => {
+                furthest = 0;
+                clusters.push(vec![interval]);
+            }
+        }
+    }
+    clusters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{closed, Rng};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec![closed(1, 2)], vec![vec![closed(1, 2)]])]
+    #[case(vec![closed(5, 6), closed(1, 2)], vec![vec![closed(1, 2)], vec![closed(5, 6)]])]
+    // chains are transitive even when the ends do not overlap
+    #[case(vec![closed(1, 3), closed(5, 7), closed(2, 6)], vec![vec![closed(1, 3), closed(2, 6), closed(5, 7)]])]
+    // a long interval keeps the cluster open past shorter ones
+    #[case(vec![closed(1, 10), closed(2, 3), closed(9, 12), closed(13, 14)],
+           vec![vec![closed(1, 10), closed(2, 3), closed(9, 12)], vec![closed(13, 14)]])]
+    // touching at a shared inclusive endpoint connects
+    #[case(vec![closed(1, 3), closed(3, 5)], vec![vec![closed(1, 3), closed(3, 5)]])]
+    // an exclusive/inclusive seam does not
+    #[case(vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), closed(3, 5)],
+           vec![vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap()], vec![closed(3, 5)]])]
+    #[case(vec![closed(1, 3), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()],
+           vec![vec![closed(1, 3)], vec![Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()]])]
+    // unbounded intervals
+    #[case(vec![closed(5, 6), Interval::since_inclusive(3), Interval::until_exclusive(0)],
+           vec![vec![Interval::until_exclusive(0)], vec![Interval::since_inclusive(3), closed(5, 6)]])]
+    fn test_cluster_overlapping(
+        #[case] intervals: Vec<Interval<i32>>,
+        #[case] expected: Vec<Vec<Interval<i32>>>,
+    ) {
+        assert_eq!(cluster_overlapping(intervals), expected);
+    }
+
+    #[test]
+    fn test_cluster_overlapping_matches_connectivity() {
+        let mut rng = Rng::new(353);
+        for _ in 0..500 {
+            let intervals: Vec<_> = (0..rng.range(0, 12))
+                .map(|_| rng.interval(-30, 30))
+                .collect();
+            let clusters = cluster_overlapping(intervals.clone());
+            assert_eq!(
+                clusters.iter().map(Vec::len).sum::<usize>(),
+                intervals.len()
+            );
+
+            for cluster in &clusters {
+                // every cluster is connected
+                let mut reached = vec![false; cluster.len()];
+                reached[0] = true;
+                let mut changed = true;
+                while changed {
+                    changed = false;
+                    for i in 0..cluster.len() {
+                        for j in 0..cluster.len() {
+                            if reached[i] && !reached[j] && cluster[i].overlaps(&cluster[j]) {
+                                reached[j] = true;
+                                changed = true;
+                            }
+                        }
+                    }
+                }
+                assert!(reached.iter().all(|&r| r), "failed: {:?}", intervals);
+            }
+            // and no interval overlaps one from another cluster
+            for (i, a) in clusters.iter().enumerate() {
+                for b in clusters.iter().skip(i + 1) {
+                    assert!(
+                        a.iter().all(|x| b.iter().all(|y| !x.overlaps(y))),
+                        "failed: {:?}",
+                        intervals
+                    );
+                }
+            }
+            for pair in clusters.windows(2) {
+                assert!(pair[0][0].start < pair[1][0].start);
+            }
+        }
+    }
+}
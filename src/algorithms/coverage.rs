@@ -0,0 +1,245 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::Interval;
+
+/// Counts how many of `intervals` contain `value`, in linear time.
+///
+/// For repeated queries over the same intervals, build a
+/// [`CoverageIndex`] once instead.
+pub fn coverage_at<T: Ord>(intervals: &[Interval<T>], value: &T) -> usize {
+    intervals
+        .iter()
+        .filter(|interval| interval.contains_value(value))
+        .count()
+}
+
+/// Splits the values covered by `intervals` into disjoint segments in
+/// axis order, each with the number of intervals covering it.
+///
+/// Implemented as a sweep over the boundaries with a running count. An
+/// end is turned into the start it releases at, `before(x)` into `at(x)`
+/// and `at(x)` into `after(x)`, and all boundaries at the same point are
+/// applied together, so flush seams like `[1, 3)` and `[3, 5]` leave no
+/// zero-width segment behind. Neighbouring segments with the same count
+/// are merged, and stretches covered by nothing are left out.
+pub fn coverage_profile<T: Ord + Clone>(intervals: &[Interval<T>]) -> Vec<(Interval<T>, usize)> {
+    // each event is a cut point and whether an interval enters there
+    let mut events: Vec<(BoundPoint<T>, bool)> = Vec::with_capacity(intervals.len() * 2);
+    for interval in intervals.iter().filter(|interval| !interval.is_empty()) {
+        events.push((interval.start.clone(), true));
+        if let Some(cut) = interval.end.clone().adjacent_start_after() {
+            events.push((cut, false));
+        }
+    }
+    events.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+    let mut profile: Vec<(Interval<T>, usize)> = Vec::new();
+    let mut active = 0;
+    let mut position = 0;
+    while position < events.len() {
+        let cut = &events[position].0;
+        let group_end = position + events[position..].partition_point(|(other, _)| other == cut);
+        for (_, enters) in &events[position..group_end] {
+            if *enters {
+                active += 1;
+            } else {
+                active -= 1;
+            }
+        }
+        let end = match events.get(group_end) {
+            Some((next, _)) => next
+                .clone()
+                .adjacent_end_before()
+                .expect("a cut after another one is finite"),
+            None => BoundPoint::pos_infinity(),
+        };
+        match profile.last_mut() {
+            Some((last, count)) if *count == active && last.end.meets(cut) => last.end = end,
+            _ if active > 0 => profile.push((
+                Interval {
+                    start: cut.clone(),
+                    end,
+                },
+                active,
+            )),
+            _ => {}
+        }
+        position = group_end;
+    }
+    profile
+}
+
+/// Answers "how many intervals contain this value" in `O(log n)`,
+/// for a collection of possibly overlapping intervals.
+///
+/// The index keeps the start bounds and the end bounds of all intervals
+/// in two sorted lists. The coverage of a value is the number of starts
+/// at or below it minus the number of ends below it.
+#[derive(Debug, Clone)]
+pub struct CoverageIndex<T>
+where
+    T: Ord,
+{
+    starts: Vec<BoundPoint<T>>,
+    ends: Vec<BoundPoint<T>>,
+}
+
+impl<T: Ord + Clone> CoverageIndex<T> {
+    pub fn new(intervals: &[Interval<T>]) -> Self {
+        // an empty interval would count its end without its start
+        let non_empty = || intervals.iter().filter(|interval| !interval.is_empty());
+        let mut starts: Vec<_> = non_empty().map(|interval| interval.start.clone()).collect();
+        let mut ends: Vec<_> = non_empty().map(|interval| interval.end.clone()).collect();
+        starts.sort_unstable();
+        ends.sort_unstable();
+        CoverageIndex { starts, ends }
+    }
+}
+
+impl<T: Ord> CoverageIndex<T> {
+    /// Returns the number of indexed intervals containing `value`.
+    pub fn count_at(&self, value: &T) -> usize {
+        let started = self
+            .starts
+            .partition_point(|start| start.cmp_value(value).is_le());
+        let ended = self
+            .ends
+            .partition_point(|end| end.cmp_value(value).is_lt());
+        started - ended
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{closed, contains_half_step, end_open, Rng};
+    use rstest::rstest;
+
+    fn intervals() -> Vec<Interval<i32>> {
+        vec![
+            Interval::from_to(1, 5, IntervalType::Close).unwrap(),
+            Interval::from_to(3, 5, IntervalType::Open).unwrap(),
+            Interval::from_to(5, 8, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(5, 8, IntervalType::StartOpen).unwrap(),
+            Interval::until_exclusive(3),
+            Interval::since_inclusive(8),
+            Interval::from_to(3, 3, IntervalType::Open).unwrap(),
+        ]
+    }
+
+    #[rstest]
+    #[case(-100, 1)]
+    #[case(0, 1)]
+    #[case(1, 2)]
+    // excluded by until_exclusive(3) and by (3, 5)
+    #[case(3, 1)]
+    #[case(4, 2)]
+    // excluded by (3, 5) and (5, 8]
+    #[case(5, 2)]
+    #[case(6, 2)]
+    // excluded by [5, 8)
+    #[case(8, 2)]
+    #[case(9, 1)]
+    fn test_coverage_at(#[case] value: i32, #[case] expected: usize) {
+        let intervals = intervals();
+        assert_eq!(coverage_at(&intervals, &value), expected);
+        assert_eq!(CoverageIndex::new(&intervals).count_at(&value), expected);
+    }
+
+    #[test]
+    fn test_coverage_index_empty() {
+        assert_eq!(CoverageIndex::<i32>::new(&[]).count_at(&0), 0);
+    }
+
+    #[test]
+    fn test_coverage_index_matches_linear_count() {
+        let mut rng = Rng::new(354);
+        for _ in 0..200 {
+            let intervals: Vec<_> = (0..rng.range(0, 30))
+                .map(|_| rng.interval(-50, 50))
+                .collect();
+            let index = CoverageIndex::new(&intervals);
+            for value in -60..60 {
+                assert_eq!(
+                    index.count_at(&value),
+                    coverage_at(&intervals, &value),
+                    "failed: {:?}, {}",
+                    intervals,
+                    value
+                );
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec![closed(1, 3), closed(5, 7)], vec![(closed(1, 3), 1), (closed(5, 7), 1)])]
+    #[case(vec![closed(1, 5), closed(3, 8)], vec![(end_open(1, 3), 1), (closed(3, 5), 2), (Interval::from_to(5, 8, IntervalType::StartOpen).unwrap(), 1)])]
+    // a flush seam merges into one segment without a phantom between
+    #[case(vec![end_open(1, 3), closed(3, 5)], vec![(closed(1, 5), 1)])]
+    #[case(vec![closed(1, 3), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()], vec![(closed(1, 5), 1)])]
+    // a missing point splits it
+    #[case(vec![end_open(1, 3), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()],
+           vec![(end_open(1, 3), 1), (Interval::from_to(3, 5, IntervalType::StartOpen).unwrap(), 1)])]
+    // touching inclusive ends share the single point
+    #[case(vec![closed(1, 3), closed(3, 5)], vec![(end_open(1, 3), 1), (closed(3, 3), 2), (Interval::from_to(3, 5, IntervalType::StartOpen).unwrap(), 1)])]
+    #[case(vec![Interval::until_exclusive(5), Interval::since_inclusive(2), Interval::from_to(9, 9, IntervalType::Open).unwrap()],
+           vec![(Interval::until_exclusive(2), 1), (end_open(2, 5), 2), (Interval::since_inclusive(5), 1)])]
+    fn test_coverage_profile(
+        #[case] intervals: Vec<Interval<i32>>,
+        #[case] expected: Vec<(Interval<i32>, usize)>,
+    ) {
+        assert_eq!(coverage_profile(&intervals), expected);
+    }
+
+    #[test]
+    fn test_coverage_profile_matches_brute_force() {
+        let mut rng = Rng::new(397);
+        for _ in 0..300 {
+            let intervals: Vec<_> = (0..rng.range(0, 8))
+                .map(|_| rng.interval(-20, 20))
+                .collect();
+            let profile = coverage_profile(&intervals);
+            for pair in profile.windows(2) {
+                let ((left, left_count), (right, right_count)) = (&pair[0], &pair[1]);
+                assert!(left.end < right.start);
+                assert!(left_count != right_count || !left.end.meets(&right.start));
+            }
+            for half_steps in -50..50 {
+                let expected = intervals
+                    .iter()
+                    .filter(|interval| contains_half_step(interval, half_steps))
+                    .count();
+                let count = profile
+                    .iter()
+                    .find(|(segment, _)| contains_half_step(segment, half_steps))
+                    .map_or(0, |(_, count)| *count);
+                assert_eq!(count, expected, "failed: {:?}, {}", intervals, half_steps);
+            }
+        }
+    }
+
+    #[test]
+    fn test_coverage_profile_preserves_total_length() {
+        let mut rng = Rng::new(3970);
+        for _ in 0..300 {
+            let intervals: Vec<_> = (0..rng.range(0, 8))
+                .map(|_| {
+                    let start = rng.range(-20, 20);
+                    let end = start + rng.range(0, 10);
+                    Interval::from_to(start, end, rng.interval_type()).unwrap()
+                })
+                .filter(|interval| !interval.is_empty())
+                .collect();
+            let total: i32 = intervals
+                .iter()
+                .map(|interval| interval.length().unwrap())
+                .sum();
+            let profiled: i32 = coverage_profile(&intervals)
+                .iter()
+                .map(|(segment, count)| segment.length().unwrap() * *count as i32)
+                .sum();
+            assert_eq!(profiled, total, "failed: {:?}", intervals);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::Interval;
+use std::collections::BTreeSet;
+
+/// Splits the axis at every boundary of `intervals` and returns the
+/// covered elementary segments in axis order, each with the sorted
+/// indices of the intervals covering it.
+///
+/// A segment starts at an interval start, or right after an interval end:
+/// an end `before(x)` cuts at `at(x)` and an end `at(x)` at `after(x)`.
+/// Coincident boundaries of different proximities therefore split the
+/// axis exactly: `[1, 3]` and `(3, 5]` yield `[1, 3]` and `(3, 5]`, while
+/// `[1, 3]` and `[3, 5]` yield `[1, 3)`, `[3, 3]` and `(3, 5]`.
+/// Stretches covered by no interval are left out; empty intervals
+/// cover nothing.
+pub fn decompose<T: Ord + Clone>(intervals: &[Interval<T>]) -> Vec<(Interval<T>, Vec<usize>)> {
+    // each event is a cut point, the interval index, and whether it enters
+    let mut events: Vec<(BoundPoint<T>, usize, bool)> = Vec::with_capacity(intervals.len() * 2);
+    for (index, interval) in intervals.iter().enumerate() {
+        if interval.is_empty() {
+            continue;
+        }
+        events.push((interval.start.clone(), index, true));
+        if let Some(cut) = interval.end.clone().adjacent_start_after() {
+            events.push((cut, index, false));
+        }
+    }
+    events.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut segments = Vec::new();
+    let mut active = BTreeSet::new();
+    let mut position = 0;
+    while position < events.len() {
+        let cut = &events[position].0;
+        let group_end = position + events[position..].partition_point(|(other, _, _)| other == cut);
+        for (_, index, enters) in &events[position..group_end] {
+            if *enters {
+                active.insert(*index);
+            } else {
+                active.remove(index);
+            }
+        }
+        if !active.is_empty() {
+            let end = match events.get(group_end) {
+                Some((next, _, _)) => next
+                    .clone()
+                    .adjacent_end_before()
+                    .expect("a cut after another one is finite"),
+                None => BoundPoint::pos_infinity(),
+            };
+            let segment = Interval {
+                start: cut.clone(),
+                end,
+            };
+            segments.push((segment, active.iter().copied().collect()));
+        }
+        position = group_end;
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{closed, contains_half_step, interval, Rng};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec![closed(1, 3)], vec![(closed(1, 3), vec![0])])]
+    #[case(vec![closed(1, 3), interval(3, 5, IntervalType::StartOpen)],
+           vec![(closed(1, 3), vec![0]), (interval(3, 5, IntervalType::StartOpen), vec![1])])]
+    #[case(vec![closed(1, 3), closed(3, 5)],
+           vec![(interval(1, 3, IntervalType::EndOpen), vec![0]), (closed(3, 3), vec![0, 1]), (interval(3, 5, IntervalType::StartOpen), vec![1])])]
+    #[case(vec![interval(1, 3, IntervalType::EndOpen), closed(3, 5)],
+           vec![(interval(1, 3, IntervalType::EndOpen), vec![0]), (closed(3, 5), vec![1])])]
+    // a gap is left out
+    #[case(vec![closed(1, 2), closed(4, 5)], vec![(closed(1, 2), vec![0]), (closed(4, 5), vec![1])])]
+    #[case(vec![closed(0, 10), closed(2, 4), interval(2, 6, IntervalType::Open)],
+           vec![(interval(0, 2, IntervalType::EndOpen), vec![0]),
+                (closed(2, 2), vec![0, 1]),
+                (interval(2, 4, IntervalType::StartOpen), vec![0, 1, 2]),
+                (interval(4, 6, IntervalType::Open), vec![0, 2]),
+                (closed(6, 10), vec![0])])]
+    #[case(vec![Interval::until_exclusive(2), Interval::since_inclusive(1)],
+           vec![(Interval::until_exclusive(1), vec![0]), (interval(1, 2, IntervalType::EndOpen), vec![0, 1]), (Interval::since_inclusive(2), vec![1])])]
+    fn test_decompose(
+        #[case] intervals: Vec<Interval<i32>>,
+        #[case] expected: Vec<(Interval<i32>, Vec<usize>)>,
+    ) {
+        assert_eq!(decompose(&intervals), expected);
+    }
+
+    #[test]
+    fn test_decompose_matches_brute_force() {
+        let mut rng = Rng::new(363);
+        for _ in 0..500 {
+            let intervals: Vec<_> = (0..rng.range(0, 8))
+                .map(|_| rng.interval(-20, 20))
+                .collect();
+            let segments = decompose(&intervals);
+            for pair in segments.windows(2) {
+                assert!(pair[0].0.end < pair[1].0.start);
+            }
+            for half_steps in -50..50 {
+                let covering: Vec<usize> = (0..intervals.len())
+                    .filter(|&index| contains_half_step(&intervals[index], half_steps))
+                    .collect();
+                let segment = segments
+                    .iter()
+                    .find(|(segment, _)| contains_half_step(segment, half_steps));
+                match segment {
+                    Some((_, indices)) => assert_eq!(
+                        *indices, covering,
+                        "failed: {:?}, {}",
+                        intervals, half_steps
+                    ),
+                    None => assert!(
+                        covering.is_empty(),
+                        "failed: {:?}, {}",
+                        intervals,
+                        half_steps
+                    ),
+                }
+            }
+        }
+    }
+}
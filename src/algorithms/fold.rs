@@ -0,0 +1,113 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::Interval;
+
+/// Intersects all the intervals, or returns `None` as soon as the
+/// running intersection becomes empty, without reading the rest.
+///
+/// The intersection of no intervals is the whole unbounded axis, the
+/// identity of intersection. An empty input interval yields `None`.
+/// Bounds are moved, never cloned.
+pub fn intersection_of<T: Ord, I: IntoIterator<Item = Interval<T>>>(
+    intervals: I,
+) -> Option<Interval<T>> {
+    let mut intersection = Interval {
+        start: BoundPoint::neg_infinity(),
+        end: BoundPoint::pos_infinity(),
+    };
+    for interval in intervals {
+        intersection = intersection.into_intersection(interval)?;
+    }
+    Some(intersection)
+}
+
+/// Returns the smallest interval containing all the intervals and any
+/// gaps between them, or `None` if there are none.
+///
+/// As with [`Interval::hull`], empty intervals add nothing, so the hull
+/// is empty only if all the intervals are. Bounds are moved, never
+/// cloned.
+pub fn hull_of<T: Ord, I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Option<Interval<T>> {
+    let mut intervals = intervals.into_iter();
+    let mut hull = intervals.next()?;
+    for interval in intervals {
+        if interval.is_empty() {
+            continue;
+        }
+        if hull.is_empty() {
+            hull = interval;
+            continue;
+        }
+        if interval.start < hull.start {
+            hull.start = interval.start;
+        }
+        if interval.end > hull.end {
+            hull.end = interval.end;
+        }
+    }
+    Some(hull)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::closed;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![], Some(Interval { start: BoundPoint::neg_infinity(), end: BoundPoint::pos_infinity() }))]
+    #[case(vec![closed(1, 5)], Some(closed(1, 5)))]
+    #[case(vec![closed(1, 5), Interval::since_exclusive(2), Interval::until_inclusive(4)], Some(Interval::from_to(2, 4, IntervalType::StartOpen).unwrap()))]
+    #[case(vec![closed(1, 3), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()], None)]
+    #[case(vec![Interval::from_to(1, 1, IntervalType::Open).unwrap()], None)]
+    fn test_intersection_of(
+        #[case] intervals: Vec<Interval<i32>>,
+        #[case] expected: Option<Interval<i32>>,
+    ) {
+        assert_eq!(intersection_of(intervals), expected);
+    }
+
+    #[test]
+    fn test_intersection_of_stops_at_first_empty() {
+        let mut read = 0;
+        let intervals = [closed(1, 2), closed(5, 6), closed(1, 6)]
+            .into_iter()
+            .inspect(|_| read += 1);
+        assert_eq!(intersection_of(intervals), None);
+        assert_eq!(read, 2);
+    }
+
+    #[rstest]
+    #[case(vec![], None)]
+    #[case(vec![closed(1, 5)], Some(closed(1, 5)))]
+    #[case(vec![closed(5, 6), closed(1, 2), Interval::from_to(3, 8, IntervalType::EndOpen).unwrap()], Some(Interval::from_to(1, 8, IntervalType::EndOpen).unwrap()))]
+    #[case(vec![closed(5, 6), Interval::until_exclusive(0)], Some(Interval::until_inclusive(6)))]
+    // empty intervals add nothing
+    #[case(vec![Interval::from_to(0, 0, IntervalType::Open).unwrap(), closed(5, 6), Interval::from_to(9, 9, IntervalType::Open).unwrap()], Some(closed(5, 6)))]
+    #[case(vec![Interval::from_to(0, 0, IntervalType::Open).unwrap()], Some(Interval::from_to(0, 0, IntervalType::Open).unwrap()))]
+    fn test_hull_of(
+        #[case] intervals: Vec<Interval<i32>>,
+        #[case] expected: Option<Interval<i32>>,
+    ) {
+        assert_eq!(hull_of(intervals), expected);
+    }
+
+    #[test]
+    fn test_fold_without_clone() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Key(i32);
+        let intervals = || {
+            [(1, 5), (3, 8)].map(|(start, end)| {
+                Interval::from_to(Key(start), Key(end), IntervalType::Close).unwrap()
+            })
+        };
+        assert_eq!(
+            intersection_of(intervals()),
+            Some(Interval::from_to(Key(3), Key(5), IntervalType::Close).unwrap())
+        );
+        assert_eq!(
+            hull_of(intervals()),
+            Some(Interval::from_to(Key(1), Key(8), IntervalType::Close).unwrap())
+        );
+    }
+}
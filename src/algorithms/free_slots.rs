@@ -0,0 +1,57 @@
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use std::ops::Sub;
+
+/// Returns the maximal free intervals of `window` not covered by any of
+/// the `busy` intervals and at least `min_len` long, sorted and disjoint.
+///
+/// Busy intervals may overlap each other and extend past the window.
+/// Each free slot takes the flipped proximities of the busy bounds it
+/// touches, so a busy block ending `before(12)` frees time from `at(12)`.
+/// An unbounded free slot, possible only in an unbounded window, is
+/// always long enough.
+pub fn free_slots<T>(window: &Interval<T>, busy: &[Interval<T>], min_len: T) -> Vec<Interval<T>>
+where
+    T: Ord + Clone + Sub<Output = T>,
+{
+    IntervalSet::from(busy.to_vec())
+        .gaps_within(window)
+        .filter(|slot| slot.length().is_none_or(|length| length >= min_len))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{closed, end_open};
+    use rstest::rstest;
+
+    #[rstest]
+    // nothing busy
+    #[case(end_open(9, 17), vec![], 1, vec![end_open(9, 17)])]
+    #[case(end_open(9, 17), vec![], 9, vec![])]
+    // half-open busy blocks free their end value
+    #[case(end_open(9, 17), vec![end_open(10, 12), end_open(13, 14)], 1,
+           vec![end_open(9, 10), end_open(12, 13), end_open(14, 17)])]
+    #[case(end_open(9, 17), vec![end_open(10, 12), end_open(13, 14)], 2, vec![end_open(14, 17)])]
+    // closed busy blocks leave open slots
+    #[case(end_open(9, 17), vec![closed(10, 12)], 5, vec![Interval::from_to(12, 17, IntervalType::Open).unwrap()])]
+    // busy blocks overlapping each other and the window edges
+    #[case(end_open(9, 17), vec![end_open(8, 10), end_open(9, 11), end_open(15, 18), end_open(12, 13)], 1,
+           vec![end_open(11, 12), end_open(13, 15)])]
+    // a busy block covering the window
+    #[case(end_open(9, 17), vec![closed(0, 24)], 0, vec![])]
+    // zero length slots are kept with a zero minimum
+    #[case(closed(9, 17), vec![end_open(9, 12), Interval::from_to(12, 17, IntervalType::StartOpen).unwrap()], 0, vec![closed(12, 12)])]
+    // an unbounded window yields an unbounded last slot
+    #[case(Interval::since_inclusive(9), vec![end_open(10, 12)], 100, vec![Interval::since_inclusive(12)])]
+    fn test_free_slots(
+        #[case] window: Interval<i32>,
+        #[case] busy: Vec<Interval<i32>>,
+        #[case] min_len: i32,
+        #[case] expected: Vec<Interval<i32>>,
+    ) {
+        assert_eq!(free_slots(&window, &busy, min_len), expected);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::interval::Interval;
+
+/// Returns the largest number of intervals sharing a common value,
+/// together with a witness interval over which that many overlap,
+/// or `None` when there is no non-empty interval.
+///
+/// The witness is maximal: it runs from the start bound at which the
+/// peak is reached up to the first end bound that releases it.
+///
+/// Implemented as a sweep over the start and end bounds in axis order.
+/// Coincident bounds follow their proximities: an end `before(3)`
+/// releases before a start `at(3)` acquires, while an end `at(3)` and
+/// a start `at(3)` do overlap.
+pub fn max_overlap<T: Ord + Clone>(intervals: &[Interval<T>]) -> Option<(usize, Interval<T>)> {
+    // `false` sorts starts ahead of ends sharing the same bound point
+    let mut events: Vec<_> = intervals
+        .iter()
+        .filter(|interval| !interval.is_empty())
+        .flat_map(|interval| [(&interval.start, false), (&interval.end, true)])
+        .collect();
+    events.sort();
+
+    let mut active = 0;
+    let mut peak: Option<(usize, usize)> = None;
+    for (index, (_, is_end)) in events.iter().enumerate() {
+        if *is_end {
+            active -= 1;
+        } else {
+            active += 1;
+            if peak.is_none_or(|(count, _)| active > count) {
+                peak = Some((active, index));
+            }
+        }
+    }
+
+    let (count, index) = peak?;
+    let (end, _) = events[index..].iter().find(|(_, is_end)| *is_end)?;
+    Some((
+        count,
+        Interval {
+            start: events[index].0.clone(),
+            end: (*end).clone(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{closed, contains_half_step, end_open, Rng};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![], None)]
+    #[case(vec![Interval::from_to(1, 1, IntervalType::Open).unwrap()], None)]
+    #[case(vec![closed(1, 3)], Some((1, closed(1, 3))))]
+    #[case(vec![closed(1, 3), closed(5, 7)], Some((1, closed(1, 3))))]
+    #[case(vec![closed(1, 5), closed(2, 3), closed(4, 8)], Some((2, closed(2, 3))))]
+    // meetings all touching at one instant with inclusive endings
+    #[case(vec![closed(0, 3), closed(1, 3), closed(3, 5), closed(3, 6)], Some((4, closed(3, 3))))]
+    // ...and with exclusive endings, where the early ones release first
+    #[case(vec![end_open(0, 3), end_open(1, 3), end_open(3, 5), end_open(3, 6)], Some((2, end_open(1, 3))))]
+    #[case(vec![end_open(0, 3), end_open(3, 5), end_open(3, 6)], Some((2, end_open(3, 5))))]
+    // an exclusive start after an inclusive end
+    #[case(vec![closed(0, 3), Interval::from_to(3, 6, IntervalType::StartOpen).unwrap()], Some((1, closed(0, 3))))]
+    #[case(vec![Interval::until_inclusive(5), Interval::since_inclusive(2), Interval::since_exclusive(4)],
+           Some((3, Interval::from_to(4, 5, IntervalType::StartOpen).unwrap())))]
+    fn test_max_overlap(
+        #[case] intervals: Vec<Interval<i32>>,
+        #[case] expected: Option<(usize, Interval<i32>)>,
+    ) {
+        assert_eq!(max_overlap(&intervals), expected);
+    }
+
+    #[test]
+    fn test_max_overlap_matches_brute_force() {
+        let mut rng = Rng::new(355);
+        for _ in 0..500 {
+            let intervals: Vec<_> = (0..rng.range(0, 15))
+                .map(|_| rng.interval(-30, 30))
+                .collect();
+            let coverage = |half_steps: i32| {
+                intervals
+                    .iter()
+                    .filter(|interval| contains_half_step(interval, half_steps))
+                    .count()
+            };
+            let expected = (-70..70).map(coverage).max().unwrap();
+            match max_overlap(&intervals) {
+                None => assert_eq!(expected, 0, "failed: {:?}", intervals),
+                Some((count, witness)) => {
+                    assert_eq!(count, expected, "failed: {:?}", intervals);
+                    for half_steps in -70..70 {
+                        if contains_half_step(&witness, half_steps) {
+                            assert_eq!(
+                                coverage(half_steps),
+                                count,
+                                "failed: {:?}, {:?}",
+                                intervals,
+                                witness
+                            );
+                        }
+                    }
+                    assert!(!witness.is_empty());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::interval::Interval;
+
+/// Coalesces intervals given in any order into a sorted list of
+/// disjoint intervals covering exactly the same values.
+///
+/// The intervals are sorted by start bound, then swept once, merging
+/// each one into its predecessor when they overlap or meet at a flush
+/// seam such as `[1, 3)` and `[3, 5]`. Empty intervals are dropped.
+/// Bounds are moved, never cloned, so `T` need not be `Clone`.
+pub fn merge_intervals<T: Ord>(mut intervals: Vec<Interval<T>>) -> Vec<Interval<T>> {
+    intervals.retain(|interval| !interval.is_empty());
+    intervals.sort_unstable_by(|a, b| a.start.cmp(&b.start));
+
+    let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end || last.end.meets(&interval.start) => {
+                if interval.end > last.end {
+                    last.end = interval.end;
+                }
+            }
+            _ => merged.push(interval),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{contains_half_step, Rng};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec![Interval::from_to(5, 8, IntervalType::Close).unwrap(), Interval::from_to(1, 3, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(5, 8, IntervalType::Close).unwrap()])]
+    #[case(vec![Interval::from_to(3, 8, IntervalType::Open).unwrap(), Interval::from_to(1, 5, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(1, 8, IntervalType::EndOpen).unwrap()])]
+    // flush seams are adjacent, a missing point is not
+    #[case(vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(3, 5, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()])]
+    #[case(vec![Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()],
+           vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()])]
+    #[case(vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()],
+           vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()])]
+    // unbounded below sorts first, unbounded above swallows the rest
+    #[case(vec![Interval::from_to(1, 2, IntervalType::Close).unwrap(), Interval::until_exclusive(0), Interval::since_inclusive(4), Interval::from_to(6, 9, IntervalType::Close).unwrap()],
+           vec![Interval::until_exclusive(0), Interval::from_to(1, 2, IntervalType::Close).unwrap(), Interval::since_inclusive(4)])]
+    #[case(vec![Interval::until_inclusive(5), Interval::since_exclusive(5)],
+           vec![Interval { start: crate::bound_point::BoundPoint::neg_infinity(), end: crate::bound_point::BoundPoint::pos_infinity() }])]
+    // empty intervals disappear
+    #[case(vec![Interval::from_to(2, 2, IntervalType::Open).unwrap(), Interval::from_to(1, 1, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(1, 1, IntervalType::Close).unwrap()])]
+    fn test_merge_intervals(
+        #[case] intervals: Vec<Interval<i32>>,
+        #[case] expected: Vec<Interval<i32>>,
+    ) {
+        assert_eq!(merge_intervals(intervals), expected);
+    }
+
+    #[test]
+    fn test_merge_intervals_without_clone() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Opaque(i32);
+
+        let merged = merge_intervals(vec![
+            Interval::from_to(Opaque(3), Opaque(5), IntervalType::Close).unwrap(),
+            Interval::from_to(Opaque(1), Opaque(4), IntervalType::Close).unwrap(),
+        ]);
+        assert_eq!(
+            merged,
+            vec![Interval::from_to(Opaque(1), Opaque(5), IntervalType::Close).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_merge_intervals_covers_same_points() {
+        let mut rng = Rng::new(352);
+        for _ in 0..1_000 {
+            let intervals: Vec<_> = (0..rng.range(0, 10))
+                .map(|_| rng.interval(-20, 20))
+                .collect();
+            let merged = merge_intervals(intervals.clone());
+            for pair in merged.windows(2) {
+                assert!(pair[0].end < pair[1].start && !pair[0].end.meets(&pair[1].start));
+            }
+            for value in -50..50 {
+                let covered = |intervals: &[Interval<i32>]| {
+                    intervals
+                        .iter()
+                        .any(|interval| contains_half_step(interval, value))
+                };
+                assert_eq!(
+                    covered(&merged),
+                    covered(&intervals),
+                    "failed: {:?}, {}",
+                    intervals,
+                    value
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+mod bins;
+mod cluster;
+mod coverage;
+mod decompose;
+mod fold;
+mod free_slots;
+mod max_overlap;
+mod merge;
+mod overlap_join;
+mod piercing;
+mod recurring;
+mod scheduling;
+mod sort;
+
+pub use bins::{bins_from_breakpoints, Binner, InvalidBreakpointsError};
+pub use cluster::cluster_overlapping;
+pub use coverage::{coverage_at, coverage_profile, CoverageIndex};
+pub use decompose::decompose;
+pub use fold::{hull_of, intersection_of};
+pub use free_slots::free_slots;
+pub use max_overlap::max_overlap;
+pub use merge::merge_intervals;
+pub use overlap_join::{overlap_join, overlap_join_with_intersection};
+pub use piercing::{min_piercing_points, min_piercing_points_discrete, PiercingError};
+pub use recurring::{recurring, InvalidPeriodError, RecurrenceEnd};
+pub use scheduling::{max_disjoint_subset, max_disjoint_subset_with, weighted_max_disjoint};
+pub use sort::{dedup_structural, is_sorted_and_disjoint, sort_intervals};
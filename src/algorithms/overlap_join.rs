@@ -0,0 +1,181 @@
+use crate::algorithms::sort::is_sorted;
+use crate::interval::Interval;
+
+/// Pairs up the overlapping intervals of two lists, each sorted with
+/// [`sort_intervals`](crate::sort_intervals), yielding
+/// `(left_index, right_index)` pairs.
+///
+/// Runs as a single merge over both lists in `O(n + m + k)` for `k`
+/// pairs, keeping for each side the intervals that may still overlap
+/// upcoming ones. Overlap follows [`Interval::overlaps`], so intervals
+/// meeting at a flush seam such as `[1, 3)` and `[3, 5]` are not paired.
+/// Empty intervals contain no value and are never paired.
+///
+/// In debug builds, panics if either list is not sorted.
+pub fn overlap_join<'a, T: Ord>(
+    left: &'a [Interval<T>],
+    right: &'a [Interval<T>],
+) -> impl Iterator<Item = (usize, usize)> + 'a {
+    debug_assert!(is_sorted(left));
+    debug_assert!(is_sorted(right));
+    OverlapJoin {
+        left,
+        right,
+        next_left: 0,
+        next_right: 0,
+        active_left: Vec::new(),
+        active_right: Vec::new(),
+        pending: Vec::new(),
+    }
+}
+
+/// Like [`overlap_join`], also yielding the intersection of each pair.
+pub fn overlap_join_with_intersection<'a, T: Ord + Clone>(
+    left: &'a [Interval<T>],
+    right: &'a [Interval<T>],
+) -> impl Iterator<Item = (usize, usize, Interval<T>)> + 'a {
+    overlap_join(left, right)
+        .filter_map(move |(i, j)| Some((i, j, left[i].intersection(&right[j])?)))
+}
+
+struct OverlapJoin<'a, T: Ord> {
+    left: &'a [Interval<T>],
+    right: &'a [Interval<T>],
+    next_left: usize,
+    next_right: usize,
+    /// Intervals already swept whose end may reach upcoming starts.
+    active_left: Vec<usize>,
+    active_right: Vec<usize>,
+    /// Pairs found for the last swept interval, yielded in reverse.
+    pending: Vec<(usize, usize)>,
+}
+
+impl<T: Ord> Iterator for OverlapJoin<'_, T> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() {
+            let take_left = match (
+                self.left.get(self.next_left),
+                self.right.get(self.next_right),
+            ) {
+                (Some(l), Some(r)) => l.start <= r.start,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => return None,
+            };
+            let (intervals, index, active_own, active_other, other) = if take_left {
+                self.next_left += 1;
+                (
+                    self.left,
+                    self.next_left - 1,
+                    &mut self.active_left,
+                    &mut self.active_right,
+                    self.right,
+                )
+            } else {
+                self.next_right += 1;
+                (
+                    self.right,
+                    self.next_right - 1,
+                    &mut self.active_right,
+                    &mut self.active_left,
+                    self.left,
+                )
+            };
+            let interval = &intervals[index];
+            if interval.is_empty() {
+                continue;
+            }
+            // every active interval of the other side starts at or before this one,
+            // so it overlaps iff it ends at or after this start
+            active_other.retain(|&other_index| other[other_index].end >= interval.start);
+            self.pending
+                .extend(active_other.iter().rev().map(|&other_index| {
+                    if take_left {
+                        (index, other_index)
+                    } else {
+                        (other_index, index)
+                    }
+                }));
+            active_own.push(index);
+        }
+        self.pending.pop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sort_intervals;
+    use crate::test_support::{closed, end_open, Rng};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![], vec![closed(1, 2)], vec![])]
+    #[case(vec![closed(1, 5)], vec![closed(2, 3), closed(4, 8), closed(6, 7)], vec![(0, 0), (0, 1)])]
+    #[case(vec![closed(1, 3), closed(2, 6)], vec![closed(3, 4)], vec![(0, 0), (1, 0)])]
+    // touching at an inclusive endpoint pairs, a flush seam does not
+    #[case(vec![closed(1, 3)], vec![closed(3, 5)], vec![(0, 0)])]
+    #[case(vec![end_open(1, 3)], vec![closed(3, 5)], vec![])]
+    #[case(vec![closed(3, 5)], vec![end_open(1, 3)], vec![])]
+    #[case(vec![Interval::until_exclusive(0), Interval::since_inclusive(10)], vec![closed(-5, 20)], vec![(0, 0), (1, 0)])]
+    // equal starts
+    #[case(vec![closed(1, 2), closed(1, 4)], vec![closed(1, 1), closed(1, 3)], vec![(0, 0), (0, 1), (1, 0), (1, 1)])]
+    fn test_overlap_join(
+        #[case] left: Vec<Interval<i32>>,
+        #[case] right: Vec<Interval<i32>>,
+        #[case] expected: Vec<(usize, usize)>,
+    ) {
+        let mut actual: Vec<_> = overlap_join(&left, &right).collect();
+        actual.sort_unstable();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_overlap_join_with_intersection() {
+        let left = vec![end_open(0, 10), closed(20, 30)];
+        let right = vec![closed(5, 25)];
+        let mut actual: Vec<_> = overlap_join_with_intersection(&left, &right).collect();
+        actual.sort_unstable_by_key(|(i, j, _)| (*i, *j));
+        assert_eq!(
+            actual,
+            vec![(0, 0, end_open(5, 10)), (1, 0, closed(20, 25))]
+        );
+    }
+
+    #[test]
+    fn test_overlap_join_matches_brute_force() {
+        let mut rng = Rng::new(362);
+        for _ in 0..500 {
+            let mut left: Vec<_> = (0..rng.range(0, 20))
+                .map(|_| rng.interval(-50, 50))
+                .collect();
+            let mut right: Vec<_> = (0..rng.range(0, 20))
+                .map(|_| rng.interval(-50, 50))
+                .collect();
+            sort_intervals(&mut left);
+            sort_intervals(&mut right);
+
+            let mut expected = Vec::new();
+            for (i, l) in left.iter().enumerate() {
+                for (j, r) in right.iter().enumerate() {
+                    if !l.is_empty() && !r.is_empty() && l.overlaps(r) {
+                        expected.push((i, j));
+                    }
+                }
+            }
+            let mut actual: Vec<_> = overlap_join(&left, &right).collect();
+            actual.sort_unstable();
+            assert_eq!(actual, expected, "failed: {:?}, {:?}", left, right);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_overlap_join_rejects_unsorted_input() {
+        let left = vec![closed(5, 6), closed(1, 2)];
+        let _ = overlap_join(&left, &[]);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::bound_point::BoundProximity;
+use crate::discrete::Discrete;
+use crate::interval::Interval;
+use std::fmt::{self, Display, Formatter};
+
+/// Error returned by [`min_piercing_points`] and
+/// [`min_piercing_points_discrete`] for an interval without a largest
+/// value to pierce it at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PiercingError {
+    /// The index of the first such interval in the input.
+    pub index: usize,
+}
+
+impl Display for PiercingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "interval {} has no largest value to pierce", self.index)
+    }
+}
+
+impl std::error::Error for PiercingError {}
+
+/// Returns a smallest set of points such that every interval contains
+/// at least one of them, in ascending order.
+///
+/// Implemented as the greedy over the intervals sorted by end: each
+/// interval not yet pierced gets a point at its largest value, which
+/// pierces as many of the later-ending intervals as possible. This needs
+/// an inclusive finite end on every interval; an exclusive end like
+/// `(1, 2)` has no largest value in a continuous type, so it and
+/// unbounded-above or empty intervals are reported as an error. Use
+/// [`min_piercing_points_discrete`] to step inside exclusive ends.
+pub fn min_piercing_points<T: Ord + Clone>(
+    intervals: &[Interval<T>],
+) -> Result<Vec<T>, PiercingError> {
+    pierce(intervals, |interval| match interval.end.proximity() {
+        Some(BoundProximity::At) if !interval.is_empty() => interval.end.finite_value().cloned(),
+        _ => None,
+    })
+}
+
+/// Like [`min_piercing_points`], but pierces `[1, 5)` at its last value
+/// `4`, so only unbounded-above and empty intervals are errors.
+pub fn min_piercing_points_discrete<T: Discrete + Clone>(
+    intervals: &[Interval<T>],
+) -> Result<Vec<T>, PiercingError> {
+    pierce(intervals, Interval::last_value)
+}
+
+fn pierce<T: Ord>(
+    intervals: &[Interval<T>],
+    last_value: impl Fn(&Interval<T>) -> Option<T>,
+) -> Result<Vec<T>, PiercingError> {
+    let mut lasts = intervals
+        .iter()
+        .enumerate()
+        .map(|(index, interval)| {
+            last_value(interval)
+                .map(|last| (last, index))
+                .ok_or(PiercingError { index })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    lasts.sort_unstable();
+
+    let mut points: Vec<T> = Vec::new();
+    for (last, index) in lasts {
+        if !points
+            .last()
+            .is_some_and(|point| intervals[index].contains_value(point))
+        {
+            points.push(last);
+        }
+    }
+    Ok(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{closed, Rng};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec![closed(1, 3)], vec![3])]
+    #[case(vec![closed(1, 4), closed(2, 5), closed(6, 8), closed(7, 9)], vec![4, 8])]
+    #[case(vec![closed(6, 8), closed(1, 10), closed(2, 3)], vec![3, 8])]
+    // an exclusive start misses a point at its value
+    #[case(vec![closed(1, 3), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()], vec![3, 5])]
+    #[case(vec![closed(1, 3), closed(3, 5)], vec![3])]
+    #[case(vec![Interval::until_inclusive(4), closed(4, 4)], vec![4])]
+    fn test_min_piercing_points(#[case] intervals: Vec<Interval<i32>>, #[case] expected: Vec<i32>) {
+        assert_eq!(min_piercing_points(&intervals), Ok(expected.clone()));
+        assert_eq!(min_piercing_points_discrete(&intervals), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(vec![closed(1, 2), Interval::from_to(1, 2, IntervalType::Open).unwrap()], 1)]
+    #[case(vec![Interval::since_inclusive(1)], 0)]
+    #[case(vec![closed(1, 2), Interval::from_to(1, 1, IntervalType::EndOpen).unwrap()], 1)]
+    fn test_min_piercing_points_without_largest_value(
+        #[case] intervals: Vec<Interval<i32>>,
+        #[case] index: usize,
+    ) {
+        assert_eq!(
+            min_piercing_points(&intervals),
+            Err(PiercingError { index })
+        );
+    }
+
+    #[test]
+    fn test_min_piercing_points_discrete() {
+        let intervals = vec![
+            Interval::from_to(1, 5, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(3, 6, IntervalType::Open).unwrap(),
+            Interval::from_to(6, 9, IntervalType::Open).unwrap(),
+        ];
+        assert_eq!(min_piercing_points_discrete(&intervals), Ok(vec![4, 8]));
+        assert_eq!(
+            min_piercing_points(&intervals),
+            Err(PiercingError { index: 0 })
+        );
+        assert_eq!(
+            min_piercing_points_discrete(&[Interval::from_to(1, 2, IntervalType::Open).unwrap()]),
+            Err(PiercingError { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_min_piercing_points_is_minimal() {
+        let mut rng = Rng::new(398);
+        for _ in 0..300 {
+            let intervals: Vec<_> = (0..rng.range(0, 7))
+                .map(|_| {
+                    let start = rng.range(0, 8);
+                    closed(start, start + rng.range(0, 3))
+                })
+                .collect();
+            let points = min_piercing_points(&intervals).unwrap();
+            for interval in &intervals {
+                assert!(points.iter().any(|point| interval.contains_value(point)));
+            }
+            // no smaller subset of the values 0..10 pierces every interval
+            let smallest = (0u32..1 << 10)
+                .filter(|mask| {
+                    intervals.iter().all(|interval| {
+                        (0..16)
+                            .any(|value| mask & 1 << value != 0 && interval.contains_value(&value))
+                    })
+                })
+                .map(u32::count_ones)
+                .min()
+                .unwrap();
+            assert_eq!(points.len(), smallest as usize, "failed: {:?}", intervals);
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::bound_point::BoundPoint;
+use crate::integer::Integer;
+use crate::interval::Interval;
+use std::fmt::{self, Display, Formatter};
+
+/// When a [`recurring`] series stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurrenceEnd<T> {
+    /// After this many occurrences.
+    Count(usize),
+
+    /// After the last occurrence starting at or before this value.
+    Until(T),
+}
+
+/// Error returned by [`recurring`] when the period is zero or negative,
+/// which would repeat the template forever or run backwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidPeriodError;
+
+impl Display for InvalidPeriodError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "recurrence period must be positive")
+    }
+}
+
+impl std::error::Error for InvalidPeriodError {}
+
+/// Yields `template` shifted by `0`, `period`, `2 * period`, … until
+/// `end` is reached, keeping the template's inclusiveness.
+///
+/// The series also stops, without panicking, at the first occurrence
+/// whose bounds would overflow `T`. Infinite template bounds stay
+/// infinite.
+///
+/// `T` is an [`Integer`] rather than any `Add`, as plain addition offers
+/// neither the zero to check `period` against nor a way to notice
+/// overflow before it panics.
+pub fn recurring<T>(
+    template: Interval<T>,
+    period: T,
+    end: RecurrenceEnd<T>,
+) -> Result<impl Iterator<Item = Interval<T>>, InvalidPeriodError>
+where
+    T: Integer,
+{
+    if period <= T::ZERO {
+        return Err(InvalidPeriodError);
+    }
+    let (mut remaining, until) = match end {
+        RecurrenceEnd::Count(count) => (Some(count), None),
+        RecurrenceEnd::Until(value) => (None, Some(value)),
+    };
+    let mut next = Some(template);
+    Ok(std::iter::from_fn(move || {
+        if let Some(remaining) = &mut remaining {
+            *remaining = remaining.checked_sub(1)?;
+        }
+        let current = next.take()?;
+        if let Some(until) = &until {
+            if current.start.cmp_value(until).is_gt() {
+                return None;
+            }
+        }
+        next = shifted(&current, &period);
+        Some(current)
+    }))
+}
+
+fn shifted<T: Integer>(interval: &Interval<T>, period: &T) -> Option<Interval<T>> {
+    let shift = |point: &BoundPoint<T>| {
+        point
+            .try_map(|value| value.checked_add(*period).ok_or(()))
+            .ok()
+    };
+    Some(Interval {
+        start: shift(&interval.start)?,
+        end: shift(&interval.end)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::interval_set::IntervalSet;
+    use rstest::rstest;
+
+    const DAY: i32 = 24;
+
+    fn office_hours() -> Interval<i32> {
+        Interval::from_to(9, 17, IntervalType::EndOpen).unwrap()
+    }
+
+    fn day(index: i32) -> Interval<i32> {
+        Interval::from_to(index * DAY + 9, index * DAY + 17, IntervalType::EndOpen).unwrap()
+    }
+
+    #[rstest]
+    #[case(RecurrenceEnd::Count(0), vec![])]
+    #[case(RecurrenceEnd::Count(3), vec![day(0), day(1), day(2)])]
+    #[case(RecurrenceEnd::Until(0), vec![])]
+    #[case(RecurrenceEnd::Until(9), vec![day(0)])]
+    // an occurrence starting exactly at the bound is included
+    #[case(RecurrenceEnd::Until(2 * DAY + 9), vec![day(0), day(1), day(2)])]
+    #[case(RecurrenceEnd::Until(2 * DAY + 8), vec![day(0), day(1)])]
+    fn test_recurring(#[case] end: RecurrenceEnd<i32>, #[case] expected: Vec<Interval<i32>>) {
+        assert_eq!(
+            recurring(office_hours(), DAY, end)
+                .unwrap()
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_recurring_keeps_proximities() {
+        let template = Interval::from_to(1, 2, IntervalType::StartOpen).unwrap();
+        let occurrences: Vec<_> = recurring(template, 10, RecurrenceEnd::Count(2))
+            .unwrap()
+            .collect();
+        assert_eq!(
+            occurrences,
+            vec![
+                template,
+                Interval::from_to(11, 12, IntervalType::StartOpen).unwrap()
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(-DAY)]
+    fn test_non_positive_period_is_rejected(#[case] period: i32) {
+        assert!(matches!(
+            recurring(office_hours(), period, RecurrenceEnd::Count(3)),
+            Err(InvalidPeriodError)
+        ));
+    }
+
+    #[test]
+    fn test_overflow_stops_the_series() {
+        let template = Interval::from_to(100u8, 120, IntervalType::Close).unwrap();
+        let occurrences: Vec<_> = recurring(template, 100, RecurrenceEnd::Until(u8::MAX))
+            .unwrap()
+            .collect();
+        assert_eq!(
+            occurrences,
+            vec![
+                template,
+                Interval::from_to(200, 220, IntervalType::Close).unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unbounded_template() {
+        let occurrences: Vec<_> =
+            recurring(Interval::until_exclusive(0), 5, RecurrenceEnd::Count(2))
+                .unwrap()
+                .collect();
+        assert_eq!(
+            occurrences,
+            vec![Interval::until_exclusive(0), Interval::until_exclusive(5)]
+        );
+    }
+
+    #[test]
+    fn test_availability_calendar_with_holidays() {
+        let holiday = Interval::from_to(2 * DAY, 3 * DAY, IntervalType::EndOpen).unwrap();
+        let available: IntervalSet<i32> = recurring(office_hours(), DAY, RecurrenceEnd::Count(5))
+            .unwrap()
+            .flat_map(|occurrence| occurrence.subtract_all([&holiday]))
+            .collect();
+        assert_eq!(
+            available.into_iter().collect::<Vec<_>>(),
+            vec![day(0), day(1), day(3), day(4)]
+        );
+    }
+}
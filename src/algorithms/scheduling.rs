@@ -0,0 +1,228 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::Interval;
+use std::ops::Add;
+
+/// Returns the indices of a largest subset of pairwise non-overlapping
+/// intervals, in axis order.
+///
+/// Implemented as the greedy over the intervals sorted by end bound,
+/// which keeps each interval that does not overlap the last one kept.
+/// Overlap follows [`Interval::overlaps`]: `[1, 3)` and `[3, 5]` are
+/// both kept, while `[1, 3]` and `[3, 5]` share `3` and conflict.
+/// Intervals with equal ends are taken in input order, and empty
+/// intervals are never selected.
+pub fn max_disjoint_subset<T: Ord>(intervals: &[Interval<T>]) -> Vec<usize> {
+    max_disjoint_subset_with(intervals, false)
+}
+
+/// Like [`max_disjoint_subset`], but with `allow_touching` two intervals
+/// may also share their boundary value, so `[1, 3]` and `[3, 5]` are
+/// both kept.
+pub fn max_disjoint_subset_with<T: Ord>(
+    intervals: &[Interval<T>],
+    allow_touching: bool,
+) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..intervals.len())
+        .filter(|&index| !intervals[index].is_empty())
+        .collect();
+    order.sort_by(|&a, &b| intervals[a].end.cmp(&intervals[b].end));
+
+    let mut selected: Vec<usize> = Vec::new();
+    for index in order {
+        let compatible = selected.last().is_none_or(|&last| {
+            compatible(
+                &intervals[last].end,
+                &intervals[index].start,
+                allow_touching,
+            )
+        });
+        if compatible {
+            selected.push(index);
+        }
+    }
+    selected
+}
+
+/// Returns the largest total weight of pairwise non-overlapping
+/// intervals, with the indices of one subset reaching it in axis order.
+///
+/// Implemented as the classic `O(n log n)` dynamic program over the
+/// intervals sorted by end bound: each interval either is skipped or is
+/// added to the best subset among the intervals ending before it starts,
+/// found by binary search. Overlap follows [`Interval::overlaps`], so an
+/// interval ending `before(3)` is compatible with one starting `at(3)`.
+/// An interval is only added if it strictly improves the total, so
+/// zero and negative weights are never selected, and empty intervals
+/// are skipped. With no selection the total is `W::default()`.
+pub fn weighted_max_disjoint<T, W>(intervals: &[(Interval<T>, W)]) -> (W, Vec<usize>)
+where
+    T: Ord,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    let mut order: Vec<usize> = (0..intervals.len())
+        .filter(|&index| !intervals[index].0.is_empty())
+        .collect();
+    order.sort_by(|&a, &b| intervals[a].0.end.cmp(&intervals[b].0.end));
+
+    // best[k] is the largest total among the first k intervals of `order`,
+    // and predecessors[k] the count of those ending before the k-th starts
+    let mut best = vec![W::default(); order.len() + 1];
+    let mut predecessors = Vec::with_capacity(order.len());
+    for (k, &index) in order.iter().enumerate() {
+        let (interval, weight) = &intervals[index];
+        let predecessor =
+            order[..k].partition_point(|&other| intervals[other].0.end < interval.start);
+        predecessors.push(predecessor);
+        best[k + 1] = std::cmp::max(best[k], best[predecessor] + *weight);
+    }
+
+    let mut selected = Vec::new();
+    let mut k = order.len();
+    while k > 0 {
+        if best[k] == best[k - 1] {
+            k -= 1;
+        } else {
+            selected.push(order[k - 1]);
+            k = predecessors[k - 1];
+        }
+    }
+    selected.reverse();
+    (best[order.len()], selected)
+}
+
+/// Whether an interval starting at `start` may follow one ending at
+/// `end`.
+fn compatible<T: Ord>(end: &BoundPoint<T>, start: &BoundPoint<T>, allow_touching: bool) -> bool {
+    end < start
+        || allow_touching
+            && end
+                .finite_value()
+                .is_some_and(|value| start.cmp_value(value).is_eq())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{closed, end_open, Rng};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![], vec![], vec![])]
+    #[case(vec![closed(1, 10), closed(2, 3), closed(4, 5), closed(6, 7)], vec![1, 2, 3], vec![1, 2, 3])]
+    // back to back across a flush seam
+    #[case(vec![end_open(1, 3), closed(3, 5)], vec![0, 1], vec![0, 1])]
+    // closed intervals touching at a point
+    #[case(vec![closed(1, 3), closed(3, 5), closed(5, 7)], vec![0, 2], vec![0, 1, 2])]
+    #[case(vec![closed(1, 3), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()], vec![0, 1], vec![0, 1])]
+    // equal ends are taken in input order
+    #[case(vec![closed(2, 5), closed(1, 5), closed(0, 5)], vec![0], vec![0])]
+    #[case(vec![Interval::until_exclusive(0), Interval::since_inclusive(0), closed(-5, 5)], vec![0, 1], vec![0, 1])]
+    // empty intervals are skipped
+    #[case(vec![Interval::from_to(2, 2, IntervalType::Open).unwrap(), closed(1, 5)], vec![1], vec![1])]
+    fn test_max_disjoint_subset(
+        #[case] intervals: Vec<Interval<i32>>,
+        #[case] expected: Vec<usize>,
+        #[case] expected_touching: Vec<usize>,
+    ) {
+        assert_eq!(max_disjoint_subset(&intervals), expected);
+        assert_eq!(
+            max_disjoint_subset_with(&intervals, true),
+            expected_touching
+        );
+    }
+
+    #[test]
+    fn test_max_disjoint_subset_is_maximum() {
+        let mut rng = Rng::new(399);
+        for _ in 0..300 {
+            let intervals: Vec<_> = (0..rng.range(0, 9)).map(|_| rng.interval(0, 12)).collect();
+            for allow_touching in [false, true] {
+                let selected = max_disjoint_subset_with(&intervals, allow_touching);
+                let conflict = |a: &Interval<i32>, b: &Interval<i32>| {
+                    let (first, second) = if a.end <= b.end { (a, b) } else { (b, a) };
+                    !compatible(&first.end, &second.start, allow_touching)
+                };
+                for (i, &a) in selected.iter().enumerate() {
+                    for &b in &selected[i + 1..] {
+                        assert!(!conflict(&intervals[a], &intervals[b]));
+                    }
+                }
+                let largest = (0u32..1 << intervals.len())
+                    .filter(|mask| {
+                        let chosen: Vec<_> = (0..intervals.len())
+                            .filter(|index| mask & 1 << index != 0)
+                            .map(|index| &intervals[index])
+                            .collect();
+                        chosen.iter().all(|interval| !interval.is_empty())
+                            && chosen
+                                .iter()
+                                .enumerate()
+                                .all(|(i, a)| chosen[i + 1..].iter().all(|b| !conflict(a, b)))
+                    })
+                    .map(u32::count_ones)
+                    .max()
+                    .unwrap();
+                assert_eq!(selected.len(), largest as usize, "failed: {:?}", intervals);
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(vec![], 0, vec![])]
+    #[case(vec![(closed(1, 10), 5), (closed(2, 3), 2), (closed(4, 5), 2)], 5, vec![0])]
+    #[case(vec![(closed(1, 10), 3), (closed(2, 3), 2), (closed(4, 5), 2)], 4, vec![1, 2])]
+    // an interval ending `before(3)` is compatible with one starting `at(3)`
+    #[case(vec![(end_open(1, 3), 2), (closed(3, 5), 2), (closed(2, 4), 3)], 4, vec![0, 1])]
+    #[case(vec![(closed(1, 3), 2), (closed(3, 5), 2), (closed(2, 4), 3)], 3, vec![2])]
+    // weights that do not improve the total are never selected
+    #[case(vec![(closed(1, 2), 0), (closed(4, 5), -1), (closed(7, 8), 1)], 1, vec![2])]
+    #[case(vec![(Interval::until_exclusive(0), 4), (Interval::since_inclusive(0), 4), (closed(-5, 5), 7)], 8, vec![0, 1])]
+    fn test_weighted_max_disjoint(
+        #[case] intervals: Vec<(Interval<i32>, i32)>,
+        #[case] expected_total: i32,
+        #[case] expected: Vec<usize>,
+    ) {
+        assert_eq!(
+            weighted_max_disjoint(&intervals),
+            (expected_total, expected)
+        );
+    }
+
+    #[test]
+    fn test_weighted_max_disjoint_is_maximum() {
+        let mut rng = Rng::new(400);
+        for _ in 0..300 {
+            let intervals: Vec<_> = (0..rng.range(0, 9))
+                .map(|_| (rng.interval(0, 12), rng.range(-2, 10)))
+                .collect();
+            let (total, selected) = weighted_max_disjoint(&intervals);
+            assert_eq!(
+                selected
+                    .iter()
+                    .map(|&index| intervals[index].1)
+                    .sum::<i32>(),
+                total
+            );
+            for (i, &a) in selected.iter().enumerate() {
+                for &b in &selected[i + 1..] {
+                    assert!(intervals[a].0.end < intervals[b].0.start);
+                }
+            }
+            let largest = (0u32..1 << intervals.len())
+                .filter_map(|mask| {
+                    let chosen: Vec<_> = (0..intervals.len())
+                        .filter(|index| mask & 1 << index != 0)
+                        .map(|index| &intervals[index])
+                        .collect();
+                    let disjoint = chosen.iter().enumerate().all(|(i, (a, _))| {
+                        !a.is_empty() && chosen[i + 1..].iter().all(|(b, _)| !a.overlaps(b))
+                    });
+                    disjoint.then(|| chosen.iter().map(|(_, weight)| weight).sum::<i32>())
+                })
+                .max()
+                .unwrap();
+            assert_eq!(total, largest, "failed: {:?}", intervals);
+        }
+    }
+}
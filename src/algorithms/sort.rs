@@ -0,0 +1,128 @@
+use crate::interval::Interval;
+use std::cmp::Ordering;
+
+/// Sorts the intervals by start bound, breaking ties by end bound.
+///
+/// Bounds compare with their proximity, so among intervals starting at
+/// `1`, `[1, 3)` comes before `[1, 3]`, and `(1, 2]` after both. The sort
+/// is stable: equal intervals keep their relative order.
+pub fn sort_intervals<T: Ord>(intervals: &mut [Interval<T>]) {
+    intervals.sort_by(compare);
+}
+
+/// Returns `true` if the intervals are sorted by start bound and no two
+/// share a value, the precondition of lookups that binary-search a slice.
+///
+/// Intervals meeting at a flush seam, such as `[1, 3)` and `[3, 5]`, are
+/// disjoint. Empty intervals are held to the same order as the others.
+pub fn is_sorted_and_disjoint<T: Ord>(intervals: &[Interval<T>]) -> bool {
+    intervals
+        .windows(2)
+        .all(|pair| pair[0].start < pair[1].start && pair[0].end < pair[1].start)
+}
+
+/// Removes the consecutive intervals equal to the one before them, bounds
+/// and proximities included, like `Vec::dedup`.
+///
+/// Duplicates are only found next to each other, so sort the intervals
+/// with [`sort_intervals`] first. In debug builds, panics if they are not
+/// sorted.
+pub fn dedup_structural<T: Ord>(intervals: &mut Vec<Interval<T>>) {
+    debug_assert!(is_sorted(intervals));
+    intervals.dedup();
+}
+
+/// Returns `true` if the intervals are in the order [`sort_intervals`]
+/// puts them in.
+pub(crate) fn is_sorted<T: Ord>(intervals: &[Interval<T>]) -> bool {
+    intervals
+        .windows(2)
+        .all(|pair| compare(&pair[0], &pair[1]) != Ordering::Greater)
+}
+
+fn compare<T: Ord>(a: &Interval<T>, b: &Interval<T>) -> Ordering {
+    a.start.cmp(&b.start).then_with(|| a.end.cmp(&b.end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::interval;
+    use rstest::rstest;
+
+    #[test]
+    fn test_sort_intervals() {
+        let mut intervals = vec![
+            Interval::since_inclusive(5),
+            interval(1, 3, IntervalType::Close),
+            interval(1, 2, IntervalType::StartOpen),
+            interval(1, 3, IntervalType::EndOpen),
+            Interval::until_inclusive(2),
+            interval(1, 4, IntervalType::EndOpen),
+            Interval::until_exclusive(2),
+        ];
+        sort_intervals(&mut intervals);
+        assert_eq!(
+            intervals,
+            vec![
+                Interval::until_exclusive(2),
+                Interval::until_inclusive(2),
+                interval(1, 3, IntervalType::EndOpen),
+                interval(1, 3, IntervalType::Close),
+                interval(1, 4, IntervalType::EndOpen),
+                interval(1, 2, IntervalType::StartOpen),
+                Interval::since_inclusive(5),
+            ]
+        );
+        assert!(is_sorted(&intervals));
+    }
+
+    #[rstest]
+    #[case(vec![], true)]
+    #[case(vec![interval(1, 3, IntervalType::Close)], true)]
+    #[case(vec![interval(1, 3, IntervalType::EndOpen), interval(3, 5, IntervalType::Close)], true)]
+    #[case(vec![interval(1, 3, IntervalType::Close), interval(3, 5, IntervalType::StartOpen)], true)]
+    #[case(vec![interval(1, 3, IntervalType::Close), interval(3, 5, IntervalType::Close)], false)]
+    #[case(vec![interval(4, 5, IntervalType::Close), interval(1, 2, IntervalType::Close)], false)]
+    #[case(vec![interval(1, 3, IntervalType::EndOpen), interval(1, 3, IntervalType::EndOpen)], false)]
+    #[case(vec![Interval::until_exclusive(1), interval(1, 2, IntervalType::Close), Interval::since_exclusive(2)], true)]
+    #[case(vec![Interval::until_inclusive(1), Interval::since_inclusive(1)], false)]
+    fn test_is_sorted_and_disjoint(#[case] intervals: Vec<Interval<i32>>, #[case] expected: bool) {
+        assert_eq!(is_sorted_and_disjoint(&intervals), expected);
+    }
+
+    #[test]
+    fn test_dedup_structural_keeps_different_proximities() {
+        let mut intervals = vec![
+            interval(1, 3, IntervalType::Close),
+            Interval::since_inclusive(5),
+            interval(1, 3, IntervalType::EndOpen),
+            interval(1, 3, IntervalType::Close),
+            Interval::since_inclusive(5),
+            interval(1, 3, IntervalType::StartOpen),
+        ];
+        sort_intervals(&mut intervals);
+        dedup_structural(&mut intervals);
+        assert_eq!(
+            intervals,
+            vec![
+                interval(1, 3, IntervalType::EndOpen),
+                interval(1, 3, IntervalType::Close),
+                interval(1, 3, IntervalType::StartOpen),
+                Interval::since_inclusive(5),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_dedup_structural_rejects_unsorted_input() {
+        let mut intervals = vec![
+            interval(5, 6, IntervalType::Close),
+            interval(1, 2, IntervalType::Close),
+        ];
+        dedup_structural(&mut intervals);
+    }
+}
@@ -0,0 +1,360 @@
+use crate::bound_point::{BoundPoint, BoundProximity, BoundValue};
+use crate::interval::Interval;
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Div, Mul};
+
+/// Error returned by [`Interval::recip`] and [`Interval::quotient`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivisionError {
+    /// The divisor contains zero, so the quotients do not form a single
+    /// interval: `1 / [-1, 1]` is `(-inf, -1] ∪ [1, +inf)`.
+    DivisorContainsZero,
+}
+
+impl Display for DivisionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DivisionError::DivisorContainsZero => write!(f, "divisor interval contains zero"),
+        }
+    }
+}
+
+impl std::error::Error for DivisionError {}
+
+/// Interval arithmetic over signed numbers, where the result of an
+/// operation on two intervals holds every result of the operation on
+/// one value of each. `T::default()` is taken as zero.
+///
+/// Finite values overflow like the arithmetic of `T` itself.
+impl<T> Interval<T>
+where
+    T: Ord + Clone + Default + Mul<Output = T>,
+{
+    /// Returns the interval of all products `x * y` with `x` in this
+    /// interval and `y` in `other`, so `[-2, 3] * [4, 5]` is `[-10, 15]`.
+    ///
+    /// The bounds are the least and greatest of the four products of
+    /// the bounds. A product is inclusive when both factors are, or when
+    /// one factor is an inclusive zero, which every value of the other
+    /// interval multiplies to zero: `[0, 1] * (2, 3)` is `[0, 3)`.
+    ///
+    /// An infinite bound multiplied by a nonzero one is infinite, with
+    /// the sign of the product. A zero bound multiplied by an infinite
+    /// one gives zero, as infinity is only approached and never a value:
+    /// `[0, 1] * [1, +inf)` is `[0, +inf)`.
+    ///
+    /// The product with an empty interval is empty.
+    pub fn product(&self, other: &Interval<T>) -> Interval<T> {
+        if self.is_empty() {
+            return self.clone();
+        }
+        if other.is_empty() {
+            return other.clone();
+        }
+        let corners = [
+            (&self.start, &other.start),
+            (&self.start, &other.end),
+            (&self.end, &other.start),
+            (&self.end, &other.end),
+        ];
+        let products = corners.map(|(a, b)| bound_product(a, b));
+        let start = products
+            .iter()
+            .map(|product| product.as_start())
+            .min()
+            .expect("four corners");
+        let end = products
+            .iter()
+            .map(|product| product.as_end())
+            .max()
+            .expect("four corners");
+        Interval { start, end }
+    }
+}
+
+/// Division, for types with exact division such as `NotNan<f64>`;
+/// integer division truncates, so the result of an integer interval may
+/// miss quotients. `T::from(1)` is taken as one.
+impl<T> Interval<T>
+where
+    T: Ord + Clone + Default + Mul<Output = T> + Div<Output = T> + From<u8>,
+{
+    /// Returns the interval of all reciprocals `1 / x` of the values in
+    /// this interval, so `[2, 4)` gives `(1/4, 1/2]`.
+    ///
+    /// The start of the result comes from the end of this interval and
+    /// the end from the start, each keeping whether it is inclusive. An
+    /// exclusive zero bound gives an infinite one and an infinite bound
+    /// an exclusive zero: `(0, 2]` gives `[1/2, +inf)`. The reciprocal
+    /// of an empty interval is empty.
+    ///
+    /// Fails with [`DivisionError::DivisorContainsZero`] when zero lies
+    /// within the interval.
+    pub fn recip(&self) -> Result<Interval<T>, DivisionError> {
+        let zero = T::default();
+        if self.contains_value(&zero) {
+            return Err(DivisionError::DivisorContainsZero);
+        }
+        if self.is_empty() {
+            return Ok(self.clone());
+        }
+        let recip = |value: T| T::from(1) / value;
+        let start = match &self.end.value {
+            BoundValue::PosInfinity => BoundPoint::after(zero.clone()),
+            BoundValue::Finite(value, _) if *value == zero => BoundPoint::neg_infinity(),
+            _ => self.end.clone().reflect(recip),
+        };
+        let end = match &self.start.value {
+            BoundValue::NegInfinity => BoundPoint::before(zero),
+            BoundValue::Finite(value, _) if *value == zero => BoundPoint::pos_infinity(),
+            _ => self.start.clone().reflect(recip),
+        };
+        Ok(Interval { start, end })
+    }
+
+    /// Returns the interval of all quotients `x / y` with `x` in this
+    /// interval and `y` in `divisor`, the [product](Interval::product)
+    /// of this interval and the [reciprocal](Interval::recip) of the
+    /// divisor.
+    ///
+    /// Fails with [`DivisionError::DivisorContainsZero`] when zero lies
+    /// within the divisor.
+    pub fn quotient(&self, divisor: &Interval<T>) -> Result<Interval<T>, DivisionError> {
+        Ok(self.product(&divisor.recip()?))
+    }
+}
+
+/// The product of two bounds: a value, infinite or not, and whether some
+/// pair of values of the intervals multiplies to it.
+enum Product<T> {
+    NegInfinity,
+    Finite(T, bool),
+    PosInfinity,
+}
+
+impl<T: Ord + Clone> Product<T> {
+    fn as_start(&self) -> BoundPoint<T> {
+        match self {
+            Product::NegInfinity => BoundPoint::neg_infinity(),
+            Product::Finite(value, true) => BoundPoint::at(value.clone()),
+            Product::Finite(value, false) => BoundPoint::after(value.clone()),
+            Product::PosInfinity => BoundPoint::pos_infinity(),
+        }
+    }
+
+    fn as_end(&self) -> BoundPoint<T> {
+        match self {
+            Product::NegInfinity => BoundPoint::neg_infinity(),
+            Product::Finite(value, true) => BoundPoint::at(value.clone()),
+            Product::Finite(value, false) => BoundPoint::before(value.clone()),
+            Product::PosInfinity => BoundPoint::pos_infinity(),
+        }
+    }
+}
+
+fn bound_product<T>(a: &BoundPoint<T>, b: &BoundPoint<T>) -> Product<T>
+where
+    T: Ord + Clone + Default + Mul<Output = T>,
+{
+    let zero = T::default();
+    let inclusive_zero = |point: &BoundPoint<T>| matches!(&point.value, BoundValue::Finite(value, BoundProximity::At) if *value == zero);
+    if inclusive_zero(a) || inclusive_zero(b) {
+        return Product::Finite(zero, true);
+    }
+    match (&a.value, &b.value) {
+        (BoundValue::Finite(x, x_proximity), BoundValue::Finite(y, y_proximity)) => {
+            let attained = *x_proximity == BoundProximity::At && *y_proximity == BoundProximity::At;
+            Product::Finite(x.clone() * y.clone(), attained)
+        }
+        _ => {
+            let (a_sign, b_sign) = (sign(a, &zero), sign(b, &zero));
+            if a_sign.is_eq() || b_sign.is_eq() {
+                // an exclusive zero times an infinity
+                Product::Finite(zero, false)
+            } else if a_sign == b_sign {
+                Product::PosInfinity
+            } else {
+                Product::NegInfinity
+            }
+        }
+    }
+}
+
+fn sign<T: Ord>(point: &BoundPoint<T>, zero: &T) -> Ordering {
+    match &point.value {
+        BoundValue::NegInfinity => Ordering::Less,
+        BoundValue::Finite(value, _) => value.cmp(zero),
+        BoundValue::PosInfinity => Ordering::Greater,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{closed, interval, Rng};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(closed(-2, 3), closed(4, 5), closed(-10, 15))]
+    #[case(closed(-2, -1), interval(-3, 4, IntervalType::EndOpen), interval(-8, 6, IntervalType::StartOpen))]
+    #[case(closed(2, 3), closed(-5, -4), closed(-15, -8))]
+    #[case(interval(-2, 3, IntervalType::Open), closed(-4, 1), interval(-12, 8, IntervalType::Open))]
+    #[case(
+        closed(0, 1),
+        interval(2, 3, IntervalType::Open),
+        interval(0, 3, IntervalType::EndOpen)
+    )]
+    #[case(
+        interval(0, 1, IntervalType::StartOpen),
+        closed(2, 3),
+        interval(0, 3, IntervalType::StartOpen)
+    )]
+    #[case(closed(0, 0), Interval::since_inclusive(1), closed(0, 0))]
+    #[case(
+        closed(0, 1),
+        Interval::since_inclusive(1),
+        Interval::since_inclusive(0)
+    )]
+    #[case(interval(0, 1, IntervalType::StartOpen), Interval::until_inclusive(-1), Interval::until_exclusive(0))]
+    #[case(closed(-1, 2), Interval::since_exclusive(3),
+           Interval { start: BoundPoint::neg_infinity(), end: BoundPoint::pos_infinity() })]
+    #[case(Interval::until_inclusive(-2), Interval::until_exclusive(-3), Interval::since_exclusive(6))]
+    #[case(
+        closed(1, 2),
+        interval(3, 3, IntervalType::Open),
+        interval(3, 3, IntervalType::Open)
+    )]
+    fn test_product(
+        #[case] a: Interval<i32>,
+        #[case] b: Interval<i32>,
+        #[case] expected: Interval<i32>,
+    ) {
+        assert_eq!(a.product(&b), expected);
+        assert_eq!(b.product(&a), expected);
+    }
+
+    #[rstest]
+    #[case(closed(-1, 1))]
+    #[case(closed(0, 1))]
+    #[case(interval(-1, 0, IntervalType::StartOpen))]
+    #[case(Interval::since_inclusive(0))]
+    #[case(Interval { start: BoundPoint::neg_infinity(), end: BoundPoint::pos_infinity() })]
+    fn test_divisor_contains_zero(#[case] divisor: Interval<i32>) {
+        assert_eq!(divisor.recip(), Err(DivisionError::DivisorContainsZero));
+        assert_eq!(
+            closed(1, 2).quotient(&divisor),
+            Err(DivisionError::DivisorContainsZero)
+        );
+    }
+
+    #[test]
+    fn test_product_matches_sampled_values() {
+        // values are sampled in quarters, so products come in sixteenths
+        let mut rng = Rng::new(421);
+        for _ in 0..300 {
+            let start = rng.range(-4, 4);
+            let a = interval(start, rng.range(start, 5), rng.interval_type());
+            let start = rng.range(-4, 4);
+            let b = interval(start, rng.range(start, 5), rng.interval_type());
+            if a.is_empty() || b.is_empty() {
+                continue;
+            }
+            let product = a.product(&b);
+            let scaled = |interval: &Interval<i32>, scale: i32| Interval {
+                start: interval.start.map(|value| value * scale),
+                end: interval.end.map(|value| value * scale),
+            };
+            let (a4, b4, product16) = (scaled(&a, 4), scaled(&b, 4), scaled(&product, 16));
+            let samples: Vec<i32> = (-16..=16)
+                .flat_map(|x| (-16..=16).map(move |y| (x, y)))
+                .filter(|&(x, y)| a4.contains(x) && b4.contains(y))
+                .map(|(x, y)| x * y)
+                .collect();
+            assert!(
+                samples.iter().all(|&sample| product16.contains(sample)),
+                "{} * {} = {}",
+                a,
+                b,
+                product
+            );
+            let (lowest, highest) = (samples.iter().min(), samples.iter().max());
+            for (bound, sample) in [(product16.start, lowest), (product16.end, highest)] {
+                let (&value, &sample) = (bound.finite_value().unwrap(), sample.unwrap());
+                match bound.proximity() {
+                    Some(BoundProximity::At) => assert_eq!(sample, value, "{} * {}", a, b),
+                    _ => assert!((sample - value).abs() <= 40, "{} * {}", a, b),
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "ordered-float")]
+    mod float {
+        use super::*;
+        use ordered_float::NotNan;
+
+        fn float(start: f64, end: f64, interval_type: IntervalType) -> Interval<NotNan<f64>> {
+            Interval::from_f64(start, end, interval_type).unwrap()
+        }
+
+        fn f(value: f64) -> NotNan<f64> {
+            NotNan::new(value).unwrap()
+        }
+
+        #[rstest]
+        #[case(
+            float(2.0, 4.0, IntervalType::Close),
+            float(0.25, 0.5, IntervalType::Close)
+        )]
+        #[case(
+            float(2.0, 4.0, IntervalType::EndOpen),
+            float(0.25, 0.5, IntervalType::StartOpen)
+        )]
+        #[case(
+            float(2.0, 4.0, IntervalType::StartOpen),
+            float(0.25, 0.5, IntervalType::EndOpen)
+        )]
+        #[case(float(-4.0, -2.0, IntervalType::EndOpen), float(-0.5, -0.25, IntervalType::StartOpen))]
+        #[case(
+            float(0.0, 2.0, IntervalType::StartOpen),
+            Interval::since_inclusive(f(0.5))
+        )]
+        #[case(float(-2.0, 0.0, IntervalType::EndOpen), Interval::until_inclusive(f(-0.5)))]
+        #[case(Interval::since_exclusive(f(2.0)), float(0.0, 0.5, IntervalType::Open))]
+        #[case(Interval::until_inclusive(f(-2.0)), float(-0.5, 0.0, IntervalType::EndOpen))]
+        #[case(
+            float(0.0, 0.0, IntervalType::Open),
+            float(0.0, 0.0, IntervalType::Open)
+        )]
+        fn test_recip(
+            #[case] interval: Interval<NotNan<f64>>,
+            #[case] expected: Interval<NotNan<f64>>,
+        ) {
+            assert_eq!(interval.recip(), Ok(expected));
+        }
+
+        #[rstest]
+        #[case(
+            float(1.0, 2.0, IntervalType::Close),
+            float(4.0, 8.0, IntervalType::Close),
+            float(0.125, 0.5, IntervalType::Close)
+        )]
+        #[case(float(-1.0, 2.0, IntervalType::EndOpen), float(4.0, 8.0, IntervalType::Close), float(-0.25, 0.5, IntervalType::EndOpen))]
+        #[case(float(1.0, 2.0, IntervalType::Close), float(-2.0, -1.0, IntervalType::StartOpen), float(-2.0, -0.5, IntervalType::EndOpen))]
+        #[case(
+            float(0.0, 1.0, IntervalType::Close),
+            float(0.0, 1.0, IntervalType::StartOpen),
+            Interval::since_inclusive(f(0.0))
+        )]
+        fn test_quotient(
+            #[case] dividend: Interval<NotNan<f64>>,
+            #[case] divisor: Interval<NotNan<f64>>,
+            #[case] expected: Interval<NotNan<f64>>,
+        ) {
+            assert_eq!(dividend.quotient(&divisor), Ok(expected));
+            assert_eq!(dividend / divisor, Ok(expected));
+        }
+    }
+}
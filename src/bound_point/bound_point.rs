@@ -1,13 +1,21 @@
-use crate::bound_point::bound_proximity::BoundProximity;
-use crate::bound_point::bound_value::BoundValue;
+use crate::bound_point::{seam, BoundProximity, BoundValue, Seam};
+use std::cmp::Ordering;
 
 /// A wrapper around an extended bound value, representing
 /// a specific endpoint of an interval on an ordered axis.
 ///
-/// `BoundPoint<T>` derives `PartialOrd` and `Ord` through its
-/// inner `BoundValue<T>`, allowing it to be compared and sorted
-/// alongside other bound points.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
+/// `BoundPoint<T>` is ordered exactly like its inner `BoundValue<T>`,
+/// allowing it to be compared and sorted alongside other bound points
+/// whatever their roles: `at(3)` lies above `before(3)` and below
+/// `after(3)`, be they starts or ends.
+///
+/// Starts are never `before(x)` and ends never `after(x)`:
+/// [`Interval::try_new`](crate::Interval::try_new) rejects them, and no
+/// operation builds them. Where such a bound is read anyway, as by
+/// [`seam`](crate::seam), it admits `x` like `at(x)` does.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct BoundPoint<T>
 where
     T: Ord,
@@ -15,6 +23,18 @@ where
     pub value: BoundValue<T>,
 }
 
+impl<T: Ord> PartialOrd for BoundPoint<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for BoundPoint<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
 impl<T: Ord> BoundPoint<T> {
     pub fn before(value: T) -> Self {
         Self {
@@ -45,4 +65,308 @@ impl<T: Ord> BoundPoint<T> {
             value: BoundValue::PosInfinity,
         }
     }
+
+    /// Applies `f` to the finite value, keeping the proximity, and
+    /// passes the infinities through.
+    ///
+    /// The result is only ordered like `self` if `f` preserves order.
+    pub fn map<U: Ord, F: FnOnce(T) -> U>(self, f: F) -> BoundPoint<U> {
+        BoundPoint {
+            value: match self.value {
+                BoundValue::NegInfinity => BoundValue::NegInfinity,
+                BoundValue::Finite(value, proximity) => BoundValue::Finite(f(value), proximity),
+                BoundValue::PosInfinity => BoundValue::PosInfinity,
+            },
+        }
+    }
+
+    /// Applies an order-reversing `f` to the finite value, swapping the
+    /// infinities and the `before`/`after` proximities so that the
+    /// result sits at the mirrored position.
+    pub(crate) fn reflect<U: Ord>(self, f: impl FnOnce(T) -> U) -> BoundPoint<U> {
+        BoundPoint {
+            value: match self.value {
+                BoundValue::NegInfinity => BoundValue::PosInfinity,
+                BoundValue::Finite(value, BoundProximity::Before) => {
+                    BoundValue::Finite(f(value), BoundProximity::After)
+                }
+                BoundValue::Finite(value, BoundProximity::At) => {
+                    BoundValue::Finite(f(value), BoundProximity::At)
+                }
+                BoundValue::Finite(value, BoundProximity::After) => {
+                    BoundValue::Finite(f(value), BoundProximity::Before)
+                }
+                BoundValue::PosInfinity => BoundValue::NegInfinity,
+            },
+        }
+    }
+
+    /// Like [`BoundPoint::map`], but returns the error of `f` if it
+    /// fails. Infinities never call `f`, so they never fail.
+    pub fn try_map<U: Ord, E, F: FnOnce(T) -> Result<U, E>>(
+        self,
+        f: F,
+    ) -> Result<BoundPoint<U>, E> {
+        Ok(BoundPoint {
+            value: match self.value {
+                BoundValue::NegInfinity => BoundValue::NegInfinity,
+                BoundValue::Finite(value, proximity) => BoundValue::Finite(f(value)?, proximity),
+                BoundValue::PosInfinity => BoundValue::PosInfinity,
+            },
+        })
+    }
+
+    /// Borrows the finite value, keeping the proximity.
+    pub(crate) fn as_ref(&self) -> BoundPoint<&T> {
+        BoundPoint {
+            value: match &self.value {
+                BoundValue::NegInfinity => BoundValue::NegInfinity,
+                BoundValue::Finite(value, proximity) => BoundValue::Finite(value, *proximity),
+                BoundValue::PosInfinity => BoundValue::PosInfinity,
+            },
+        }
+    }
+
+    /// Returns `true` unless the bound is one of the infinities.
+    pub fn is_finite(&self) -> bool {
+        matches!(self.value, BoundValue::Finite(..))
+    }
+
+    /// Returns `true` for the bound below every value, the start of an
+    /// interval unbounded below.
+    pub fn is_neg_infinity(&self) -> bool {
+        matches!(self.value, BoundValue::NegInfinity)
+    }
+
+    /// Returns `true` for the bound above every value, the end of an
+    /// interval unbounded above.
+    pub fn is_pos_infinity(&self) -> bool {
+        matches!(self.value, BoundValue::PosInfinity)
+    }
+
+    /// Returns the finite value of the bound, or `None` for infinities.
+    pub fn finite_value(&self) -> Option<&T> {
+        match &self.value {
+            BoundValue::Finite(value, _) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Like [`BoundPoint::finite_value`], but borrows the value mutably.
+    /// Changing it keeps the proximity.
+    pub(crate) fn finite_value_mut(&mut self) -> Option<&mut T> {
+        match &mut self.value {
+            BoundValue::Finite(value, _) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Like [`BoundPoint::finite_value`], but takes ownership.
+    pub(crate) fn into_finite_value(self) -> Option<T> {
+        match self.value {
+            BoundValue::Finite(value, _) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the proximity of the bound, or `None` for infinities.
+    pub fn proximity(&self) -> Option<BoundProximity> {
+        match &self.value {
+            BoundValue::Finite(_, proximity) => Some(*proximity),
+            _ => None,
+        }
+    }
+
+    /// Takes the finite value and its proximity out of the bound, or
+    /// returns `None` for infinities.
+    pub fn into_finite(self) -> Option<(T, BoundProximity)> {
+        match self.value {
+            BoundValue::Finite(value, proximity) => Some((value, proximity)),
+            _ => None,
+        }
+    }
+
+    /// Returns the start bound of the interval that begins exactly where
+    /// an interval ending at `self` stops, without gap or overlap.
+    ///
+    /// An end `before(x)` is followed by a start `at(x)`, and an end
+    /// `at(x)` by a start `after(x)`. Infinities have no follower.
+    pub fn adjacent_start_after(self) -> Option<Self> {
+        match self.value {
+            BoundValue::Finite(value, BoundProximity::Before) => Some(Self::at(value)),
+            BoundValue::Finite(value, _) => Some(Self::after(value)),
+            _ => None,
+        }
+    }
+
+    /// Returns the end bound of the interval that stops exactly where
+    /// an interval starting at `self` begins, without gap or overlap.
+    ///
+    /// A start `after(x)` is preceded by an end `at(x)`, and a start
+    /// `at(x)` by an end `before(x)`. Infinities have no predecessor.
+    pub fn adjacent_end_before(self) -> Option<Self> {
+        match self.value {
+            BoundValue::Finite(value, BoundProximity::After) => Some(Self::at(value)),
+            BoundValue::Finite(value, _) => Some(Self::before(value)),
+            _ => None,
+        }
+    }
+
+    /// Whether `start`, read as a start bound, begins exactly where
+    /// `self`, read as an end bound, stops: the two intervals neither
+    /// overlap nor leave a gap between them.
+    pub(crate) fn meets(&self, start: &BoundPoint<T>) -> bool {
+        seam(self, start) == Seam::Flush
+    }
+
+    /// Compares this bound with the point `at(value)` without
+    /// having to build that point, so `value` may stay borrowed.
+    pub(crate) fn cmp_value(&self, value: &T) -> Ordering {
+        match &self.value {
+            BoundValue::NegInfinity => Ordering::Less,
+            BoundValue::Finite(bound, proximity) => {
+                bound.cmp(value).then_with(|| proximity.cmp(&BoundProximity::At))
+            }
+            BoundValue::PosInfinity => Ordering::Greater,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Rng;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(BoundPoint::neg_infinity(), None, None, true, false)]
+    #[case(
+        BoundPoint::before(3),
+        Some(3),
+        Some(BoundProximity::Before),
+        false,
+        false
+    )]
+    #[case(BoundPoint::at(3), Some(3), Some(BoundProximity::At), false, false)]
+    #[case(
+        BoundPoint::after(3),
+        Some(3),
+        Some(BoundProximity::After),
+        false,
+        false
+    )]
+    #[case(BoundPoint::pos_infinity(), None, None, false, true)]
+    fn test_accessors(
+        #[case] point: BoundPoint<i32>,
+        #[case] value: Option<i32>,
+        #[case] proximity: Option<BoundProximity>,
+        #[case] is_neg_infinity: bool,
+        #[case] is_pos_infinity: bool,
+    ) {
+        assert_eq!(point.finite_value(), value.as_ref());
+        assert_eq!(point.proximity(), proximity);
+        assert_eq!(point.is_finite(), value.is_some());
+        assert_eq!(point.is_neg_infinity(), is_neg_infinity);
+        assert_eq!(point.is_pos_infinity(), is_pos_infinity);
+        assert_eq!(point.into_finite(), value.zip(proximity));
+    }
+
+    #[rstest]
+    #[case(BoundPoint::neg_infinity(), BoundPoint::neg_infinity())]
+    #[case(BoundPoint::before(3), BoundPoint::before(3000))]
+    #[case(BoundPoint::at(3), BoundPoint::at(3000))]
+    #[case(BoundPoint::after(3), BoundPoint::after(3000))]
+    #[case(BoundPoint::pos_infinity(), BoundPoint::pos_infinity())]
+    fn test_map(#[case] point: BoundPoint<u8>, #[case] expected: BoundPoint<i64>) {
+        assert_eq!(point.map(|value| i64::from(value) * 1000), expected);
+        assert_eq!(
+            point.try_map(|value| Ok::<_, ()>(i64::from(value) * 1000)),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_try_map_fails_for_one_bound() {
+        let (start, end) = (BoundPoint::at(100i32), BoundPoint::before(300i32));
+        assert_eq!(start.try_map(u8::try_from), Ok(BoundPoint::at(100u8)));
+        assert!(end.try_map(u8::try_from).is_err());
+        assert_eq!(
+            BoundPoint::<i32>::pos_infinity().try_map(|_| Err::<u8, _>("never called")),
+            Ok(BoundPoint::pos_infinity())
+        );
+    }
+
+    #[rstest]
+    #[case(BoundPoint::before(3), Some(BoundPoint::at(3)))]
+    #[case(BoundPoint::at(3), Some(BoundPoint::after(3)))]
+    #[case(BoundPoint::neg_infinity(), None)]
+    #[case(BoundPoint::pos_infinity(), None)]
+    fn test_adjacent_start_after(
+        #[case] end: BoundPoint<i32>,
+        #[case] expected: Option<BoundPoint<i32>>,
+    ) {
+        assert_eq!(end.adjacent_start_after(), expected);
+        if let Some(start) = expected {
+            assert!(end.meets(&start));
+            assert_eq!(start.adjacent_end_before(), Some(end));
+        }
+    }
+
+    #[rstest]
+    #[case(BoundPoint::after(3), Some(BoundPoint::at(3)))]
+    #[case(BoundPoint::at(3), Some(BoundPoint::before(3)))]
+    #[case(BoundPoint::neg_infinity(), None)]
+    #[case(BoundPoint::pos_infinity(), None)]
+    fn test_adjacent_end_before(
+        #[case] start: BoundPoint<i32>,
+        #[case] expected: Option<BoundPoint<i32>>,
+    ) {
+        assert_eq!(start.adjacent_end_before(), expected);
+        if let Some(end) = expected {
+            assert!(end.meets(&start));
+            assert_eq!(end.adjacent_start_after(), Some(start));
+        }
+    }
+
+    fn random_point(rng: &mut Rng) -> BoundPoint<i32> {
+        let value = rng.range(-2, 3);
+        match rng.range(0, 5) {
+            0 => BoundPoint::neg_infinity(),
+            1 => BoundPoint::before(value),
+            2 => BoundPoint::at(value),
+            3 => BoundPoint::after(value),
+            _ => BoundPoint::pos_infinity(),
+        }
+    }
+
+    /// The documented ordering, spelled out independently of the impls.
+    fn key(point: &BoundPoint<i32>) -> (u8, i32, u8) {
+        match point.value {
+            BoundValue::NegInfinity => (0, 0, 0),
+            BoundValue::Finite(value, BoundProximity::Before) => (1, value, 0),
+            BoundValue::Finite(value, BoundProximity::At) => (1, value, 1),
+            BoundValue::Finite(value, BoundProximity::After) => (1, value, 2),
+            BoundValue::PosInfinity => (2, 0, 0),
+        }
+    }
+
+    #[test]
+    fn test_ordering_properties() {
+        let mut rng = Rng::new(431);
+        for _ in 0..5000 {
+            let (a, b, c) = (
+                random_point(&mut rng),
+                random_point(&mut rng),
+                random_point(&mut rng),
+            );
+            assert_eq!(a.cmp(&b), key(&a).cmp(&key(&b)), "{a:?} {b:?}");
+            assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+            assert_eq!(b.cmp(&a), a.cmp(&b).reverse());
+            assert_eq!(a == b, a.cmp(&b) == Ordering::Equal);
+            assert_eq!(a.cmp_value(&0), a.cmp(&BoundPoint::at(0)));
+            if a <= b && b <= c {
+                assert!(a <= c, "{a:?} {b:?} {c:?}");
+            }
+        }
+    }
 }
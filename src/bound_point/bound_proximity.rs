@@ -1,12 +1,16 @@
+use std::cmp::Ordering;
+
 /// Indicates the relative position of a finite bound
 /// in the context of an interval.
 ///
 /// This enum is used to distinguish whether a bound
 /// should be considered just before, exactly at,
-/// or just after a given value. It derives
-/// `PartialOrd` and `Ord`, with the natural ordering:
-/// `Before < At < After`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// or just after a given value. It implements
+/// `PartialOrd` and `Ord` by hand, with the natural ordering:
+/// `Before < At < After`, so that reordering the variants
+/// cannot change it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BoundProximity {
     /// The bound lies immediately _before_ the value.
     ///
@@ -25,4 +29,45 @@ pub enum BoundProximity {
     /// Use this for an _exclusive lower bound_,
     /// e.g. `(value, …)`.
     After,
-}
\ No newline at end of file
+}
+
+impl BoundProximity {
+    /// The position of the proximity in the ordering.
+    fn rank(self) -> u8 {
+        match self {
+            BoundProximity::Before => 0,
+            BoundProximity::At => 1,
+            BoundProximity::After => 2,
+        }
+    }
+}
+
+impl PartialOrd for BoundProximity {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BoundProximity {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ordering() {
+        use BoundProximity::{After, At, Before};
+        let ordered = [Before, At, After];
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert_eq!(a.cmp(b), i.cmp(&j), "{a:?} {b:?}");
+                assert_eq!(a.partial_cmp(b), Some(i.cmp(&j)));
+                assert_eq!(a == b, i == j);
+            }
+        }
+    }
+}
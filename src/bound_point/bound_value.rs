@@ -1,14 +1,18 @@
-use crate::bound_point::bound_proximity::BoundProximity;
+use crate::bound_point::BoundProximity;
+use std::cmp::Ordering;
 
 /// Represents an extended bound point on an ordered axis,
 /// allowing for negative infinity, finite values with
 /// precise inclusion/exclusion semantics, or positive infinity.
 ///
-/// This enum derives `PartialOrd` and `Ord`, with the following ordering:
+/// This enum implements `PartialOrd` and `Ord` by hand, with the
+/// following ordering, which does not depend on the variant order:
 /// `NegInfinity < Finite(value, proximity) < PosInfinity`.
 /// Within `Finite`, comparisons first use the inner `T` value,
-/// then the `BoundProximity`.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
+/// then the `BoundProximity`, so even `Finite(T::MIN, Before)` lies
+/// above `NegInfinity` and `Finite(T::MAX, After)` below `PosInfinity`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BoundValue<T>
 where
     T: Ord,
@@ -32,4 +36,62 @@ where
     ///
     /// This is always greater than any `Finite` bound or `NegInfinity`.
     PosInfinity,
-}
\ No newline at end of file
+}
+
+impl<T: Ord> PartialOrd for BoundValue<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for BoundValue<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (BoundValue::NegInfinity, BoundValue::NegInfinity)
+            | (BoundValue::PosInfinity, BoundValue::PosInfinity) => Ordering::Equal,
+            (BoundValue::NegInfinity, _) | (_, BoundValue::PosInfinity) => Ordering::Less,
+            (_, BoundValue::NegInfinity) | (BoundValue::PosInfinity, _) => Ordering::Greater,
+            (BoundValue::Finite(a, a_proximity), BoundValue::Finite(b, b_proximity)) => {
+                a.cmp(b).then_with(|| a_proximity.cmp(b_proximity))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        BoundValue::NegInfinity,
+        BoundValue::Finite(i32::MIN, BoundProximity::Before)
+    )]
+    #[case(
+        BoundValue::Finite(i32::MAX, BoundProximity::After),
+        BoundValue::PosInfinity
+    )]
+    #[case(BoundValue::NegInfinity, BoundValue::PosInfinity)]
+    #[case(
+        BoundValue::Finite(1, BoundProximity::Before),
+        BoundValue::Finite(1, BoundProximity::At)
+    )]
+    #[case(
+        BoundValue::Finite(1, BoundProximity::At),
+        BoundValue::Finite(1, BoundProximity::After)
+    )]
+    // the value decides before the proximity
+    #[case(
+        BoundValue::Finite(1, BoundProximity::After),
+        BoundValue::Finite(2, BoundProximity::Before)
+    )]
+    #[case(BoundValue::Finite(-1, BoundProximity::After), BoundValue::Finite(0, BoundProximity::Before))]
+    fn test_ordering(#[case] lower: BoundValue<i32>, #[case] higher: BoundValue<i32>) {
+        assert_eq!(lower.cmp(&higher), Ordering::Less);
+        assert_eq!(higher.cmp(&lower), Ordering::Greater);
+        assert_eq!(lower.partial_cmp(&higher), Some(Ordering::Less));
+        assert_eq!(lower.cmp(&lower), Ordering::Equal);
+        assert_eq!(higher.cmp(&higher), Ordering::Equal);
+    }
+}
@@ -1,5 +1,10 @@
 mod bound_proximity;
 mod bound_value;
+mod seam;
+#[allow(clippy::module_inception)]
 mod bound_point;
 
-pub use bound_point::BoundPoint;
\ No newline at end of file
+pub use bound_point::BoundPoint;
+pub use bound_proximity::BoundProximity;
+pub use bound_value::BoundValue;
+pub use seam::{seam, Seam};
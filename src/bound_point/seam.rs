@@ -0,0 +1,95 @@
+use crate::bound_point::{BoundPoint, BoundProximity, BoundValue};
+use std::cmp::Ordering;
+
+/// How an interval ending at some bound relates to one starting at
+/// another, as classified by [`seam`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seam {
+    /// The two sides share at least one value, like an end `at(3)` and
+    /// a start `at(3)`.
+    Overlapping,
+
+    /// No value is shared and none is left out between them, like an
+    /// end `before(3)` and a start `at(3)`.
+    Flush,
+
+    /// At least one value lies between them, like the point `3` between
+    /// an end `before(3)` and a start `after(3)`.
+    Gapped,
+}
+
+/// Classifies the seam between an interval ending at `end` and one
+/// starting at `start`.
+///
+/// Bounds are read by the values they admit: a start `before(x)`
+/// admits `x` like `at(x)` does, and so does an end `after(x)`. An end
+/// at `+inf` or a start at `-inf` overlaps anything, while an end at
+/// `-inf` or a start at `+inf` leaves a gap. Distinct finite values
+/// either overlap or leave a gap, never meet flush, as the type may
+/// hold values between them.
+pub fn seam<T: Ord>(end: &BoundPoint<T>, start: &BoundPoint<T>) -> Seam {
+    match (&end.value, &start.value) {
+        (BoundValue::PosInfinity, _) | (_, BoundValue::NegInfinity) => Seam::Overlapping,
+        (BoundValue::NegInfinity, _) | (_, BoundValue::PosInfinity) => Seam::Gapped,
+        (BoundValue::Finite(end, end_proximity), BoundValue::Finite(start, start_proximity)) => {
+            match end.cmp(start) {
+                Ordering::Less => Seam::Gapped,
+                Ordering::Greater => Seam::Overlapping,
+                Ordering::Equal => {
+                    let excludes_end = *end_proximity == BoundProximity::Before;
+                    let excludes_start = *start_proximity == BoundProximity::After;
+                    match (excludes_end, excludes_start) {
+                        (false, false) => Seam::Overlapping,
+                        (true, true) => Seam::Gapped,
+                        _ => Seam::Flush,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    // the 3x3 proximity matrix at a shared value
+    #[case(BoundPoint::before(3), BoundPoint::before(3), Seam::Flush)]
+    #[case(BoundPoint::before(3), BoundPoint::at(3), Seam::Flush)]
+    #[case(BoundPoint::before(3), BoundPoint::after(3), Seam::Gapped)]
+    #[case(BoundPoint::at(3), BoundPoint::before(3), Seam::Overlapping)]
+    #[case(BoundPoint::at(3), BoundPoint::at(3), Seam::Overlapping)]
+    #[case(BoundPoint::at(3), BoundPoint::after(3), Seam::Flush)]
+    #[case(BoundPoint::after(3), BoundPoint::before(3), Seam::Overlapping)]
+    #[case(BoundPoint::after(3), BoundPoint::at(3), Seam::Overlapping)]
+    #[case(BoundPoint::after(3), BoundPoint::after(3), Seam::Flush)]
+    // distinct values never meet flush, whatever the proximities
+    #[case(BoundPoint::after(2), BoundPoint::before(3), Seam::Gapped)]
+    #[case(BoundPoint::at(2), BoundPoint::at(3), Seam::Gapped)]
+    #[case(BoundPoint::before(4), BoundPoint::after(3), Seam::Overlapping)]
+    // infinities
+    #[case(BoundPoint::pos_infinity(), BoundPoint::after(3), Seam::Overlapping)]
+    #[case(
+        BoundPoint::pos_infinity(),
+        BoundPoint::pos_infinity(),
+        Seam::Overlapping
+    )]
+    #[case(BoundPoint::before(3), BoundPoint::neg_infinity(), Seam::Overlapping)]
+    #[case(
+        BoundPoint::neg_infinity(),
+        BoundPoint::neg_infinity(),
+        Seam::Overlapping
+    )]
+    #[case(BoundPoint::neg_infinity(), BoundPoint::at(3), Seam::Gapped)]
+    #[case(BoundPoint::at(3), BoundPoint::pos_infinity(), Seam::Gapped)]
+    #[case(BoundPoint::neg_infinity(), BoundPoint::pos_infinity(), Seam::Gapped)]
+    fn test_seam(
+        #[case] end: BoundPoint<i32>,
+        #[case] start: BoundPoint<i32>,
+        #[case] expected: Seam,
+    ) {
+        assert_eq!(seam(&end, &start), expected);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::interval::Interval;
+
+/// A rectangular region: the product of an interval on the x axis and
+/// an interval on the y axis.
+///
+/// Each axis keeps its own bound kinds, so a tile can be closed on x
+/// and half-open on y. Every operation applies the matching interval
+/// operation to each axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox<X, Y>
+where
+    X: Ord,
+    Y: Ord,
+{
+    x: Interval<X>,
+    y: Interval<Y>,
+}
+
+impl<X: Ord, Y: Ord> BoundingBox<X, Y> {
+    pub fn new(x: Interval<X>, y: Interval<Y>) -> Self {
+        BoundingBox { x, y }
+    }
+
+    pub fn x(&self) -> &Interval<X> {
+        &self.x
+    }
+
+    pub fn y(&self) -> &Interval<Y> {
+        &self.y
+    }
+
+    /// Returns `true` if no point lies within the box, which is the case
+    /// when either axis interval is empty.
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty() || self.y.is_empty()
+    }
+
+    /// Returns `true` if the point `(x, y)` lies within both axis intervals.
+    pub fn contains(&self, (x, y): &(X, Y)) -> bool {
+        self.x.contains_value(x) && self.y.contains_value(y)
+    }
+
+    /// Returns `true` if the boxes share at least one point, which
+    /// requires them to overlap on both axes.
+    pub fn overlaps(&self, other: &BoundingBox<X, Y>) -> bool {
+        self.x.overlaps(&other.x) && self.y.overlaps(&other.y)
+    }
+}
+
+impl<X: Ord + Clone, Y: Ord + Clone> BoundingBox<X, Y> {
+    /// Returns the points shared by both boxes, or `None` when they do
+    /// not overlap on some axis.
+    pub fn intersection(&self, other: &BoundingBox<X, Y>) -> Option<BoundingBox<X, Y>> {
+        Some(BoundingBox {
+            x: self.x.intersection(&other.x)?,
+            y: self.y.intersection(&other.y)?,
+        })
+    }
+
+    /// Returns the smallest box containing both boxes, as the hull of
+    /// each axis.
+    pub fn hull(&self, other: &BoundingBox<X, Y>) -> BoundingBox<X, Y> {
+        BoundingBox {
+            x: self.x.hull(&other.x),
+            y: self.y.hull(&other.y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use rstest::rstest;
+
+    /// A tile of a grid: closed on x, half-open on y.
+    fn tile(x0: i32, x1: i32, y0: i64, y1: i64) -> BoundingBox<i32, i64> {
+        BoundingBox::new(
+            Interval::from_to(x0, x1, IntervalType::Close).unwrap(),
+            Interval::from_to(y0, y1, IntervalType::EndOpen).unwrap(),
+        )
+    }
+
+    #[rstest]
+    #[case((0, 0), true)]
+    #[case((10, 0), true)]
+    #[case((5, 9), true)]
+    #[case((5, 10), false)]
+    #[case((11, 5), false)]
+    #[case((-1, 5), false)]
+    fn test_contains(#[case] point: (i32, i64), #[case] expected: bool) {
+        assert_eq!(tile(0, 10, 0, 10).contains(&point), expected);
+    }
+
+    #[rstest]
+    #[case(tile(5, 15, 5, 15), Some(tile(5, 10, 5, 10)))]
+    // closed x axes touching at 10 share a line
+    #[case(tile(10, 20, 0, 10), Some(tile(10, 10, 0, 10)))]
+    // half-open y axes touching at 10 share nothing
+    #[case(tile(0, 10, 10, 20), None)]
+    // overlapping on x only
+    #[case(tile(5, 15, 20, 30), None)]
+    fn test_intersection(
+        #[case] other: BoundingBox<i32, i64>,
+        #[case] expected: Option<BoundingBox<i32, i64>>,
+    ) {
+        let tile = tile(0, 10, 0, 10);
+        assert_eq!(tile.intersection(&other), expected);
+        assert_eq!(other.intersection(&tile), expected);
+        assert_eq!(tile.overlaps(&other), expected.is_some());
+        assert_eq!(other.overlaps(&tile), expected.is_some());
+    }
+
+    #[test]
+    fn test_hull() {
+        let hull = tile(0, 10, 0, 10).hull(&tile(20, 30, -5, 5));
+        assert_eq!(hull, tile(0, 30, -5, 10));
+        assert!(hull.contains(&(15, 7)));
+    }
+
+    #[test]
+    fn test_is_empty() {
+        assert!(!tile(0, 0, 0, 1).is_empty());
+        assert!(tile(0, 10, 3, 3).is_empty());
+        assert!(!tile(0, 10, 3, 3).contains(&(5, 3)));
+    }
+}
@@ -0,0 +1,152 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::{Interval, IntervalError};
+use crate::interval_set::IntervalSet;
+
+/// A comparison a value must satisfy, such as `x >= 3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Constraint<T> {
+    /// `x > value`
+    GreaterThan(T),
+
+    /// `x >= value`
+    AtLeast(T),
+
+    /// `x < value`
+    LessThan(T),
+
+    /// `x <= value`
+    AtMost(T),
+
+    /// `x == value`
+    EqualTo(T),
+
+    /// `x != value`
+    NotEqualTo(T),
+}
+
+impl<T: Ord + Clone> IntervalSet<T> {
+    /// Returns the set of values satisfying every constraint at once.
+    ///
+    /// Bounds on the same side keep the stricter one, so `AtLeast(3)`
+    /// and `GreaterThan(3)` give `(3, +inf)`, and each `NotEqualTo`
+    /// punches its value out of the result. No constraints at all give
+    /// the whole axis.
+    ///
+    /// When the constraints leave no value, fails with
+    /// [`IntervalError::Contradiction`] naming the first constraint
+    /// after which nothing was left and the earlier one it conflicts
+    /// with, by their positions: `[AtLeast(5), AtMost(9), LessThan(4)]`
+    /// fails with `Contradiction(0, 2)`.
+    pub fn from_constraints<I>(constraints: I) -> Result<IntervalSet<T>, IntervalError>
+    where
+        I: IntoIterator<Item = Constraint<T>>,
+    {
+        let mut hull = Interval {
+            start: BoundPoint::neg_infinity(),
+            end: BoundPoint::pos_infinity(),
+        };
+        // positions of the constraints setting the current bounds
+        let (mut lower, mut upper) = (None, None);
+        let mut holes: Vec<(usize, T)> = Vec::new();
+
+        for (i, constraint) in constraints.into_iter().enumerate() {
+            let (start, end) = match constraint {
+                Constraint::GreaterThan(value) => (Some(BoundPoint::after(value)), None),
+                Constraint::AtLeast(value) => (Some(BoundPoint::at(value)), None),
+                Constraint::LessThan(value) => (None, Some(BoundPoint::before(value))),
+                Constraint::AtMost(value) => (None, Some(BoundPoint::at(value))),
+                Constraint::EqualTo(value) => (
+                    Some(BoundPoint::at(value.clone())),
+                    Some(BoundPoint::at(value)),
+                ),
+                Constraint::NotEqualTo(value) => {
+                    holes.push((i, value));
+                    (None, None)
+                }
+            };
+            let mut raised = false;
+            if let Some(start) = start.filter(|start| *start > hull.start) {
+                hull.start = start;
+                (lower, raised) = (Some(i), true);
+            }
+            if let Some(end) = end.filter(|end| *end < hull.end) {
+                hull.end = end;
+                upper = Some(i);
+            }
+
+            if hull.is_empty() {
+                let other = if raised { upper } else { lower };
+                return Err(IntervalError::Contradiction(other.unwrap_or(i), i));
+            }
+            let hole = holes.iter().find(|(_, value)| {
+                hull.start == BoundPoint::at(value.clone())
+                    && hull.end == BoundPoint::at(value.clone())
+            });
+            if let Some(&(j, _)) = hole {
+                // a single value is left and punched out
+                let other = if j == i {
+                    lower.max(upper).unwrap_or(i)
+                } else {
+                    j
+                };
+                return Err(IntervalError::Contradiction(other, i));
+            }
+        }
+
+        let mut set = IntervalSet::from(vec![hull]);
+        for (_, value) in holes {
+            set.remove(&Interval {
+                start: BoundPoint::at(value.clone()),
+                end: BoundPoint::at(value),
+            });
+        }
+        Ok(set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![], vec![Interval { start: BoundPoint::neg_infinity(), end: BoundPoint::pos_infinity() }])]
+    #[case(vec![Constraint::AtLeast(3), Constraint::LessThan(10)], vec![Interval::from_to(3, 10, IntervalType::EndOpen).unwrap()])]
+    // the stricter bound on the same value wins, in either order
+    #[case(vec![Constraint::AtLeast(3), Constraint::GreaterThan(3)], vec![Interval::since_exclusive(3)])]
+    #[case(vec![Constraint::GreaterThan(3), Constraint::AtLeast(3)], vec![Interval::since_exclusive(3)])]
+    #[case(vec![Constraint::AtMost(8), Constraint::LessThan(8), Constraint::AtMost(9)], vec![Interval::until_exclusive(8)])]
+    #[case(vec![Constraint::GreaterThan(5), Constraint::LessThan(8)], vec![Interval::from_to(5, 8, IntervalType::Open).unwrap()])]
+    #[case(vec![Constraint::EqualTo(4), Constraint::AtLeast(4)], vec![Interval::from_to(4, 4, IntervalType::Close).unwrap()])]
+    #[case(vec![Constraint::AtLeast(0), Constraint::NotEqualTo(5), Constraint::AtMost(9)],
+           vec![Interval::from_to(0, 5, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 9, IntervalType::StartOpen).unwrap()])]
+    // punching out a bound trims it, and values outside change nothing
+    #[case(vec![Constraint::AtLeast(0), Constraint::NotEqualTo(0), Constraint::AtMost(10), Constraint::NotEqualTo(20)], vec![Interval::from_to(0, 10, IntervalType::StartOpen).unwrap()])]
+    fn test_from_constraints(
+        #[case] constraints: Vec<Constraint<i32>>,
+        #[case] expected: Vec<Interval<i32>>,
+    ) {
+        assert_eq!(
+            IntervalSet::from_constraints(constraints),
+            Ok(IntervalSet::from(expected))
+        );
+    }
+
+    #[rstest]
+    #[case(vec![Constraint::AtLeast(5), Constraint::AtMost(9), Constraint::LessThan(4)], (0, 2))]
+    #[case(vec![Constraint::AtLeast(3), Constraint::LessThan(3)], (0, 1))]
+    #[case(vec![Constraint::LessThan(3), Constraint::AtLeast(3)], (0, 1))]
+    #[case(vec![Constraint::EqualTo(3), Constraint::AtMost(9), Constraint::EqualTo(4)], (0, 2))]
+    #[case(vec![Constraint::AtLeast(3), Constraint::AtMost(3), Constraint::NotEqualTo(3)], (1, 2))]
+    #[case(vec![Constraint::NotEqualTo(3), Constraint::AtLeast(0), Constraint::EqualTo(3)], (0, 2))]
+    fn test_from_constraints_contradiction(
+        #[case] constraints: Vec<Constraint<i32>>,
+        #[case] pair: (usize, usize),
+    ) {
+        assert_eq!(
+            IntervalSet::from_constraints(constraints),
+            Err(IntervalError::Contradiction(pair.0, pair.1))
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::{Interval, IntervalType};
+use std::fmt::{self, Display, Formatter};
+
+/// Error returned by [`CyclicInterval::new`] when a bound value
+/// lies outside the domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutsideDomainError;
+
+impl Display for OutsideDomainError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "interval bound lies outside the cyclic domain")
+    }
+}
+
+impl std::error::Error for OutsideDomainError {}
+
+/// An interval on a cyclic domain, such as the minutes of a day or
+/// the degrees of a compass, which may wrap around the domain end.
+///
+/// When the start value exceeds the end value, as in `22:00–02:00` or
+/// `350°–10°`, the interval wraps: it covers everything from its start
+/// to the end of the domain, and everything from the start of the
+/// domain to its end. Otherwise it behaves like an ordinary interval.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CyclicInterval<T>
+where
+    T: Ord,
+{
+    domain: Interval<T>,
+    start: BoundPoint<T>,
+    end: BoundPoint<T>,
+}
+
+impl<T: Ord> CyclicInterval<T> {
+    /// Builds the interval from `start` to `end` within `domain`,
+    /// wrapping around when `start > end`.
+    ///
+    /// Both values must lie within `domain`, e.g. `[0, 1440)` for the
+    /// minutes of a day.
+    pub fn new(
+        start: T,
+        end: T,
+        interval_type: IntervalType,
+        domain: Interval<T>,
+    ) -> Result<Self, OutsideDomainError> {
+        if !domain.contains_value(&start) || !domain.contains_value(&end) {
+            return Err(OutsideDomainError);
+        }
+        let (start, end) = match interval_type {
+            IntervalType::Open => (BoundPoint::after(start), BoundPoint::before(end)),
+            IntervalType::StartOpen => (BoundPoint::after(start), BoundPoint::at(end)),
+            IntervalType::EndOpen => (BoundPoint::at(start), BoundPoint::before(end)),
+            IntervalType::Close => (BoundPoint::at(start), BoundPoint::at(end)),
+        };
+        Ok(CyclicInterval { domain, start, end })
+    }
+
+    pub fn domain(&self) -> &Interval<T> {
+        &self.domain
+    }
+
+    /// Returns `true` if the interval wraps around the end of the domain.
+    pub fn is_wrapping(&self) -> bool {
+        self.start.finite_value() > self.end.finite_value()
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.pieces()
+            .into_iter()
+            .flatten()
+            .any(|(start, end)| start.cmp_value(value).is_le() && end.cmp_value(value).is_ge())
+    }
+
+    /// Returns `true` if the interval shares at least one value with
+    /// `other`. Empty pieces overlap nothing.
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.pieces()
+            .into_iter()
+            .flatten()
+            .any(|piece| pieces_overlap(piece, (&other.start, &other.end)))
+    }
+
+    /// Returns `true` if both intervals share at least one value.
+    pub fn overlaps_cyclic(&self, other: &CyclicInterval<T>) -> bool {
+        self.pieces().into_iter().flatten().any(|piece| {
+            other
+                .pieces()
+                .into_iter()
+                .flatten()
+                .any(|other| pieces_overlap(piece, other))
+        })
+    }
+
+    /// Returns the bounds of the ordinary pieces covering the same values:
+    /// one piece, or two for a wrapping interval.
+    fn pieces(&self) -> [Option<(&BoundPoint<T>, &BoundPoint<T>)>; 2] {
+        if self.is_wrapping() {
+            [
+                Some((&self.start, &self.domain.end)),
+                Some((&self.domain.start, &self.end)),
+            ]
+        } else {
+            [Some((&self.start, &self.end)), None]
+        }
+    }
+}
+
+impl<T: Ord + Clone> CyclicInterval<T> {
+    /// Returns the ordinary intervals covering the same values, so the
+    /// rest of the crate can consume them: the interval itself, or for
+    /// a wrapping interval the piece up to the domain end followed by
+    /// the piece from the domain start.
+    pub fn to_linear_pieces(&self) -> (Interval<T>, Option<Interval<T>>) {
+        let [first, second] = self.pieces().map(|piece| {
+            piece.map(|(start, end)| Interval {
+                start: start.clone(),
+                end: end.clone(),
+            })
+        });
+        (first.expect("there is always a first piece"), second)
+    }
+}
+
+fn pieces_overlap<T: Ord>(
+    (start, end): (&BoundPoint<T>, &BoundPoint<T>),
+    (other_start, other_end): (&BoundPoint<T>, &BoundPoint<T>),
+) -> bool {
+    start <= end && other_start <= other_end && start <= other_end && end >= other_start
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const DAY: i32 = 24 * 60;
+
+    fn minutes(hours: i32, minutes: i32) -> i32 {
+        hours * 60 + minutes
+    }
+
+    fn time_of_day(start: i32, end: i32, interval_type: IntervalType) -> CyclicInterval<i32> {
+        let day = Interval::from_to(0, DAY, IntervalType::EndOpen).unwrap();
+        CyclicInterval::new(start, end, interval_type, day).unwrap()
+    }
+
+    fn night() -> CyclicInterval<i32> {
+        time_of_day(minutes(22, 0), minutes(2, 0), IntervalType::EndOpen)
+    }
+
+    #[rstest]
+    #[case(minutes(23, 30), true)]
+    #[case(minutes(1, 0), true)]
+    #[case(minutes(12, 0), false)]
+    #[case(minutes(22, 0), true)]
+    #[case(minutes(2, 0), false)]
+    #[case(0, true)]
+    #[case(DAY - 1, true)]
+    // outside the domain
+    #[case(DAY, false)]
+    #[case(-1, false)]
+    fn test_contains(#[case] value: i32, #[case] expected: bool) {
+        assert!(night().is_wrapping());
+        assert_eq!(night().contains(&value), expected);
+    }
+
+    #[test]
+    fn test_heading_range() {
+        let compass = Interval::from_to(0, 360, IntervalType::EndOpen).unwrap();
+        let north = CyclicInterval::new(350, 10, IntervalType::Close, compass).unwrap();
+        assert!(north.contains(&355));
+        assert!(north.contains(&0));
+        assert!(north.contains(&10));
+        assert!(!north.contains(&180));
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(minutes(21, 0), minutes(22, 0), IntervalType::EndOpen).unwrap(), false)]
+    #[case(Interval::from_to(minutes(21, 0), minutes(22, 0), IntervalType::Close).unwrap(), true)]
+    #[case(Interval::from_to(minutes(1, 0), minutes(3, 0), IntervalType::Close).unwrap(), true)]
+    #[case(Interval::from_to(minutes(2, 0), minutes(3, 0), IntervalType::Close).unwrap(), false)]
+    #[case(Interval::from_to(minutes(9, 0), minutes(17, 0), IntervalType::Close).unwrap(), false)]
+    #[case(Interval::from_to(minutes(1, 0), minutes(1, 0), IntervalType::Open).unwrap(), false)]
+    fn test_overlaps(#[case] other: Interval<i32>, #[case] expected: bool) {
+        assert_eq!(night().overlaps(&other), expected);
+    }
+
+    #[rstest]
+    #[case(time_of_day(minutes(23, 0), minutes(1, 0), IntervalType::Close), true)]
+    #[case(time_of_day(minutes(1, 0), minutes(3, 0), IntervalType::Close), true)]
+    #[case(time_of_day(minutes(2, 0), minutes(22, 0), IntervalType::Open), false)]
+    #[case(
+        time_of_day(minutes(2, 0), minutes(22, 0), IntervalType::StartOpen),
+        true
+    )]
+    #[case(time_of_day(minutes(3, 0), minutes(1, 0), IntervalType::Close), true)]
+    fn test_overlaps_cyclic(#[case] other: CyclicInterval<i32>, #[case] expected: bool) {
+        assert_eq!(night().overlaps_cyclic(&other), expected);
+        assert_eq!(other.overlaps_cyclic(&night()), expected);
+    }
+
+    #[test]
+    fn test_to_linear_pieces() {
+        assert_eq!(
+            night().to_linear_pieces(),
+            (
+                Interval::from_to(minutes(22, 0), DAY, IntervalType::EndOpen).unwrap(),
+                Some(Interval::from_to(0, minutes(2, 0), IntervalType::EndOpen).unwrap())
+            )
+        );
+        let office = time_of_day(minutes(9, 0), minutes(17, 0), IntervalType::Close);
+        assert!(!office.is_wrapping());
+        assert_eq!(
+            office.to_linear_pieces(),
+            (
+                Interval::from_to(minutes(9, 0), minutes(17, 0), IntervalType::Close).unwrap(),
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_bounds_outside_domain_are_rejected() {
+        let day = Interval::from_to(0, DAY, IntervalType::EndOpen).unwrap();
+        assert_eq!(
+            CyclicInterval::new(minutes(22, 0), DAY, IntervalType::Close, day),
+            Err(OutsideDomainError)
+        );
+    }
+}
@@ -0,0 +1,475 @@
+use crate::bound_point::{BoundPoint, BoundProximity, BoundValue};
+use crate::integer::Integer;
+use crate::interval::Interval;
+use std::fmt::{self, Display, Formatter};
+
+/// A type whose values follow each other in steps, like integers, so an
+/// exclusive bound can be turned into an inclusive one on the next value.
+pub trait Discrete: Ord + Sized {
+    /// Returns the next value, or `None` at the top of the type.
+    fn succ(&self) -> Option<Self>;
+
+    /// Returns the previous value, or `None` at the bottom of the type.
+    fn pred(&self) -> Option<Self>;
+
+    /// Returns how many steps lead from `self` up to `later`, or `None`
+    /// when `later` comes before `self` or the count does not fit.
+    fn steps_to(&self, later: &Self) -> Option<u128>;
+
+    /// Returns the value `steps` steps above `self`, or `None` past the
+    /// top of the type.
+    ///
+    /// The default implementation calls [`Discrete::succ`] `steps` times.
+    fn forward(&self, steps: u128) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        let mut value = self.clone();
+        for _ in 0..steps {
+            value = value.succ()?;
+        }
+        Some(value)
+    }
+}
+
+/// Error returned by [`Interval::sample_points`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleError {
+    /// No sample was requested.
+    NoSamples,
+
+    /// The interval is unbounded on at least one side.
+    Unbounded,
+
+    /// The interval holds fewer distinct values than samples requested.
+    TooFewValues,
+}
+
+impl Display for SampleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SampleError::NoSamples => write!(f, "at least one sample must be requested"),
+            SampleError::Unbounded => write!(f, "cannot sample an unbounded interval"),
+            SampleError::TooFewValues => {
+                write!(f, "interval holds fewer values than samples requested")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SampleError {}
+
+impl<T: Integer> Discrete for T {
+    fn succ(&self) -> Option<Self> {
+        self.checked_add(T::ONE)
+    }
+
+    fn pred(&self) -> Option<Self> {
+        self.checked_sub(T::ONE)
+    }
+
+    fn steps_to(&self, later: &Self) -> Option<u128> {
+        (later >= self).then(|| self.abs_diff(*later))
+    }
+
+    fn forward(&self, steps: u128) -> Option<Self> {
+        if self.steps_to(&T::MAX)? < steps {
+            return None;
+        }
+        // two's complement addition of an offset known not to overflow
+        Some(self.wrapping_add_steps(steps))
+    }
+}
+
+impl<T: Discrete> BoundPoint<T> {
+    /// Rewrites a start bound as the inclusive one admitting the same
+    /// values: `after(3)` becomes `at(4)`, and `before(3)` `at(3)`.
+    ///
+    /// Infinities are kept. Returns `None` when `after(x)` sits at the
+    /// top of the type, as no value follows it.
+    pub fn normalize_as_start(self) -> Option<BoundPoint<T>> {
+        match self.value {
+            BoundValue::Finite(value, BoundProximity::After) => Some(BoundPoint::at(value.succ()?)),
+            BoundValue::Finite(value, _) => Some(BoundPoint::at(value)),
+            _ => Some(self),
+        }
+    }
+
+    /// Rewrites an end bound as the inclusive one admitting the same
+    /// values: `before(10)` becomes `at(9)`, and `after(10)` `at(10)`.
+    ///
+    /// Infinities are kept. Returns `None` when `before(x)` sits at the
+    /// bottom of the type, as no value precedes it.
+    pub fn normalize_as_end(self) -> Option<BoundPoint<T>> {
+        match self.value {
+            BoundValue::Finite(value, BoundProximity::Before) => {
+                Some(BoundPoint::at(value.pred()?))
+            }
+            BoundValue::Finite(value, _) => Some(BoundPoint::at(value)),
+            _ => Some(self),
+        }
+    }
+}
+
+impl<T: Discrete + Clone> Interval<T> {
+    /// Returns the smallest value in the interval: `(3, 7]` has first
+    /// value `4`, unlike its infimum `3`.
+    ///
+    /// Returns `None` when the interval is unbounded below, holds no
+    /// value, or an exclusive start sits at the top of the type.
+    pub fn first_value(&self) -> Option<T> {
+        let first = self
+            .start
+            .clone()
+            .normalize_as_start()?
+            .into_finite_value()?;
+        self.contains_value(&first).then_some(first)
+    }
+
+    /// Returns the largest value in the interval: `[3, 7)` has last
+    /// value `6`, unlike its supremum `7`.
+    ///
+    /// Returns `None` when the interval is unbounded above, holds no
+    /// value, or an exclusive end sits at the bottom of the type.
+    pub fn last_value(&self) -> Option<T> {
+        let last = self.end.clone().normalize_as_end()?.into_finite_value()?;
+        self.contains_value(&last).then_some(last)
+    }
+
+    /// Returns the value in the interval closest to `preferred`: the
+    /// preference itself when contained, else the first value when it
+    /// lies below the interval and the last value when above.
+    ///
+    /// Unlike clamping, this steps inside exclusive bounds, so `(3, 10]`
+    /// with preference `1` gives `4`. Returns `None` when the interval
+    /// holds no value, which includes an exclusive bound at the limit of
+    /// the type such as `(i32::MAX, +inf)`.
+    pub fn nearest_contained(&self, preferred: &T) -> Option<T> {
+        if self.contains_value(preferred) {
+            Some(preferred.clone())
+        } else if BoundPoint::at(preferred.clone()) < self.start {
+            self.first_value()
+        } else {
+            self.last_value()
+        }
+    }
+
+    /// Returns the same values as `[first, last + 1)`, or as `[first, last]`
+    /// when `last` is the top of the type, or `None` if there are none.
+    ///
+    /// Canonical intervals holding adjacent values meet at a flush seam,
+    /// so an [`IntervalSet`](crate::IntervalSet) of them merges
+    /// `[1, 3]` and `[4, 5]` into `[1, 6)`.
+    pub fn canonicalize(&self) -> Option<Interval<T>> {
+        if self.is_empty() {
+            return None;
+        }
+        let start = match self.start.finite_value() {
+            Some(_) => BoundPoint::at(self.first_value()?),
+            None => BoundPoint::neg_infinity(),
+        };
+        let end = match self.end.finite_value() {
+            Some(_) => {
+                let last = self.last_value()?;
+                match last.succ() {
+                    Some(next) => BoundPoint::before(next),
+                    None => BoundPoint::at(last),
+                }
+            }
+            None => BoundPoint::pos_infinity(),
+        };
+        let canonical = Interval { start, end };
+        (!canonical.is_empty()).then_some(canonical)
+    }
+
+    /// Returns the number of values in the interval, or `None` when it is
+    /// unbounded or the number does not fit in a `u128`.
+    pub fn count_values(&self) -> Option<u128> {
+        if self.start.finite_value().is_none() || self.end.finite_value().is_none() {
+            return None;
+        }
+        let (Some(first), Some(last)) = (self.first_value(), self.last_value()) else {
+            return Some(0);
+        };
+        first.steps_to(&last)?.checked_add(1)
+    }
+
+    /// Returns `n` distinct values spread as evenly as possible from the
+    /// first to the last value of the interval, both included.
+    ///
+    /// Exclusive bounds are never sampled, as the first and last values
+    /// already lie one step inside them. Sample `k` is `first + k * span
+    /// / (n - 1)` rounded down, where `span` is the number of steps from
+    /// the first to the last value, so `[0, 10]` with `n = 5` gives `0,
+    /// 2, 5, 7, 10`. A single sample is the first value.
+    pub fn sample_points(&self, n: usize) -> Result<Vec<T>, SampleError> {
+        if n == 0 {
+            return Err(SampleError::NoSamples);
+        }
+        if self.start.finite_value().is_none() || self.end.finite_value().is_none() {
+            return Err(SampleError::Unbounded);
+        }
+        let (Some(first), Some(last)) = (self.first_value(), self.last_value()) else {
+            return Err(SampleError::TooFewValues);
+        };
+        let span = first.steps_to(&last).ok_or(SampleError::TooFewValues)?;
+        let gaps = n as u128 - 1;
+        if span < gaps {
+            return Err(SampleError::TooFewValues);
+        }
+        if gaps == 0 {
+            return Ok(vec![first]);
+        }
+        // k * span / gaps, split so that no product can overflow
+        let (quotient, remainder) = (span / gaps, span % gaps);
+        Ok((0..=gaps)
+            .map(|k| {
+                first
+                    .forward(k * quotient + k * remainder / gaps)
+                    .expect("samples stay within the interval")
+            })
+            .collect())
+    }
+
+    /// Yields every value in the interval in ascending order, starting
+    /// from the first one; an interval unbounded below yields nothing.
+    pub fn values(&self) -> Values<T> {
+        Values {
+            next: self.first_value(),
+            last: self.last_value(),
+            bounded_above: self.end.finite_value().is_some(),
+        }
+    }
+}
+
+/// Iterates over the values of an interval over a [`Discrete`] type,
+/// as [`Interval::values`] does.
+impl<T: Discrete + Clone> IntoIterator for Interval<T> {
+    type Item = T;
+    type IntoIter = Values<T>;
+
+    fn into_iter(self) -> Values<T> {
+        self.values()
+    }
+}
+
+/// Iterator over the values of an interval, returned by
+/// [`Interval::values`].
+#[derive(Debug, Clone)]
+pub struct Values<T> {
+    next: Option<T>,
+    last: Option<T>,
+    bounded_above: bool,
+}
+
+impl<T: Discrete> Iterator for Values<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let current = self.next.take()?;
+        if self.bounded_above && self.last.as_ref().is_none_or(|last| current > *last) {
+            return None;
+        }
+        self.next = current.succ();
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::interval_set::IntervalSet;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(BoundPoint::after(3), Some(BoundPoint::at(4)), Some(BoundPoint::at(3)))]
+    #[case(BoundPoint::at(3), Some(BoundPoint::at(3)), Some(BoundPoint::at(3)))]
+    #[case(
+        BoundPoint::before(3),
+        Some(BoundPoint::at(3)),
+        Some(BoundPoint::at(2))
+    )]
+    #[case(BoundPoint::after(i8::MAX), None, Some(BoundPoint::at(i8::MAX)))]
+    #[case(BoundPoint::before(i8::MAX), Some(BoundPoint::at(i8::MAX)), Some(BoundPoint::at(i8::MAX - 1)))]
+    #[case(BoundPoint::before(i8::MIN), Some(BoundPoint::at(i8::MIN)), None)]
+    #[case(BoundPoint::after(i8::MIN), Some(BoundPoint::at(i8::MIN + 1)), Some(BoundPoint::at(i8::MIN)))]
+    #[case(
+        BoundPoint::neg_infinity(),
+        Some(BoundPoint::neg_infinity()),
+        Some(BoundPoint::neg_infinity())
+    )]
+    #[case(
+        BoundPoint::pos_infinity(),
+        Some(BoundPoint::pos_infinity()),
+        Some(BoundPoint::pos_infinity())
+    )]
+    fn test_normalize_bound(
+        #[case] point: BoundPoint<i8>,
+        #[case] as_start: Option<BoundPoint<i8>>,
+        #[case] as_end: Option<BoundPoint<i8>>,
+    ) {
+        assert_eq!(point.normalize_as_start(), as_start);
+        assert_eq!(point.normalize_as_end(), as_end);
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(3, 7, IntervalType::StartOpen).unwrap(), Some(4), Some(7))]
+    #[case(Interval::from_to(3, 7, IntervalType::EndOpen).unwrap(), Some(3), Some(6))]
+    #[case(Interval::from_to(3, 5, IntervalType::Open).unwrap(), Some(4), Some(4))]
+    #[case(Interval::from_to(3, 4, IntervalType::Open).unwrap(), None, None)]
+    #[case(Interval::from_to(3, 3, IntervalType::EndOpen).unwrap(), None, None)]
+    #[case(Interval::until_inclusive(3), None, Some(3))]
+    #[case(Interval::since_exclusive(3), Some(4), None)]
+    // the adjustment would overflow
+    #[case(Interval::since_exclusive(i32::MAX), None, None)]
+    #[case(Interval::until_exclusive(i32::MIN), None, None)]
+    #[case(Interval::from_to(i32::MIN, i32::MAX, IntervalType::Close).unwrap(), Some(i32::MIN), Some(i32::MAX))]
+    fn test_first_last_value(
+        #[case] interval: Interval<i32>,
+        #[case] first: Option<i32>,
+        #[case] last: Option<i32>,
+    ) {
+        assert_eq!(interval.first_value(), first);
+        assert_eq!(interval.last_value(), last);
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(3, 10, IntervalType::StartOpen).unwrap(), 1, Some(4))]
+    #[case(Interval::from_to(3, 10, IntervalType::StartOpen).unwrap(), 3, Some(4))]
+    #[case(Interval::from_to(3, 10, IntervalType::StartOpen).unwrap(), 7, Some(7))]
+    #[case(Interval::from_to(3, 10, IntervalType::StartOpen).unwrap(), 12, Some(10))]
+    #[case(Interval::from_to(3, 10, IntervalType::EndOpen).unwrap(), 10, Some(9))]
+    #[case(Interval::from_to(3, 4, IntervalType::Open).unwrap(), 1, None)]
+    #[case(Interval::from_to(3, 3, IntervalType::EndOpen).unwrap(), 3, None)]
+    // half-bounded intervals
+    #[case(Interval::until_inclusive(3), 1, Some(1))]
+    #[case(Interval::until_inclusive(3), 5, Some(3))]
+    #[case(Interval::since_inclusive(3), 1, Some(3))]
+    // stepping inside would overflow
+    #[case(Interval::since_exclusive(i32::MAX), 0, None)]
+    #[case(Interval::until_exclusive(i32::MIN), 0, None)]
+    #[case(Interval::from_to(i32::MAX - 1, i32::MAX, IntervalType::StartOpen).unwrap(), 0, Some(i32::MAX))]
+    fn test_nearest_contained(
+        #[case] interval: Interval<i32>,
+        #[case] preferred: i32,
+        #[case] expected: Option<i32>,
+    ) {
+        assert_eq!(interval.nearest_contained(&preferred), expected);
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(3, 7, IntervalType::StartOpen).unwrap(), Some(Interval::from_to(4, 8, IntervalType::EndOpen).unwrap()))]
+    #[case(Interval::from_to(3, 7, IntervalType::Open).unwrap(), Some(Interval::from_to(4, 7, IntervalType::EndOpen).unwrap()))]
+    #[case(Interval::from_to(3, 4, IntervalType::Open).unwrap(), None)]
+    #[case(Interval::from_to(3, 3, IntervalType::Close).unwrap(), Some(Interval::from_to(3, 4, IntervalType::EndOpen).unwrap()))]
+    #[case(Interval::until_inclusive(3), Some(Interval::until_exclusive(4)))]
+    #[case(Interval::since_exclusive(3), Some(Interval::since_inclusive(4)))]
+    #[case(Interval::since_exclusive(i32::MAX), None)]
+    #[case(Interval::until_exclusive(i32::MIN), None)]
+    // the top of the type stays inclusive
+    #[case(Interval::from_to(0, i32::MAX, IntervalType::Close).unwrap(), Some(Interval::from_to(0, i32::MAX, IntervalType::Close).unwrap()))]
+    fn test_canonicalize(#[case] interval: Interval<i32>, #[case] expected: Option<Interval<i32>>) {
+        assert_eq!(interval.canonicalize(), expected);
+    }
+
+    #[test]
+    fn test_canonical_neighbours_merge() {
+        let set: IntervalSet<i32> = [
+            Interval::from_to(1, 3, IntervalType::Close).unwrap(),
+            Interval::from_to(3, 5, IntervalType::StartOpen).unwrap(),
+        ]
+        .iter()
+        .filter_map(Interval::canonicalize)
+        .collect();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![&Interval::from_to(1, 6, IntervalType::EndOpen).unwrap()]
+        );
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(3, 7, IntervalType::StartOpen).unwrap(), Some(4))]
+    #[case(Interval::from_to(3, 4, IntervalType::Open).unwrap(), Some(0))]
+    #[case(Interval::from_to(3, 3, IntervalType::EndOpen).unwrap(), Some(0))]
+    #[case(Interval::since_inclusive(3), None)]
+    fn test_count_values(#[case] interval: Interval<i32>, #[case] expected: Option<u128>) {
+        assert_eq!(interval.count_values(), expected);
+    }
+
+    #[test]
+    fn test_count_values_at_type_limits() {
+        let all = Interval::from_to(i128::MIN, i128::MAX, IntervalType::EndOpen).unwrap();
+        assert_eq!(all.count_values(), Some(u128::MAX));
+        let all = Interval::from_to(u128::MIN, u128::MAX, IntervalType::Close).unwrap();
+        assert_eq!(all.count_values(), None);
+        let all = Interval::from_to(i8::MIN, i8::MAX, IntervalType::Close).unwrap();
+        assert_eq!(all.count_values(), Some(256));
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(3, 7, IntervalType::StartOpen).unwrap(), vec![4, 5, 6, 7])]
+    #[case(Interval::from_to(3, 4, IntervalType::Open).unwrap(), vec![])]
+    #[case(Interval::until_inclusive(3), vec![])]
+    fn test_values(#[case] interval: Interval<i32>, #[case] expected: Vec<i32>) {
+        assert_eq!(interval.values().collect::<Vec<_>>(), expected);
+        assert_eq!(interval.into_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(0, 10, IntervalType::Close).unwrap(), 5, vec![0, 2, 5, 7, 10])]
+    #[case(Interval::from_to(0, 10, IntervalType::Open).unwrap(), 3, vec![1, 5, 9])]
+    #[case(Interval::from_to(0, 10, IntervalType::EndOpen).unwrap(), 10, (0..10).collect())]
+    #[case(Interval::from_to(0, 10, IntervalType::Close).unwrap(), 2, vec![0, 10])]
+    #[case(Interval::from_to(0, 10, IntervalType::StartOpen).unwrap(), 1, vec![1])]
+    #[case(Interval::from_to(-3, 3, IntervalType::Close).unwrap(), 4, vec![-3, -1, 1, 3])]
+    fn test_sample_points(
+        #[case] interval: Interval<i32>,
+        #[case] n: usize,
+        #[case] expected: Vec<i32>,
+    ) {
+        assert_eq!(interval.sample_points(n), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(0, 10, IntervalType::Close).unwrap(), 0, SampleError::NoSamples)]
+    #[case(Interval::since_inclusive(0), 3, SampleError::Unbounded)]
+    #[case(Interval::until_inclusive(0), 3, SampleError::Unbounded)]
+    #[case(Interval::from_to(0, 10, IntervalType::EndOpen).unwrap(), 11, SampleError::TooFewValues)]
+    #[case(Interval::from_to(0, 1, IntervalType::Open).unwrap(), 1, SampleError::TooFewValues)]
+    fn test_sample_points_errors(
+        #[case] interval: Interval<i32>,
+        #[case] n: usize,
+        #[case] expected: SampleError,
+    ) {
+        assert_eq!(interval.sample_points(n), Err(expected));
+    }
+
+    #[test]
+    fn test_sample_points_across_the_whole_type() {
+        let all = Interval::from_to(i128::MIN, i128::MAX, IntervalType::Close).unwrap();
+        assert_eq!(all.sample_points(3), Ok(vec![i128::MIN, -1, i128::MAX]));
+        let all = Interval::from_to(i8::MIN, i8::MAX, IntervalType::Close).unwrap();
+        assert_eq!(all.sample_points(256), Ok(all.values().collect()));
+    }
+
+    #[test]
+    fn test_forward() {
+        assert_eq!(i8::MIN.forward(255), Some(i8::MAX));
+        assert_eq!(i8::MIN.forward(256), None);
+        assert_eq!((-5i32).forward(7), Some(2));
+        assert_eq!(u128::MAX.forward(0), Some(u128::MAX));
+        assert_eq!(0u128.forward(u128::MAX), Some(u128::MAX));
+    }
+
+    #[test]
+    fn test_values_up_to_the_top_of_the_type() {
+        let values: Vec<u8> = Interval::since_exclusive(250u8).values().collect();
+        assert_eq!(values, vec![251, 252, 253, 254, 255]);
+        let values: Vec<u8> = Interval::from_to(253u8, 255, IntervalType::Close)
+            .unwrap()
+            .values()
+            .collect();
+        assert_eq!(values, vec![253, 254, 255]);
+    }
+}
@@ -0,0 +1,483 @@
+//! Intervals over `f64`, through `ordered-float`'s [`NotNan`].
+//!
+//! `f64` is only `PartialOrd`, so float intervals store their values as
+//! `NotNan<f64>` and get the whole generic API from there. NaN can never
+//! become a bound: the `f64` constructors reject it, and
+//! [`Interval::contains_f64`] reports NaN as not contained.
+//!
+//! Infinite values are ordinary bound values: `[0, inf]` contains
+//! `f64::INFINITY` while `[0, inf)` does not. `-0.0` and `0.0` compare
+//! equal, as for `f64`. A single point at an infinite value, such as
+//! `[inf, inf]`, would have the NaN length `inf - inf`, so the `f64`
+//! constructors reject it, and [`Interval::length_f64`] measures it as
+//! zero when it comes out of other operations.
+
+use crate::bound_point::{BoundPoint, BoundProximity};
+use crate::discrete::SampleError;
+use crate::interval::{Interval, IntervalError, IntervalType};
+use crate::interval_set::IntervalSet;
+use ordered_float::NotNan;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Range, RangeInclusive};
+
+/// An interval over non-NaN `f64` values.
+pub type FloatInterval = Interval<NotNan<f64>>;
+
+/// A set of non-NaN `f64` values.
+pub type FloatIntervalSet = IntervalSet<NotNan<f64>>;
+
+/// Error returned when building a [`FloatInterval`] from `f64` bounds fails.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FloatIntervalError {
+    /// A bound is NaN.
+    NanBound,
+
+    /// Both bounds are the same infinite value, as in `[inf, inf]`.
+    InfinitePoint,
+
+    /// The bounds are not NaN but do not form a valid interval.
+    Invalid(IntervalError),
+}
+
+impl Display for FloatIntervalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FloatIntervalError::NanBound => write!(f, "interval bound must not be NaN"),
+            FloatIntervalError::InfinitePoint => {
+                write!(f, "interval bounds must not both be the same infinity")
+            }
+            FloatIntervalError::Invalid(IntervalError::StartMustBeMinorThanEnd) => {
+                write!(f, "interval start must not be greater than its end")
+            }
+            FloatIntervalError::Invalid(_) => write!(f, "interval bounds are invalid"),
+        }
+    }
+}
+
+impl std::error::Error for FloatIntervalError {}
+
+/// Error returned when rounding a [`FloatInterval`] to integers fails
+/// because a bound does not fit in `i64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundToIntError;
+
+impl Display for RoundToIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "interval bound does not fit in i64")
+    }
+}
+
+impl std::error::Error for RoundToIntError {}
+
+impl Interval<NotNan<f64>> {
+    /// Like [`Interval::from_to`], but takes raw `f64` bounds and
+    /// rejects NaN, and a single point at an infinite value.
+    pub fn from_f64(
+        start: f64,
+        end: f64,
+        interval_type: IntervalType,
+    ) -> Result<Self, FloatIntervalError> {
+        let start = NotNan::new(start).map_err(|_| FloatIntervalError::NanBound)?;
+        let end = NotNan::new(end).map_err(|_| FloatIntervalError::NanBound)?;
+        if start == end && start.is_infinite() {
+            return Err(FloatIntervalError::InfinitePoint);
+        }
+        Interval::from_to(start, end, interval_type).map_err(FloatIntervalError::Invalid)
+    }
+
+    /// Like [`Interval::length`], but returns a raw `f64` and never
+    /// panics: bounds at the same value, even an infinite one, have
+    /// length zero.
+    pub fn length_f64(&self) -> Option<f64> {
+        let start = self.start.finite_value()?.into_inner();
+        let end = self.end.finite_value()?.into_inner();
+        Some(if start == end { 0.0 } else { end - start })
+    }
+
+    /// Like [`Interval::contains`], but takes a raw `f64`.
+    /// NaN is never contained.
+    pub fn contains_f64(&self, value: f64) -> bool {
+        NotNan::new(value).is_ok_and(|value| self.contains(value))
+    }
+
+    /// Returns `n` evenly spaced values from the start to the end of the
+    /// interval, like `linspace`.
+    ///
+    /// An exclusive bound is a sample position left out, so `[0, 10]`
+    /// with `n = 3` gives `0, 5, 10` while `(0, 10)` gives `2.5, 5, 7.5`.
+    /// A single sample of a closed interval is its start. Fails when the
+    /// interval is unbounded or has an infinite bound value, or when the
+    /// samples would not be distinct values inside the interval.
+    pub fn sample_points(&self, n: usize) -> Result<Vec<NotNan<f64>>, SampleError> {
+        if n == 0 {
+            return Err(SampleError::NoSamples);
+        }
+        let (Some(start), Some(end)) = (self.start.finite_value(), self.end.finite_value()) else {
+            return Err(SampleError::Unbounded);
+        };
+        let (start, end) = (start.into_inner(), end.into_inner());
+        if !start.is_finite() || !end.is_finite() {
+            return Err(SampleError::Unbounded);
+        }
+        let skip_start = usize::from(self.start.proximity() == Some(BoundProximity::After));
+        let skip_end = usize::from(self.end.proximity() == Some(BoundProximity::Before));
+        let divisions = n - 1 + skip_start + skip_end;
+        let samples: Vec<_> = (skip_start..skip_start + n)
+            .map(|i| match i {
+                0 => start,
+                i if i == divisions => end,
+                i => start + (end - start) * (i as f64 / divisions as f64),
+            })
+            .map(|sample| NotNan::new(sample).expect("finite bounds give finite samples"))
+            .collect();
+        let distinct = samples.windows(2).all(|pair| pair[0] < pair[1]);
+        if !distinct || !samples.iter().all(|sample| self.contains(*sample)) {
+            return Err(SampleError::TooFewValues);
+        }
+        Ok(samples)
+    }
+
+    /// Returns the smallest integer interval containing this one, with
+    /// the start rounded down and the end rounded up.
+    ///
+    /// A bound that moves becomes inclusive, while an integral bound
+    /// keeps its proximity, so `(2.5, 4.0)` rounds to `[2, 4)`. A
+    /// `-inf` start and an `inf` end become unbounded. Each bound is
+    /// rounded on its own, so an empty interval such as `(2.5, 2.5)`
+    /// may round to a non-empty one.
+    pub fn round_outward_to_int(&self) -> Result<Interval<i64>, RoundToIntError> {
+        Ok(Interval {
+            start: round_start(&self.start, f64::floor)?,
+            end: round_end(&self.end, f64::ceil)?,
+        })
+    }
+
+    /// Returns the largest integer interval contained in this one, with
+    /// the start rounded up and the end rounded down, or `None` if it
+    /// contains no integer.
+    ///
+    /// Bounds are treated as in [`Interval::round_outward_to_int`], so
+    /// `(2.5, 4.0)` rounds to `[3, 4)`.
+    pub fn round_inward_to_int(&self) -> Result<Option<Interval<i64>>, RoundToIntError> {
+        let interval = Interval {
+            start: round_start(&self.start, f64::ceil)?,
+            end: round_end(&self.end, f64::floor)?,
+        };
+        Ok((!interval.is_empty()).then_some(interval))
+    }
+}
+
+fn round_start(
+    start: &BoundPoint<NotNan<f64>>,
+    round: fn(f64) -> f64,
+) -> Result<BoundPoint<i64>, RoundToIntError> {
+    match start.finite_value() {
+        Some(value) if **value == f64::NEG_INFINITY => Ok(BoundPoint::neg_infinity()),
+        _ => round_bound(start, round),
+    }
+}
+
+fn round_end(
+    end: &BoundPoint<NotNan<f64>>,
+    round: fn(f64) -> f64,
+) -> Result<BoundPoint<i64>, RoundToIntError> {
+    match end.finite_value() {
+        Some(value) if **value == f64::INFINITY => Ok(BoundPoint::pos_infinity()),
+        _ => round_bound(end, round),
+    }
+}
+
+/// Rounds a bound value with `round`, making the bound inclusive if the
+/// value moved.
+fn round_bound(
+    point: &BoundPoint<NotNan<f64>>,
+    round: fn(f64) -> f64,
+) -> Result<BoundPoint<i64>, RoundToIntError> {
+    match point.finite_value() {
+        Some(value) if round(**value) != **value => Ok(BoundPoint::at(to_i64(round(**value))?)),
+        _ => (*point).try_map(|value| to_i64(*value)),
+    }
+}
+
+/// Converts an integral `f64`, failing outside the range of `i64`.
+fn to_i64(value: f64) -> Result<i64, RoundToIntError> {
+    // i64::MAX as f64 rounds up to 2^63, the first value out of range
+    if value >= i64::MIN as f64 && value < i64::MAX as f64 {
+        Ok(value as i64)
+    } else {
+        Err(RoundToIntError)
+    }
+}
+
+/// Converts `start..end` into `[start, end)`.
+impl TryFrom<Range<f64>> for Interval<NotNan<f64>> {
+    type Error = FloatIntervalError;
+
+    fn try_from(range: Range<f64>) -> Result<Self, Self::Error> {
+        Interval::from_f64(range.start, range.end, IntervalType::EndOpen)
+    }
+}
+
+/// Converts `start..=end` into `[start, end]`.
+impl TryFrom<RangeInclusive<f64>> for Interval<NotNan<f64>> {
+    type Error = FloatIntervalError;
+
+    fn try_from(range: RangeInclusive<f64>) -> Result<Self, Self::Error> {
+        let (start, end) = range.into_inner();
+        Interval::from_f64(start, end, IntervalType::Close)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn float(value: f64) -> NotNan<f64> {
+        NotNan::new(value).unwrap()
+    }
+
+    #[rstest]
+    #[case(f64::NAN, 1.0)]
+    #[case(0.0, f64::NAN)]
+    #[case(f64::NAN, f64::NAN)]
+    fn test_nan_bounds_are_rejected(#[case] start: f64, #[case] end: f64) {
+        assert_eq!(
+            Interval::from_f64(start, end, IntervalType::Close),
+            Err(FloatIntervalError::NanBound)
+        );
+        assert_eq!(
+            FloatInterval::try_from(start..end),
+            Err(FloatIntervalError::NanBound)
+        );
+    }
+
+    #[test]
+    fn test_reversed_bounds_are_rejected() {
+        assert_eq!(
+            FloatInterval::try_from(2.0..=1.0),
+            Err(FloatIntervalError::Invalid(
+                IntervalError::StartMustBeMinorThanEnd
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case(0.0..1.0, 0.0, true)]
+    #[case(0.0..1.0, 1.0, false)]
+    #[case(0.0..1.0, 0.999_999, true)]
+    #[case(0.0..1.0, -0.0, true)]
+    #[case(-1.0..-0.0, 0.0, false)]
+    #[case(0.0..1.0, f64::NAN, false)]
+    #[case(f64::NEG_INFINITY..f64::INFINITY, f64::NAN, false)]
+    #[case(f64::NEG_INFINITY..f64::INFINITY, f64::NEG_INFINITY, true)]
+    #[case(f64::NEG_INFINITY..f64::INFINITY, f64::INFINITY, false)]
+    #[case(f64::NEG_INFINITY..f64::INFINITY, f64::MAX, true)]
+    fn test_contains_f64(#[case] range: Range<f64>, #[case] value: f64, #[case] expected: bool) {
+        assert_eq!(
+            FloatInterval::try_from(range).unwrap().contains_f64(value),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(f64::INFINITY, IntervalType::Close)]
+    #[case(f64::NEG_INFINITY, IntervalType::Close)]
+    #[case(f64::INFINITY, IntervalType::Open)]
+    #[case(f64::NEG_INFINITY, IntervalType::EndOpen)]
+    fn test_infinite_points_are_rejected(#[case] value: f64, #[case] interval_type: IntervalType) {
+        assert_eq!(
+            Interval::from_f64(value, value, interval_type),
+            Err(FloatIntervalError::InfinitePoint)
+        );
+    }
+
+    #[rstest]
+    #[case(FloatInterval::try_from(1.0..3.5).unwrap(), Some(2.5))]
+    #[case(FloatInterval::try_from(-0.0..=0.0).unwrap(), Some(0.0))]
+    #[case(FloatInterval::try_from(0.0..=f64::INFINITY).unwrap(), Some(f64::INFINITY))]
+    #[case(FloatInterval::try_from(f64::NEG_INFINITY..f64::INFINITY).unwrap(), Some(f64::INFINITY))]
+    #[case(Interval::since_inclusive(float(0.0)), None)]
+    // built from generic operations rather than `from_f64`
+    #[case(FloatInterval::try_from(0.0..=f64::INFINITY).unwrap().intersection(&Interval::since_inclusive(float(f64::INFINITY))).unwrap(), Some(0.0))]
+    fn test_length_f64(#[case] interval: FloatInterval, #[case] expected: Option<f64>) {
+        assert_eq!(interval.length_f64(), expected);
+    }
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!(
+            FloatIntervalError::Invalid(IntervalError::StartMustBeMinorThanEnd).to_string(),
+            "interval start must not be greater than its end"
+        );
+        assert_eq!(
+            FloatIntervalError::NanBound.to_string(),
+            "interval bound must not be NaN"
+        );
+    }
+
+    #[test]
+    fn test_inclusive_infinite_bound() {
+        let interval = FloatInterval::try_from(0.0..=f64::INFINITY).unwrap();
+        assert!(interval.contains_f64(f64::INFINITY));
+        assert_eq!(interval.length(), Some(float(f64::INFINITY)));
+    }
+
+    #[test]
+    fn test_signed_zeros_are_equal() {
+        let interval = FloatInterval::try_from(-0.0..=0.0).unwrap();
+        assert!(!interval.is_empty());
+        assert!(interval.contains_f64(0.0));
+        assert!(interval.contains_f64(-0.0));
+        assert_eq!(interval.length(), Some(float(0.0)));
+    }
+
+    #[rstest]
+    #[case(0.0..1.0, 1.0..2.0, None)]
+    #[case(0.0..=1.0, 1.0..2.0, Some(FloatInterval::try_from(1.0..=1.0).unwrap()))]
+    #[case(0.0..=1.5, -0.0..0.5, Some(FloatInterval::try_from(0.0..0.5).unwrap()))]
+    fn test_intersection(
+        #[case] a: impl TryInto<FloatInterval, Error = FloatIntervalError>,
+        #[case] b: Range<f64>,
+        #[case] expected: Option<FloatInterval>,
+    ) {
+        let a = a.try_into().unwrap();
+        let b = FloatInterval::try_from(b).unwrap();
+        assert_eq!(a.intersection(&b), expected);
+        assert_eq!(a.overlaps(&b), expected.is_some());
+    }
+
+    #[rstest]
+    #[case(0.0..=10.0, 3, vec![0.0, 5.0, 10.0])]
+    #[case(0.0..=10.0, 1, vec![0.0])]
+    #[case(-1.0..=1.0, 5, vec![-1.0, -0.5, 0.0, 0.5, 1.0])]
+    #[case(2.0..=2.0, 1, vec![2.0])]
+    fn test_sample_points_closed(
+        #[case] range: RangeInclusive<f64>,
+        #[case] n: usize,
+        #[case] expected: Vec<f64>,
+    ) {
+        let samples = FloatInterval::try_from(range).unwrap().sample_points(n);
+        assert_eq!(samples, Ok(expected.into_iter().map(float).collect()));
+    }
+
+    #[rstest]
+    #[case(IntervalType::Open, 3, vec![2.5, 5.0, 7.5])]
+    #[case(IntervalType::Open, 1, vec![5.0])]
+    #[case(IntervalType::EndOpen, 2, vec![0.0, 5.0])]
+    #[case(IntervalType::StartOpen, 4, vec![2.5, 5.0, 7.5, 10.0])]
+    fn test_sample_points_exclusive(
+        #[case] interval_type: IntervalType,
+        #[case] n: usize,
+        #[case] expected: Vec<f64>,
+    ) {
+        let interval = Interval::from_f64(0.0, 10.0, interval_type).unwrap();
+        assert_eq!(
+            interval.sample_points(n),
+            Ok(expected.into_iter().map(float).collect())
+        );
+    }
+
+    #[rstest]
+    #[case(FloatInterval::try_from(0.0..=10.0).unwrap(), 0, SampleError::NoSamples)]
+    #[case(Interval::since_inclusive(float(0.0)), 2, SampleError::Unbounded)]
+    #[case(FloatInterval::try_from(0.0..=f64::INFINITY).unwrap(), 2, SampleError::Unbounded)]
+    #[case(FloatInterval::try_from(2.0..=2.0).unwrap(), 2, SampleError::TooFewValues)]
+    #[case(FloatInterval::try_from(2.0..2.0).unwrap(), 1, SampleError::TooFewValues)]
+    #[case(Interval::from_f64(0.0, 5e-324, IntervalType::Open).unwrap(), 3, SampleError::TooFewValues)]
+    fn test_sample_points_errors(
+        #[case] interval: FloatInterval,
+        #[case] n: usize,
+        #[case] expected: SampleError,
+    ) {
+        assert_eq!(interval.sample_points(n), Err(expected));
+    }
+
+    fn int(start: i64, end: i64, interval_type: IntervalType) -> Interval<i64> {
+        Interval::from_to(start, end, interval_type).unwrap()
+    }
+
+    #[rstest]
+    #[case(2.5, 4.0, IntervalType::Open, int(2, 4, IntervalType::EndOpen), Some(int(3, 4, IntervalType::EndOpen)))]
+    #[case(2.0, 4.0, IntervalType::Open, int(2, 4, IntervalType::Open), Some(int(2, 4, IntervalType::Open)))]
+    #[case(-2.5, -0.5, IntervalType::Close, int(-3, 0, IntervalType::Close), Some(int(-2, -1, IntervalType::Close)))]
+    #[case(0.2, 0.8, IntervalType::Close, int(0, 1, IntervalType::Close), None)]
+    #[case(3.0, 3.5, IntervalType::EndOpen, int(3, 4, IntervalType::Close), Some(int(3, 3, IntervalType::Close)))]
+    #[case(3.0, 3.0, IntervalType::Close, int(3, 3, IntervalType::Close), Some(int(3, 3, IntervalType::Close)))]
+    fn test_round_to_int(
+        #[case] start: f64,
+        #[case] end: f64,
+        #[case] interval_type: IntervalType,
+        #[case] outward: Interval<i64>,
+        #[case] inward: Option<Interval<i64>>,
+    ) {
+        let interval = Interval::from_f64(start, end, interval_type).unwrap();
+        assert_eq!(interval.round_outward_to_int(), Ok(outward));
+        assert_eq!(interval.round_inward_to_int(), Ok(inward));
+    }
+
+    #[test]
+    fn test_round_to_int_unbounded() {
+        let full = Interval {
+            start: BoundPoint::neg_infinity(),
+            end: BoundPoint::pos_infinity(),
+        };
+        let interval = FloatInterval::try_from(f64::NEG_INFINITY..=f64::INFINITY).unwrap();
+        assert_eq!(interval.round_outward_to_int(), Ok(full));
+        assert_eq!(interval.round_inward_to_int(), Ok(Some(full)));
+        let interval = Interval::since_exclusive(float(1.5));
+        assert_eq!(
+            interval.round_outward_to_int(),
+            Ok(Interval::since_inclusive(1))
+        );
+        assert_eq!(
+            interval.round_inward_to_int(),
+            Ok(Some(Interval::since_inclusive(2)))
+        );
+    }
+
+    #[rstest]
+    #[case(0.0..1e19)]
+    #[case(-1e19..0.0)]
+    #[case(0.0..9_223_372_036_854_775_808.0)]
+    fn test_round_to_int_out_of_range(#[case] range: Range<f64>) {
+        let interval = FloatInterval::try_from(range).unwrap();
+        assert_eq!(interval.round_outward_to_int(), Err(RoundToIntError));
+        assert_eq!(interval.round_inward_to_int(), Err(RoundToIntError));
+    }
+
+    #[rstest]
+    #[case(f64::INFINITY)]
+    #[case(f64::NEG_INFINITY)]
+    fn test_round_infinite_point_to_int(#[case] value: f64) {
+        // `from_f64` rejects these, but generic constructors still build them
+        let interval =
+            Interval::from_to(float(value), float(value), IntervalType::EndOpen).unwrap();
+        assert_eq!(interval.round_outward_to_int(), Err(RoundToIntError));
+        assert_eq!(interval.round_inward_to_int(), Err(RoundToIntError));
+    }
+
+    #[test]
+    fn test_round_to_int_at_limit() {
+        let interval = FloatInterval::try_from(-9_223_372_036_854_775_808.0..0.0).unwrap();
+        assert_eq!(
+            interval.round_outward_to_int(),
+            Ok(int(i64::MIN, 0, IntervalType::EndOpen))
+        );
+    }
+
+    #[test]
+    fn test_float_interval_set() {
+        let set: FloatIntervalSet = [0.0..1.0, 1.0..2.5, 3.0..f64::INFINITY]
+            .into_iter()
+            .map(|range| FloatInterval::try_from(range).unwrap())
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&float(1.0)));
+        assert!(!set.contains(&float(2.5)));
+        assert_eq!(
+            set.measure_within(&FloatInterval::try_from(0.0..10.0).unwrap()),
+            Some(float(9.5))
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use crate::bound_point::{BoundPoint, BoundProximity};
+use crate::interval::{Interval, IntervalError, IntervalType};
+use std::fmt::{self, Alignment, Display, Formatter, Write};
+use std::str::FromStr;
+
+/// The tokens [`Interval::write_to`] writes around and between the bound
+/// values. The values themselves are written with their own [`Display`].
+///
+/// The default tokens are those of the [`Display`] notation for
+/// [`Interval`], such as `[1, 3)` and `(-inf, 5]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntervalFormat<'a> {
+    pub inclusive_start: &'a str,
+    pub exclusive_start: &'a str,
+    pub inclusive_end: &'a str,
+    pub exclusive_end: &'a str,
+    pub separator: &'a str,
+    pub neg_infinity: &'a str,
+    pub pos_infinity: &'a str,
+}
+
+impl Default for IntervalFormat<'_> {
+    fn default() -> Self {
+        IntervalFormat {
+            inclusive_start: "[",
+            exclusive_start: "(",
+            inclusive_end: "]",
+            exclusive_end: ")",
+            separator: ", ",
+            neg_infinity: "-inf",
+            pos_infinity: "+inf",
+        }
+    }
+}
+
+/// Error returned when parsing an [`Interval`] from text fails.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseIntervalError {
+    /// The text does not start with `[` or `(`.
+    MissingOpeningBracket,
+
+    /// The text does not end with `]` or `)`.
+    MissingClosingBracket,
+
+    /// The bounds are not separated by a comma.
+    MissingSeparator,
+
+    /// A bound could not be parsed as a value; holds the bound text.
+    InvalidValue(String),
+
+    /// An infinite bound was written with an inclusive bracket,
+    /// e.g. `[-inf, 3]`.
+    InclusiveInfinity,
+
+    /// The bounds were parsed but do not form a valid interval.
+    Invalid(IntervalError),
+}
+
+impl Display for ParseIntervalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseIntervalError::MissingOpeningBracket => {
+                write!(f, "interval must start with '[' or '('")
+            }
+            ParseIntervalError::MissingClosingBracket => {
+                write!(f, "interval must end with ']' or ')'")
+            }
+            ParseIntervalError::MissingSeparator => {
+                write!(f, "interval bounds must be separated by ','")
+            }
+            ParseIntervalError::InvalidValue(value) => write!(f, "invalid bound value '{}'", value),
+            ParseIntervalError::InclusiveInfinity => {
+                write!(f, "infinite bounds must use '(' or ')'")
+            }
+            ParseIntervalError::Invalid(error) => write!(f, "invalid interval: {:?}", error),
+        }
+    }
+}
+
+impl std::error::Error for ParseIntervalError {}
+
+impl<T: Ord + Display> Interval<T> {
+    /// Writes the interval to `w` with the tokens of `format`, without
+    /// allocating, so a `String` or a stack buffer can be reused across
+    /// many intervals.
+    ///
+    /// Infinite bounds are written with the exclusive brackets.
+    pub fn write_to<W: Write>(&self, w: &mut W, format: &IntervalFormat<'_>) -> fmt::Result {
+        match (self.start.finite_value(), self.start.proximity()) {
+            (Some(value), Some(BoundProximity::After)) => {
+                write!(w, "{}{}", format.exclusive_start, value)?
+            }
+            (Some(value), _) => write!(w, "{}{}", format.inclusive_start, value)?,
+            (None, _) => write!(w, "{}{}", format.exclusive_start, format.neg_infinity)?,
+        }
+        w.write_str(format.separator)?;
+        match (self.end.finite_value(), self.end.proximity()) {
+            (Some(value), Some(BoundProximity::Before)) => {
+                write!(w, "{}{}", value, format.exclusive_end)
+            }
+            (Some(value), _) => write!(w, "{}{}", value, format.inclusive_end),
+            (None, _) => write!(w, "{}{}", format.pos_infinity, format.exclusive_end),
+        }
+    }
+}
+
+/// Formats the interval in mathematical notation, such as `[1, 3)`,
+/// `(-inf, 5]` or `(10, +inf)`.
+///
+/// The width, fill and alignment flags pad the whole interval, so
+/// `{:>10}` right-aligns it like a string; alignment defaults to the
+/// left. Padding measures the text in a first pass that writes nothing,
+/// so formatting never allocates.
+impl<T: Ord + Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let format = IntervalFormat::default();
+        let Some(width) = f.width() else {
+            return self.write_to(f, &format);
+        };
+        let mut length = CharCount(0);
+        self.write_to(&mut length, &format)?;
+        let padding = width.saturating_sub(length.0);
+        let (before, after) = match f.align() {
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(Alignment::Left) | None => (0, padding),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        self.write_to(f, &format)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// Counts the characters written to it, discarding them.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Parses the notation produced by [`Display`], tolerating whitespace
+/// around the brackets, bounds and separator. Infinite bounds are written
+/// `-inf` and `+inf` (or `inf`) and must use exclusive brackets.
+impl<T: Ord + FromStr> FromStr for Interval<T> {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let start_inclusive = match s.chars().next() {
+            Some('[') => true,
+            Some('(') => false,
+            _ => return Err(ParseIntervalError::MissingOpeningBracket),
+        };
+        let end_inclusive = match s.chars().next_back() {
+            Some(']') if s.len() > 1 => true,
+            Some(')') if s.len() > 1 => false,
+            _ => return Err(ParseIntervalError::MissingClosingBracket),
+        };
+        let (start, end) = s[1..s.len() - 1]
+            .split_once(',')
+            .ok_or(ParseIntervalError::MissingSeparator)?;
+        let (start, end) = (start.trim(), end.trim());
+
+        let start = match start {
+            "-inf" if start_inclusive => return Err(ParseIntervalError::InclusiveInfinity),
+            "-inf" => None,
+            value => Some(parse_value::<T>(value)?),
+        };
+        let end = match end {
+            "+inf" | "inf" if end_inclusive => return Err(ParseIntervalError::InclusiveInfinity),
+            "+inf" | "inf" => None,
+            value => Some(parse_value::<T>(value)?),
+        };
+
+        match (start, end) {
+            (Some(start), Some(end)) => {
+                let interval_type = match (start_inclusive, end_inclusive) {
+                    (false, false) => IntervalType::Open,
+                    (false, true) => IntervalType::StartOpen,
+                    (true, false) => IntervalType::EndOpen,
+                    (true, true) => IntervalType::Close,
+                };
+                Interval::from_to(start, end, interval_type).map_err(ParseIntervalError::Invalid)
+            }
+            (Some(start), None) if start_inclusive => Ok(Interval::since_inclusive(start)),
+            (Some(start), None) => Ok(Interval::since_exclusive(start)),
+            (None, Some(end)) if end_inclusive => Ok(Interval::until_inclusive(end)),
+            (None, Some(end)) => Ok(Interval::until_exclusive(end)),
+            (None, None) => Ok(Interval {
+                start: BoundPoint::neg_infinity(),
+                end: BoundPoint::pos_infinity(),
+            }),
+        }
+    }
+}
+
+fn parse_value<T: FromStr>(value: &str) -> Result<T, ParseIntervalError> {
+    value
+        .parse()
+        .map_err(|_| ParseIntervalError::InvalidValue(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Interval::from_to(1, 3, IntervalType::Open).unwrap(), "(1, 3)")]
+    #[case(Interval::from_to(1, 3, IntervalType::StartOpen).unwrap(), "(1, 3]")]
+    #[case(Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), "[1, 3)")]
+    #[case(Interval::from_to(-1, 3, IntervalType::Close).unwrap(), "[-1, 3]")]
+    #[case(Interval::until_exclusive(3), "(-inf, 3)")]
+    #[case(Interval::until_inclusive(3), "(-inf, 3]")]
+    #[case(Interval::since_exclusive(3), "(3, +inf)")]
+    #[case(Interval::since_inclusive(3), "[3, +inf)")]
+    #[case(Interval { start: BoundPoint::neg_infinity(), end: BoundPoint::pos_infinity() }, "(-inf, +inf)")]
+    fn test_display_round_trip(#[case] interval: Interval<i32>, #[case] text: &str) {
+        assert_eq!(interval.to_string(), text);
+        assert_eq!(text.parse::<Interval<i32>>(), Ok(interval));
+    }
+
+    #[rstest]
+    #[case("[1,3)", Interval::from_to(1, 3, IntervalType::EndOpen).unwrap())]
+    #[case("  ( 1 ,3 ]  ", Interval::from_to(1, 3, IntervalType::StartOpen).unwrap())]
+    #[case("(10,inf)", Interval::since_exclusive(10))]
+    #[case("(1, 1)", Interval::from_to(1, 1, IntervalType::Open).unwrap())]
+    fn test_parse(#[case] text: &str, #[case] expected: Interval<i32>) {
+        assert_eq!(text.parse::<Interval<i32>>(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("", ParseIntervalError::MissingOpeningBracket)]
+    #[case("1, 3]", ParseIntervalError::MissingOpeningBracket)]
+    #[case("[1, 3", ParseIntervalError::MissingClosingBracket)]
+    #[case("[", ParseIntervalError::MissingClosingBracket)]
+    #[case("[1 3]", ParseIntervalError::MissingSeparator)]
+    #[case("[a, 3]", ParseIntervalError::InvalidValue("a".to_string()))]
+    #[case("[1, ]", ParseIntervalError::InvalidValue("".to_string()))]
+    #[case("[-inf, 3]", ParseIntervalError::InclusiveInfinity)]
+    #[case("(1, +inf]", ParseIntervalError::InclusiveInfinity)]
+    #[case(
+        "[3, 1]",
+        ParseIntervalError::Invalid(IntervalError::StartMustBeMinorThanEnd)
+    )]
+    fn test_parse_errors(#[case] text: &str, #[case] expected: ParseIntervalError) {
+        assert_eq!(text.parse::<Interval<i32>>(), Err(expected));
+    }
+
+    fn one_to_three() -> Interval<i32> {
+        Interval::from_to(1, 3, IntervalType::EndOpen).unwrap()
+    }
+
+    #[rstest]
+    #[case(format!("{:>10}", one_to_three()), "    [1, 3)")]
+    #[case(format!("{:<10}", one_to_three()), "[1, 3)    ")]
+    #[case(format!("{:10}", one_to_three()), "[1, 3)    ")]
+    #[case(format!("{:^11}", one_to_three()), "  [1, 3)   ")]
+    #[case(format!("{:*^10}", one_to_three()), "**[1, 3)**")]
+    #[case(format!("{:>0}", one_to_three()), "[1, 3)")]
+    #[case(format!("{:>1}", one_to_three()), "[1, 3)")]
+    #[case(format!("{:>6}", one_to_three()), "[1, 3)")]
+    fn test_display_padding(#[case] text: String, #[case] expected: &str) {
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn test_display_padding_in_table() {
+        let rows = [
+            Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(),
+            Interval::since_exclusive(-250),
+        ];
+        let lines: Vec<_> = rows.iter().map(|row| format!("|{:>20}|", row)).collect();
+        assert_eq!(lines, ["|              [1, 3)|", "|        (-250, +inf)|"]);
+    }
+
+    #[test]
+    fn test_write_to_reuses_buffer() {
+        let iso = IntervalFormat {
+            inclusive_start: "[",
+            exclusive_start: "]",
+            inclusive_end: "]",
+            exclusive_end: "[",
+            separator: "; ",
+            neg_infinity: "−∞",
+            pos_infinity: "+∞",
+        };
+        let mut buffer = String::with_capacity(32);
+        let mut lines = Vec::new();
+        for interval in [
+            Interval::from_to(1, 3, IntervalType::StartOpen).unwrap(),
+            Interval::until_exclusive(5),
+        ] {
+            buffer.clear();
+            interval.write_to(&mut buffer, &iso).unwrap();
+            lines.push(buffer.clone());
+        }
+        assert_eq!(lines, ["]1; 3]", "]−∞; 5["]);
+
+        buffer.clear();
+        Interval::since_inclusive(7)
+            .write_to(&mut buffer, &IntervalFormat::default())
+            .unwrap();
+        assert_eq!(buffer, Interval::since_inclusive(7).to_string());
+    }
+}
@@ -0,0 +1,85 @@
+use std::ops::{Add, Sub};
+
+mod sealed {
+    /// Keeps [`Integer`](super::Integer) implementable only within this
+    /// crate.
+    pub trait Sealed {}
+}
+
+/// The primitive integer arithmetic the crate relies on to step, shift
+/// and pad bounds without panicking on overflow.
+///
+/// Implemented for every primitive integer type. Every integer is
+/// [`Discrete`](crate::Discrete) through this trait. The trait is
+/// sealed, so it can be used as a bound but not implemented outside
+/// this crate.
+pub trait Integer: sealed::Sealed + Ord + Copy + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    /// Returns `self + other`, or `None` on overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Returns `self - other`, or `None` on overflow.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Returns `self + other` clamped to [`Integer::MIN`] and
+    /// [`Integer::MAX`].
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Returns `self - other` clamped to [`Integer::MIN`] and
+    /// [`Integer::MAX`].
+    fn saturating_sub(self, other: Self) -> Self;
+
+    /// Returns the distance between `self` and `other`, which always
+    /// fits a `u128`.
+    fn abs_diff(self, other: Self) -> u128;
+
+    /// Returns `self + steps` in two's complement, wrapping around at
+    /// the top of the type.
+    fn wrapping_add_steps(self, steps: u128) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty => $unsigned:ty),*) => {
+        $(impl sealed::Sealed for $t {}
+
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                <$t>::saturating_add(self, other)
+            }
+
+            fn saturating_sub(self, other: Self) -> Self {
+                <$t>::saturating_sub(self, other)
+            }
+
+            fn abs_diff(self, other: Self) -> u128 {
+                <$t>::abs_diff(self, other) as u128
+            }
+
+            fn wrapping_add_steps(self, steps: u128) -> Self {
+                (self as $unsigned).wrapping_add(steps as $unsigned) as $t
+            }
+        })*
+    };
+}
+
+impl_integer!(
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize
+);
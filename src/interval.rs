@@ -1,4 +1,6 @@
-use crate::bound_point::BoundPoint;
+use crate::bound_point::{seam, BoundPoint, BoundProximity, BoundValue, Seam};
+use crate::interval_set::IntervalSet;
+use std::ops::{AddAssign, Sub};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum IntervalType {
@@ -9,17 +11,39 @@ pub enum IntervalType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Interval<T>
 where
     T: Ord,
 {
-    start: BoundPoint<T>,
-    end: BoundPoint<T>,
+    pub(crate) start: BoundPoint<T>,
+    pub(crate) end: BoundPoint<T>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum IntervalError {
     StartMustBeMinorThanEnd,
+
+    /// A start bound `before(x)` or an end bound `after(x)`, which no
+    /// interval has: starts are inclusive or `after`, ends inclusive or
+    /// `before`.
+    InvalidProximity,
+
+    /// The operation needs finite bounds on both sides.
+    Unbounded,
+
+    /// Weights were given, but none of them is positive, or none at all.
+    NoWeight,
+
+    /// A period or step is zero or negative.
+    NonPositivePeriod,
+
+    /// A computed bound or index does not fit in its type.
+    Overflow,
+
+    /// The constraints at these two positions leave no value between
+    /// them; see [`IntervalSet::from_constraints`].
+    Contradiction(usize, usize),
 }
 
 impl<T: Ord> Interval<T> {
@@ -81,19 +105,347 @@ impl<T: Ord> Interval<T> {
         }
     }
 
+    /// Checks that raw bound points form an interval the public
+    /// constructors could have built: the start value must not exceed
+    /// the end value, whatever the proximities, and neither bound may
+    /// face the wrong way.
+    pub(crate) fn validate_points(
+        start: &BoundPoint<T>,
+        end: &BoundPoint<T>,
+    ) -> Result<(), IntervalError> {
+        if start.proximity() == Some(BoundProximity::Before)
+            || end.proximity() == Some(BoundProximity::After)
+        {
+            return Err(IntervalError::InvalidProximity);
+        }
+        match (start.finite_value(), end.finite_value()) {
+            (Some(start), Some(end)) => Self::validate(start, end),
+            _ if start > end
+                || *start == BoundPoint::pos_infinity()
+                || *end == BoundPoint::neg_infinity() =>
+            {
+                Err(IntervalError::StartMustBeMinorThanEnd)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Compares `value` with each bound directly rather than with a
+    /// temporary `at(value)` point; see `benches/contains.rs`.
     pub fn contains(&self, value: T) -> bool {
-        let bound_point = BoundPoint::at(value);
-        self.start <= bound_point && self.end >= bound_point
+        self.contains_value(&value)
     }
 
+    /// Like [`Interval::contains`], but borrows the value.
+    pub(crate) fn contains_value(&self, value: &T) -> bool {
+        // one comparison of the raw values per bound, the proximity only
+        // deciding whether equality passes
+        let above_start = match &self.start.value {
+            BoundValue::NegInfinity => true,
+            BoundValue::Finite(start, BoundProximity::After) => start < value,
+            BoundValue::Finite(start, _) => start <= value,
+            BoundValue::PosInfinity => false,
+        };
+        above_start
+            && match &self.end.value {
+                BoundValue::NegInfinity => false,
+                BoundValue::Finite(end, BoundProximity::Before) => value < end,
+                BoundValue::Finite(end, _) => value <= end,
+                BoundValue::PosInfinity => true,
+            }
+    }
+
+    /// Returns `true` if the intervals share at least one value: each
+    /// must end at an [overlapping](Seam::Overlapping) [`seam`] with the
+    /// start of the other.
     pub fn overlaps(&self, other: &Interval<T>) -> bool {
-        self.start <= other.end && self.end >= other.start
+        seam(&self.end, &other.start) == Seam::Overlapping
+            && seam(&other.end, &self.start) == Seam::Overlapping
+    }
+
+    /// Returns `true` if one interval ends exactly where the other
+    /// begins, at a [flush](Seam::Flush) [`seam`] such as `[1, 3)` and
+    /// `[3, 5]`, so that together they cover a gapless range.
+    pub fn is_adjacent(&self, other: &Interval<T>) -> bool {
+        seam(&self.end, &other.start) == Seam::Flush || seam(&other.end, &self.start) == Seam::Flush
+    }
+
+    /// Returns `true` if no value lies within the interval,
+    /// e.g. `(1, 1)` or `[1, 1)`.
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Returns the start value, or `None` when unbounded below.
+    ///
+    /// This drops whether the start is inclusive: `[1, 3]` and `(1, 3]`
+    /// both have infimum `1`, so use [`Interval::start_point`] where it matters
+    /// whether `1` itself is covered.
+    pub fn infimum(&self) -> Option<&T> {
+        self.start.finite_value()
+    }
+
+    /// Returns the end value, or `None` when unbounded above.
+    ///
+    /// Like [`Interval::infimum`], this drops whether the end is inclusive,
+    /// which [`Interval::end_point`] keeps.
+    pub fn supremum(&self) -> Option<&T> {
+        self.end.finite_value()
+    }
+
+    /// Takes the start and end values out of the interval, dropping
+    /// whether they were inclusive.
+    pub fn into_infimum_supremum(self) -> (Option<T>, Option<T>) {
+        (self.start.into_finite_value(), self.end.into_finite_value())
+    }
+
+    /// Returns the start bound, which keeps whether it is inclusive.
+    pub fn start_point(&self) -> &BoundPoint<T> {
+        &self.start
+    }
+
+    /// Returns the end bound, which keeps whether it is inclusive.
+    pub fn end_point(&self) -> &BoundPoint<T> {
+        &self.end
+    }
+
+    /// Takes the start and end bounds out of the interval, to be put
+    /// back together with [`Interval::try_new`].
+    pub fn into_parts(self) -> (BoundPoint<T>, BoundPoint<T>) {
+        (self.start, self.end)
+    }
+
+    /// Builds an interval from its bounds, checking them like
+    /// [`Interval::from_to`] checks its values: the start value must not
+    /// exceed the end value, a start cannot be `+inf` nor an end `-inf`,
+    /// and a start cannot be `before(x)` nor an end `after(x)`. Empty
+    /// intervals such as `(1, 1)` are accepted.
+    pub fn try_new(start: BoundPoint<T>, end: BoundPoint<T>) -> Result<Self, IntervalError> {
+        Self::validate_points(&start, &end)?;
+        Ok(Interval { start, end })
+    }
+
+    /// Builds an interval from its bounds without checking them.
+    ///
+    /// The caller must uphold what [`Interval::try_new`] checks, for
+    /// instance because the bounds come from a trusted snapshot or a
+    /// comparison already made; other methods may return wrong results
+    /// for an interval breaking these rules. Debug builds still check
+    /// the bounds and panic on invalid ones.
+    pub fn from_parts_unchecked(start: BoundPoint<T>, end: BoundPoint<T>) -> Self {
+        debug_assert!(Self::validate_points(&start, &end).is_ok());
+        Interval { start, end }
+    }
+
+    /// Checks that the bounds follow the rules [`Interval::try_new`]
+    /// enforces, so property tests can assert that no operation produces
+    /// a corrupt interval.
+    ///
+    /// An inverted interval such as `(5, 1)` fails even though both
+    /// bounds are open, while an empty one such as `(1, 1)` passes.
+    pub fn check_invariants(&self) -> Result<(), IntervalError> {
+        Self::validate_points(&self.start, &self.end)
+    }
+
+    /// Borrows the bound values, so that operations on the returned
+    /// interval work on references and never clone a `T`.
+    ///
+    /// [`Interval::cloned`] turns the final result back into an owned
+    /// interval.
+    pub fn as_ref(&self) -> Interval<&T> {
+        Interval {
+            start: self.start.as_ref(),
+            end: self.end.as_ref(),
+        }
+    }
+
+    /// Like [`Interval::intersection`], but moves the bounds out of the
+    /// operands instead of cloning them.
+    pub fn into_intersection(self, other: Interval<T>) -> Option<Interval<T>> {
+        let start = std::cmp::max(self.start, other.start);
+        let end = std::cmp::min(self.end, other.end);
+        (start <= end).then_some(Interval { start, end })
+    }
+}
+
+impl<T: Ord + Clone> Interval<&T> {
+    /// Clones the borrowed bound values into an owned interval.
+    pub fn cloned(self) -> Interval<T> {
+        Interval {
+            start: self.start.map(T::clone),
+            end: self.end.map(T::clone),
+        }
+    }
+}
+
+impl<T: Ord + Clone> Interval<T> {
+    /// Returns the values shared by both intervals,
+    /// or `None` when they do not overlap.
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let start = std::cmp::max(&self.start, &other.start);
+        let end = std::cmp::min(&self.end, &other.end);
+        if start > end {
+            return None;
+        }
+        Some(Interval {
+            start: start.clone(),
+            end: end.clone(),
+        })
+    }
+
+    /// Narrows this interval to the values it shares with `other`, like
+    /// [`Interval::intersection`] without building a new interval, and
+    /// returns `true`.
+    ///
+    /// When the intervals share no value, returns `false` and leaves this
+    /// interval unchanged. Only the bounds that move are cloned.
+    pub fn intersect_with(&mut self, other: &Interval<T>) -> bool {
+        if std::cmp::max(&self.start, &other.start) > std::cmp::min(&self.end, &other.end) {
+            return false;
+        }
+        if other.start > self.start {
+            self.start.clone_from(&other.start);
+        }
+        if other.end < self.end {
+            self.end.clone_from(&other.end);
+        }
+        true
+    }
+
+    /// Widens this interval to [`Interval::hull`] of itself and `other`
+    /// without building a new interval. Only the bounds that move are
+    /// cloned.
+    pub fn hull_with(&mut self, other: &Interval<T>) {
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() {
+            self.clone_from(other);
+            return;
+        }
+        if other.start < self.start {
+            self.start.clone_from(&other.start);
+        }
+        if other.end > self.end {
+            self.end.clone_from(&other.end);
+        }
+    }
+
+    /// Returns the interval moved by `delta`, keeping the inclusiveness.
+    /// Infinite bounds stay infinite.
+    pub fn shift(&self, delta: &T) -> Interval<T>
+    where
+        T: for<'a> AddAssign<&'a T>,
+    {
+        let mut shifted = self.clone();
+        shifted.shift_mut(delta);
+        shifted
+    }
+
+    /// Returns the values of both intervals as one interval when they
+    /// overlap or are [adjacent](Interval::is_adjacent), or `None` when
+    /// a gap separates them. An empty interval adds nothing to the other.
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.is_empty() || other.is_empty() || self.overlaps(other) || self.is_adjacent(other) {
+            Some(self.hull(other))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest interval containing both intervals and any
+    /// gap between them. An empty interval adds nothing to the other.
+    pub fn hull(&self, other: &Interval<T>) -> Interval<T> {
+        if other.is_empty() {
+            return self.clone();
+        }
+        if self.is_empty() {
+            return other.clone();
+        }
+        Interval {
+            start: std::cmp::min(&self.start, &other.start).clone(),
+            end: std::cmp::max(&self.end, &other.end).clone(),
+        }
+    }
+
+    /// Removes the values of `other` from this interval and returns the
+    /// remaining pieces in order: none, one, or two when `other` falls
+    /// strictly inside. Pieces are never empty.
+    pub fn difference(&self, other: &Interval<T>) -> Vec<Interval<T>> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        self.subtract_all([other])
+    }
+
+    /// Removes every interval of `others` from this one and returns the
+    /// remaining pieces, sorted and disjoint.
+    ///
+    /// The exclusions may overlap each other and extend beyond this
+    /// interval; they are merged first, so the whole operation takes
+    /// `O(n log n)`. When nothing is excluded the result is
+    /// `vec![self.clone()]`, even for an empty interval, and full
+    /// coverage leaves nothing.
+    pub fn subtract_all<'a, I>(&self, others: I) -> Vec<Interval<T>>
+    where
+        I: IntoIterator<Item = &'a Interval<T>>,
+        T: 'a,
+    {
+        let excluded: IntervalSet<T> = others.into_iter().cloned().collect();
+        if excluded.is_empty() {
+            return vec![self.clone()];
+        }
+        excluded.gaps_within(self).collect()
+    }
+
+    /// Cuts this interval at the member bounds of `set` and returns the
+    /// pieces in order, each flagged `true` if it lies within the set.
+    ///
+    /// The pieces are disjoint and cover exactly this interval, and
+    /// consecutive pieces always differ in their flag. Bounds between
+    /// pieces come from the members: a member ending `before(x)` is
+    /// followed by an uncovered piece starting `at(x)`.
+    pub fn split_by_set(&self, set: &IntervalSet<T>) -> Vec<(Interval<T>, bool)> {
+        let covered = set
+            .overlapping(self)
+            .filter_map(|member| member.intersection(self))
+            .map(|piece| (piece, true));
+        let uncovered = set.gaps_within(self).map(|piece| (piece, false));
+        let mut pieces: Vec<_> = covered.chain(uncovered).collect();
+        pieces.sort_unstable_by(|(a, _), (b, _)| a.start.cmp(&b.start));
+        pieces
+    }
+}
+
+impl<T: Ord + for<'a> AddAssign<&'a T>> Interval<T> {
+    /// Moves this interval by `delta` in place, like [`Interval::shift`].
+    pub fn shift_mut(&mut self, delta: &T) {
+        if let Some(start) = self.start.finite_value_mut() {
+            *start += delta;
+        }
+        if let Some(end) = self.end.finite_value_mut() {
+            *end += delta;
+        }
+    }
+}
+
+impl<T: Ord + Clone + Sub<Output = T>> Interval<T> {
+    /// Returns the distance between the start and end values,
+    /// or `None` when either side is unbounded.
+    ///
+    /// The proximity of the bounds does not affect the length,
+    /// so `[1, 3]` and `(1, 3)` both have length `2`.
+    pub fn length(&self) -> Option<T> {
+        let start = self.start.finite_value()?;
+        let end = self.end.finite_value()?;
+        Some(end.clone() - start.clone())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{contains_half_step, Rng};
     use rstest::rstest;
 
     #[rstest]
@@ -133,6 +485,40 @@ mod tests {
             "failed: {:?}, {} → got {}, expected {}",
             interval, value, expected, actual
         );
+        assert_eq!(actual, contains_via_bound_point(&interval, value));
+    }
+
+    /// The former `contains`, comparing whole bound points.
+    fn contains_via_bound_point(interval: &Interval<i32>, value: i32) -> bool {
+        let bound_point = BoundPoint::at(value);
+        interval.start <= bound_point && interval.end >= bound_point
+    }
+
+    #[test]
+    fn test_contains_matches_bound_point_comparison() {
+        let mut rng = Rng::new(428);
+        for _ in 0..1000 {
+            let interval = rng.interval(-10, 10);
+            for value in -12..12 {
+                assert_eq!(
+                    interval.contains(value),
+                    contains_via_bound_point(&interval, value),
+                    "{interval:?} {value}"
+                );
+            }
+        }
+        for interval in [
+            Interval::from_to(i32::MIN, i32::MAX, IntervalType::Open).unwrap(),
+            Interval::until_inclusive(i32::MIN),
+            Interval::since_exclusive(i32::MAX),
+        ] {
+            for value in [i32::MIN, 0, i32::MAX] {
+                assert_eq!(
+                    interval.contains(value),
+                    contains_via_bound_point(&interval, value)
+                );
+            }
+        }
     }
 
     #[rstest]
@@ -182,4 +568,407 @@ mod tests {
             interval, other, expected, actual
         );
     }
+
+    #[rstest]
+    #[case(Interval::from_to(1, 3, IntervalType::Close).unwrap(), false)]
+    #[case(Interval::from_to(1, 1, IntervalType::Close).unwrap(), false)]
+    #[case(Interval::from_to(1, 1, IntervalType::Open).unwrap(), true)]
+    #[case(Interval::from_to(1, 1, IntervalType::StartOpen).unwrap(), true)]
+    #[case(Interval::from_to(1, 1, IntervalType::EndOpen).unwrap(), true)]
+    #[case(Interval::from_to(1, 2, IntervalType::Open).unwrap(), false)]
+    #[case(Interval::until_exclusive(1), false)]
+    #[case(Interval::since_exclusive(1), false)]
+    fn test_is_empty(#[case] interval: Interval<i32>, #[case] expected: bool) {
+        assert_eq!(interval.is_empty(), expected, "failed: {:?}", interval);
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(0, 3, IntervalType::Close).unwrap(), Interval::from_to(1, 5, IntervalType::Close).unwrap(), Some(Interval::from_to(1, 3, IntervalType::Close).unwrap()))]
+    #[case(Interval::from_to(0, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(1, 5, IntervalType::StartOpen).unwrap(), Some(Interval::from_to(1, 3, IntervalType::Open).unwrap()))]
+    #[case(Interval::from_to(0, 3, IntervalType::Close).unwrap(), Interval::from_to(3, 5, IntervalType::Close).unwrap(), Some(Interval::from_to(3, 3, IntervalType::Close).unwrap()))]
+    #[case(Interval::from_to(0, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(3, 5, IntervalType::Close).unwrap(), None)]
+    #[case(Interval::from_to(0, 3, IntervalType::Close).unwrap(), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap(), None)]
+    #[case(Interval::from_to(0, 3, IntervalType::Close).unwrap(), Interval::from_to(4, 5, IntervalType::Close).unwrap(), None)]
+    #[case(Interval::until_exclusive(3), Interval::since_inclusive(1), Some(Interval::from_to(1, 3, IntervalType::EndOpen).unwrap()))]
+    #[case(Interval::until_inclusive(3), Interval::from_to(1, 5, IntervalType::Open).unwrap(), Some(Interval::from_to(1, 3, IntervalType::StartOpen).unwrap()))]
+    fn test_intersection(
+        #[case] interval: Interval<i32>,
+        #[case] other: Interval<i32>,
+        #[case] expected: Option<Interval<i32>>,
+    ) {
+        assert_eq!(interval.intersection(&other), expected);
+        assert_eq!(other.intersection(&interval), expected);
+        assert_eq!(
+            interval.as_ref().intersection(&other.as_ref()).map(Interval::cloned),
+            expected
+        );
+        assert_eq!(interval.into_intersection(other), expected);
+    }
+
+    thread_local! {
+        static CLONES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    /// Counts its clones in `CLONES`.
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Counted(String);
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            CLONES.with(|clones| clones.set(clones.get() + 1));
+            Counted(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn test_intersection_without_clones() {
+        let intervals: Vec<_> = (0..10_000)
+            .map(|i| {
+                let start = Counted(format!("{:05}", i));
+                let end = Counted(format!("{:05}", i + 2));
+                Interval::from_to(start, end, IntervalType::EndOpen).unwrap()
+            })
+            .collect();
+        let intersections: Vec<_> = intervals
+            .windows(2)
+            .filter_map(|pair| pair[0].as_ref().intersection(&pair[1].as_ref()))
+            .collect();
+        assert_eq!(intersections.len(), 9_999);
+        assert_eq!(CLONES.with(|clones| clones.get()), 0);
+
+        let owned: Vec<_> = intersections.into_iter().map(Interval::cloned).collect();
+        assert_eq!(CLONES.with(|clones| clones.get()), 2 * owned.len());
+        assert_eq!(
+            owned[0],
+            Interval::from_to(
+                Counted("00001".to_string()),
+                Counted("00002".to_string()),
+                IntervalType::EndOpen
+            )
+            .unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(0, 3, IntervalType::Close).unwrap(), Interval::from_to(1, 5, IntervalType::Open).unwrap(), Interval::from_to(0, 5, IntervalType::EndOpen).unwrap())]
+    #[case(Interval::from_to(0, 1, IntervalType::StartOpen).unwrap(), Interval::from_to(4, 5, IntervalType::Close).unwrap(), Interval::from_to(0, 5, IntervalType::StartOpen).unwrap())]
+    #[case(Interval::from_to(0, 3, IntervalType::Open).unwrap(), Interval::from_to(0, 3, IntervalType::Close).unwrap(), Interval::from_to(0, 3, IntervalType::Close).unwrap())]
+    #[case(Interval::until_exclusive(3), Interval::from_to(1, 5, IntervalType::Close).unwrap(), Interval::until_inclusive(5))]
+    // an empty interval adds nothing, wherever it sits
+    #[case(Interval::from_to(9, 9, IntervalType::EndOpen).unwrap(), Interval::from_to(1, 5, IntervalType::Close).unwrap(), Interval::from_to(1, 5, IntervalType::Close).unwrap())]
+    fn test_hull(
+        #[case] interval: Interval<i32>,
+        #[case] other: Interval<i32>,
+        #[case] expected: Interval<i32>,
+    ) {
+        assert_eq!(interval.hull(&other), expected);
+        assert_eq!(other.hull(&interval), expected);
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(3, 5, IntervalType::Close).unwrap(), true, Some(Interval::from_to(1, 5, IntervalType::Close).unwrap()))]
+    #[case(Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap(), true, Some(Interval::from_to(1, 5, IntervalType::Close).unwrap()))]
+    // overlapping is not adjacent
+    #[case(Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(3, 5, IntervalType::Close).unwrap(), false, Some(Interval::from_to(1, 5, IntervalType::Close).unwrap()))]
+    // the missing point 3 is a gap
+    #[case(Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap(), false, None)]
+    #[case(Interval::from_to(1, 2, IntervalType::Close).unwrap(), Interval::from_to(3, 5, IntervalType::Close).unwrap(), false, None)]
+    #[case(Interval::until_exclusive(0), Interval::since_inclusive(0), true, Some(Interval { start: BoundPoint::neg_infinity(), end: BoundPoint::pos_infinity() }))]
+    #[case(Interval::from_to(9, 9, IntervalType::Open).unwrap(), Interval::from_to(1, 5, IntervalType::Close).unwrap(), false, Some(Interval::from_to(1, 5, IntervalType::Close).unwrap()))]
+    fn test_is_adjacent_and_union(
+        #[case] interval: Interval<i32>,
+        #[case] other: Interval<i32>,
+        #[case] adjacent: bool,
+        #[case] union: Option<Interval<i32>>,
+    ) {
+        assert_eq!(interval.is_adjacent(&other), adjacent);
+        assert_eq!(other.is_adjacent(&interval), adjacent);
+        assert_eq!(interval.union(&other), union);
+        assert_eq!(other.union(&interval), union);
+    }
+
+    #[test]
+    fn test_overlaps_and_union_match_brute_force() {
+        let mut rng = Rng::new(410);
+        for _ in 0..2000 {
+            let (a, b) = (rng.interval(-10, 10), rng.interval(-10, 10));
+            if a.is_empty() || b.is_empty() {
+                continue;
+            }
+            let shared = (-30..30).any(|half_steps| {
+                contains_half_step(&a, half_steps) && contains_half_step(&b, half_steps)
+            });
+            assert_eq!(a.overlaps(&b), shared, "failed: {:?}, {:?}", a, b);
+            if let Some(union) = a.union(&b) {
+                for half_steps in -30..30 {
+                    assert_eq!(
+                        contains_half_step(&union, half_steps),
+                        contains_half_step(&a, half_steps) || contains_half_step(&b, half_steps),
+                        "failed: {:?}, {:?}",
+                        a,
+                        b
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bound_roles_over_every_raw_pair() {
+        let points: Vec<BoundPoint<i32>> = [BoundPoint::neg_infinity(), BoundPoint::pos_infinity()]
+            .into_iter()
+            .chain((0..3).flat_map(|v| {
+                [
+                    BoundPoint::before(v),
+                    BoundPoint::at(v),
+                    BoundPoint::after(v),
+                ]
+            }))
+            .collect();
+        let mut intervals = Vec::new();
+        for start in &points {
+            for end in &points {
+                let interval = Interval::try_new(*start, *end);
+                let wrong_way = start.proximity() == Some(BoundProximity::Before)
+                    || end.proximity() == Some(BoundProximity::After);
+                if wrong_way {
+                    assert_eq!(interval, Err(IntervalError::InvalidProximity));
+                } else if let Ok(interval) = interval {
+                    intervals.push(interval);
+                }
+            }
+        }
+        // start-vs-value and end-vs-value in `contains`, start-vs-end in
+        // `is_empty`
+        for interval in &intervals {
+            for value in -1..4 {
+                assert_eq!(
+                    interval.contains(value),
+                    contains_half_step(interval, value * 2)
+                );
+            }
+            let holds_any = (-4..10).any(|half_steps| contains_half_step(interval, half_steps));
+            assert_eq!(interval.is_empty(), !holds_any, "{interval:?}");
+        }
+        // end-vs-start in `overlaps`
+        for a in intervals.iter().filter(|interval| !interval.is_empty()) {
+            for b in intervals.iter().filter(|interval| !interval.is_empty()) {
+                let shared = (-4..10).any(|half_steps| {
+                    contains_half_step(a, half_steps) && contains_half_step(b, half_steps)
+                });
+                assert_eq!(a.overlaps(b), shared, "{a:?} {b:?}");
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(1, 3, IntervalType::Close).unwrap(), Some(1), Some(3))]
+    #[case(Interval::from_to(1, 3, IntervalType::Open).unwrap(), Some(1), Some(3))]
+    #[case(Interval::until_exclusive(3), None, Some(3))]
+    #[case(Interval::since_inclusive(1), Some(1), None)]
+    fn test_infimum_supremum(
+        #[case] interval: Interval<i32>,
+        #[case] infimum: Option<i32>,
+        #[case] supremum: Option<i32>,
+    ) {
+        assert_eq!(interval.infimum(), infimum.as_ref());
+        assert_eq!(interval.supremum(), supremum.as_ref());
+        assert_eq!(interval.into_infimum_supremum(), (infimum, supremum));
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(1, 3, IntervalType::Close).unwrap(), Some(2))]
+    #[case(Interval::from_to(1, 3, IntervalType::Open).unwrap(), Some(2))]
+    #[case(Interval::from_to(1, 1, IntervalType::Close).unwrap(), Some(0))]
+    #[case(Interval::from_to(-4, 3, IntervalType::EndOpen).unwrap(), Some(7))]
+    #[case(Interval::until_inclusive(3), None)]
+    #[case(Interval::since_exclusive(3), None)]
+    fn test_length(#[case] interval: Interval<i32>, #[case] expected: Option<i32>) {
+        assert_eq!(interval.length(), expected);
+    }
+
+    #[rstest]
+    #[case(vec![], vec![Interval::from_to(0, 10, IntervalType::EndOpen).unwrap()])]
+    #[case(vec![Interval::from_to(2, 4, IntervalType::EndOpen).unwrap()],
+           vec![Interval::from_to(0, 2, IntervalType::EndOpen).unwrap(), Interval::from_to(4, 10, IntervalType::EndOpen).unwrap()])]
+    #[case(vec![Interval::from_to(2, 4, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(0, 2, IntervalType::EndOpen).unwrap(), Interval::from_to(4, 10, IntervalType::Open).unwrap()])]
+    // overlapping exclusions in any order
+    #[case(vec![Interval::from_to(6, 7, IntervalType::Close).unwrap(), Interval::from_to(3, 5, IntervalType::Close).unwrap(), Interval::from_to(2, 4, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(0, 2, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 6, IntervalType::Open).unwrap(), Interval::from_to(7, 10, IntervalType::Open).unwrap()])]
+    // exclusions extending beyond the interval
+    #[case(vec![Interval::until_exclusive(3), Interval::from_to(8, 20, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(3, 8, IntervalType::EndOpen).unwrap()])]
+    // a single excluded point
+    #[case(vec![Interval::from_to(5, 5, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(0, 5, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 10, IntervalType::Open).unwrap()])]
+    // full coverage
+    #[case(vec![Interval::from_to(0, 5, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 10, IntervalType::Close).unwrap()], vec![])]
+    // exclusions outside the interval
+    #[case(vec![Interval::from_to(10, 12, IntervalType::Close).unwrap()], vec![Interval::from_to(0, 10, IntervalType::EndOpen).unwrap()])]
+    fn test_subtract_all(#[case] others: Vec<Interval<i32>>, #[case] expected: Vec<Interval<i32>>) {
+        let interval = Interval::from_to(0, 10, IntervalType::EndOpen).unwrap();
+        assert_eq!(interval.subtract_all(&others), expected);
+    }
+
+    #[test]
+    fn test_subtract_all_from_empty_interval() {
+        let empty = Interval::from_to(3, 3, IntervalType::EndOpen).unwrap();
+        assert_eq!(empty.subtract_all([]), vec![empty]);
+        let excluded = Interval::from_to(0, 5, IntervalType::Close).unwrap();
+        assert!(empty.subtract_all([&excluded]).is_empty());
+    }
+
+    #[rstest]
+    #[case(vec![], vec![(Interval::from_to(0, 10, IntervalType::EndOpen).unwrap(), false)])]
+    #[case(vec![Interval::from_to(-5, 15, IntervalType::Close).unwrap()], vec![(Interval::from_to(0, 10, IntervalType::EndOpen).unwrap(), true)])]
+    #[case(vec![Interval::from_to(2, 4, IntervalType::EndOpen).unwrap(), Interval::from_to(6, 12, IntervalType::StartOpen).unwrap()],
+           vec![(Interval::from_to(0, 2, IntervalType::EndOpen).unwrap(), false),
+                (Interval::from_to(2, 4, IntervalType::EndOpen).unwrap(), true),
+                (Interval::from_to(4, 6, IntervalType::Close).unwrap(), false),
+                (Interval::from_to(6, 10, IntervalType::Open).unwrap(), true)])]
+    #[case(vec![Interval::until_inclusive(0), Interval::from_to(5, 5, IntervalType::Close).unwrap()],
+           vec![(Interval::from_to(0, 0, IntervalType::Close).unwrap(), true),
+                (Interval::from_to(0, 5, IntervalType::Open).unwrap(), false),
+                (Interval::from_to(5, 5, IntervalType::Close).unwrap(), true),
+                (Interval::from_to(5, 10, IntervalType::Open).unwrap(), false)])]
+    fn test_split_by_set(#[case] members: Vec<Interval<i32>>, #[case] expected: Vec<(Interval<i32>, bool)>) {
+        let interval = Interval::from_to(0, 10, IntervalType::EndOpen).unwrap();
+        assert_eq!(interval.split_by_set(&IntervalSet::from(members)), expected);
+    }
+
+    #[test]
+    fn test_in_place_operations_match_pure_ones() {
+        let mut rng = Rng::new(393);
+        for _ in 0..2000 {
+            let (a, b) = (rng.interval(-20, 20), rng.interval(-20, 20));
+
+            let mut intersected = a;
+            let shared = intersected.intersect_with(&b);
+            match a.intersection(&b) {
+                Some(expected) => assert!(shared && intersected == expected, "{:?}, {:?}", a, b),
+                None => assert!(!shared && intersected == a, "{:?}, {:?}", a, b),
+            }
+
+            let mut hull = a;
+            hull.hull_with(&b);
+            assert_eq!(hull, a.hull(&b), "{:?}, {:?}", a, b);
+
+            let delta = rng.range(-10, 10);
+            let mut shifted = a;
+            shifted.shift_mut(&delta);
+            assert_eq!(shifted, a.shift(&delta));
+            for half_steps in -60..60 {
+                assert_eq!(
+                    contains_half_step(&shifted, half_steps + 2 * delta),
+                    contains_half_step(&a, half_steps),
+                    "{:?}, {}",
+                    a,
+                    delta
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_intersect_with_clones_only_moved_bounds() {
+        let mut interval = Interval::from_to(
+            Counted("a".to_string()),
+            Counted("m".to_string()),
+            IntervalType::Close,
+        )
+        .unwrap();
+        let other = Interval::from_to(
+            Counted("c".to_string()),
+            Counted("z".to_string()),
+            IntervalType::Open,
+        )
+        .unwrap();
+        let before = CLONES.with(|clones| clones.get());
+        assert!(interval.intersect_with(&other));
+        assert_eq!(CLONES.with(|clones| clones.get()) - before, 1);
+        assert_eq!(
+            interval,
+            Interval::from_to(
+                Counted("c".to_string()),
+                Counted("m".to_string()),
+                IntervalType::StartOpen
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_split_by_set_of_empty_interval() {
+        let interval = Interval::from_to(3, 3, IntervalType::EndOpen).unwrap();
+        let set = IntervalSet::from(vec![Interval::since_inclusive(0)]);
+        assert_eq!(interval.split_by_set(&set), vec![]);
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(1, 5, IntervalType::Open).unwrap())]
+    #[case(Interval::from_to(1, 5, IntervalType::StartOpen).unwrap())]
+    #[case(Interval::from_to(1, 5, IntervalType::EndOpen).unwrap())]
+    #[case(Interval::from_to(1, 5, IntervalType::Close).unwrap())]
+    #[case(Interval::from_to(3, 3, IntervalType::Open).unwrap())]
+    #[case(Interval::since_exclusive(3))]
+    #[case(Interval::since_inclusive(3))]
+    #[case(Interval::until_exclusive(3))]
+    #[case(Interval::until_inclusive(3))]
+    #[case(Interval { start: BoundPoint::neg_infinity(), end: BoundPoint::pos_infinity() })]
+    fn test_into_parts_round_trip(#[case] interval: Interval<i32>) {
+        assert_eq!(interval.start_point(), &interval.start);
+        assert_eq!(interval.end_point(), &interval.end);
+        let (start, end) = interval.into_parts();
+        assert_eq!(Interval::try_new(start, end), Ok(interval));
+    }
+
+    #[rstest]
+    #[case(BoundPoint::at(5), BoundPoint::at(1), IntervalError::StartMustBeMinorThanEnd)]
+    #[case(BoundPoint::after(5), BoundPoint::before(1), IntervalError::StartMustBeMinorThanEnd)]
+    #[case(BoundPoint::pos_infinity(), BoundPoint::pos_infinity(), IntervalError::StartMustBeMinorThanEnd)]
+    #[case(BoundPoint::neg_infinity(), BoundPoint::neg_infinity(), IntervalError::StartMustBeMinorThanEnd)]
+    #[case(BoundPoint::before(1), BoundPoint::at(5), IntervalError::InvalidProximity)]
+    #[case(BoundPoint::at(1), BoundPoint::after(5), IntervalError::InvalidProximity)]
+    fn test_try_new_invalid(
+        #[case] start: BoundPoint<i32>,
+        #[case] end: BoundPoint<i32>,
+        #[case] expected: IntervalError,
+    ) {
+        assert_eq!(Interval::try_new(start, end), Err(expected));
+        assert_eq!(Interval { start, end }.check_invariants(), Err(expected));
+    }
+
+    #[test]
+    fn test_check_invariants_of_random_operations() {
+        let mut rng = Rng::new(417);
+        for _ in 0..1000 {
+            let a = rng.interval(-10, 10);
+            let b = rng.interval(-10, 10);
+            assert_eq!(a.check_invariants(), Ok(()));
+            let results = a
+                .intersection(&b)
+                .into_iter()
+                .chain(a.union(&b))
+                .chain(a.difference(&b))
+                .chain([a.hull(&b)]);
+            for result in results {
+                assert_eq!(result.check_invariants(), Ok(()), "{:?}, {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_parts_unchecked() {
+        let interval = Interval::from_to(1, 1, IntervalType::Open).unwrap();
+        let (start, end) = interval.into_parts();
+        assert_eq!(Interval::from_parts_unchecked(start, end), interval);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_from_parts_unchecked_checks_in_debug_builds() {
+        let _ = Interval::from_parts_unchecked(BoundPoint::at(5), BoundPoint::at(1));
+    }
 }
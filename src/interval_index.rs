@@ -0,0 +1,221 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::Interval;
+use std::ops::Range;
+
+/// A static, read-optimized index over possibly overlapping intervals.
+///
+/// The intervals are sorted by start bound, and for every position the
+/// index remembers which interval up to there reaches furthest. A query
+/// only scans the intervals starting at or before its end, and skips the
+/// whole prefix whose furthest end still falls short of its start. This
+/// is cheaper to build than an [`IntervalTree`](crate::IntervalTree) and
+/// fast whenever few intervals reach far beyond their successors.
+///
+/// Empty intervals contain no value and are dropped on construction.
+#[derive(Debug, Clone)]
+pub struct IntervalIndex<T>
+where
+    T: Ord,
+{
+    intervals: Vec<Interval<T>>,
+    /// For each position, the index of the interval with the greatest
+    /// end bound among the intervals up to that position.
+    furthest: Vec<usize>,
+}
+
+impl<T: Ord> IntervalIndex<T> {
+    pub fn new(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort_by(|a, b| a.start.cmp(&b.start));
+
+        let mut furthest: Vec<usize> = Vec::with_capacity(intervals.len());
+        for (index, interval) in intervals.iter().enumerate() {
+            match furthest.last() {
+                Some(&previous) if intervals[previous].end >= interval.end => {
+                    furthest.push(previous)
+                }
+                _ => furthest.push(index),
+            }
+        }
+        IntervalIndex {
+            intervals,
+            furthest,
+        }
+    }
+
+    /// Returns the indexed intervals, sorted by start bound.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// Returns the intervals containing `value`, sorted by start bound.
+    pub fn query_point<'a>(&'a self, value: &'a T) -> impl Iterator<Item = &'a Interval<T>> + 'a {
+        let range = self.candidates(
+            |start| start.cmp_value(value).is_le(),
+            |end| end.cmp_value(value).is_lt(),
+        );
+        self.intervals[range]
+            .iter()
+            .filter(move |interval| interval.end.cmp_value(value).is_ge())
+    }
+
+    /// Returns the position in [`intervals`](Self::intervals) of the first
+    /// interval containing `value`, or `None` if no interval contains it.
+    pub fn first_containing(&self, value: &T) -> Option<usize> {
+        let range = self.candidates(
+            |start| start.cmp_value(value).is_le(),
+            |end| end.cmp_value(value).is_lt(),
+        );
+        range
+            .into_iter()
+            .find(|&index| self.intervals[index].end.cmp_value(value).is_ge())
+    }
+
+    /// Returns [`first_containing`](Self::first_containing) for each of `points`.
+    pub fn classify(&self, points: &[T]) -> Vec<Option<usize>> {
+        points
+            .iter()
+            .map(|point| self.first_containing(point))
+            .collect()
+    }
+
+    /// Returns the intervals overlapping `query`, sorted by start bound.
+    pub fn query_interval<'a>(
+        &'a self,
+        query: &'a Interval<T>,
+    ) -> impl Iterator<Item = &'a Interval<T>> + 'a {
+        let range = if query.is_empty() {
+            0..0
+        } else {
+            self.candidates(|start| *start <= query.end, |end| *end < query.start)
+        };
+        self.intervals[range]
+            .iter()
+            .filter(move |interval| interval.end >= query.start)
+    }
+
+    /// Returns the positions a query has to scan: the intervals starting
+    /// early enough, minus the prefix in which no interval ends late enough.
+    pub(crate) fn candidates<S, E>(&self, starts_early_enough: S, ends_too_early: E) -> Range<usize>
+    where
+        S: Fn(&BoundPoint<T>) -> bool,
+        E: Fn(&BoundPoint<T>) -> bool,
+    {
+        let hi = self
+            .intervals
+            .partition_point(|interval| starts_early_enough(&interval.start));
+        let lo = self.furthest[..hi]
+            .partition_point(|&index| ends_too_early(&self.intervals[index].end));
+        lo..hi
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::Rng;
+    use rstest::rstest;
+
+    fn index() -> IntervalIndex<i32> {
+        IntervalIndex::new(vec![
+            Interval::from_to(5, 8, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(1, 5, IntervalType::Close).unwrap(),
+            Interval::from_to(3, 5, IntervalType::Open).unwrap(),
+            Interval::from_to(5, 8, IntervalType::StartOpen).unwrap(),
+            Interval::until_exclusive(3),
+            Interval::since_inclusive(8),
+            Interval::from_to(4, 4, IntervalType::Open).unwrap(),
+        ])
+    }
+
+    #[rstest]
+    #[case(0, vec![Interval::until_exclusive(3)])]
+    #[case(3, vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()])]
+    #[case(5, vec![Interval::from_to(1, 5, IntervalType::Close).unwrap(), Interval::from_to(5, 8, IntervalType::EndOpen).unwrap()])]
+    #[case(8, vec![Interval::from_to(5, 8, IntervalType::StartOpen).unwrap(), Interval::since_inclusive(8)])]
+    fn test_query_point(#[case] value: i32, #[case] expected: Vec<Interval<i32>>) {
+        let index = index();
+        assert_eq!(
+            index.query_point(&value).cloned().collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(3, 5, IntervalType::EndOpen).unwrap(),
+           vec![Interval::from_to(1, 5, IntervalType::Close).unwrap(), Interval::from_to(3, 5, IntervalType::Open).unwrap()])]
+    #[case(Interval::from_to(8, 9, IntervalType::StartOpen).unwrap(), vec![Interval::since_inclusive(8)])]
+    #[case(Interval::from_to(4, 4, IntervalType::Open).unwrap(), vec![])]
+    fn test_query_interval(#[case] query: Interval<i32>, #[case] expected: Vec<Interval<i32>>) {
+        let index = index();
+        assert_eq!(
+            index.query_interval(&query).cloned().collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let mut rng = Rng::new(361);
+        for _ in 0..100 {
+            let intervals: Vec<_> = (0..rng.range(0, 50))
+                .map(|_| rng.interval(-100, 100))
+                .collect();
+            let index = IntervalIndex::new(intervals.clone());
+            for value in -110..110 {
+                let expected = intervals
+                    .iter()
+                    .filter(|interval| interval.contains(value))
+                    .count();
+                assert_eq!(index.query_point(&value).count(), expected);
+            }
+            for _ in 0..50 {
+                let query = rng.interval(-110, 110);
+                let expected = intervals
+                    .iter()
+                    .filter(|interval| {
+                        !interval.is_empty() && !query.is_empty() && interval.overlaps(&query)
+                    })
+                    .count();
+                assert_eq!(
+                    index.query_interval(&query).count(),
+                    expected,
+                    "failed: {:?}",
+                    query
+                );
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(-1, Some(0))]
+    #[case(3, Some(1))]
+    #[case(5, Some(1))]
+    #[case(8, Some(4))]
+    #[case(100, Some(5))]
+    fn test_first_containing(#[case] value: i32, #[case] expected: Option<usize>) {
+        let index = index();
+        assert_eq!(index.first_containing(&value), expected);
+        assert_eq!(index.classify(&[value, value]), vec![expected, expected]);
+    }
+
+    #[test]
+    fn test_scan_stops_at_cutoff() {
+        // 100k short, barely overlapping intervals
+        let intervals: Vec<_> = (0..100_000)
+            .map(|start| {
+                Interval::from_to(start * 10, start * 10 + 15, IntervalType::Close).unwrap()
+            })
+            .collect();
+        let index = IntervalIndex::new(intervals);
+        for value in [0, 12, 500_000, 999_999] {
+            let scanned = index.candidates(
+                |start| start.cmp_value(&value).is_le(),
+                |end| end.cmp_value(&value).is_lt(),
+            );
+            assert!(scanned.len() <= 2, "scanned {:?} for {}", scanned, value);
+            assert!(index.query_point(&value).count() >= 1);
+        }
+    }
+}
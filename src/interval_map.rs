@@ -0,0 +1,324 @@
+use crate::interval::Interval;
+use std::fmt::{self, Display, Formatter};
+
+/// Error returned by [`IntervalMap::insert`] when the new key
+/// overlaps a key already in the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverlapError;
+
+impl Display for OverlapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "interval overlaps a key already in the map")
+    }
+}
+
+impl std::error::Error for OverlapError {}
+
+/// A map from pairwise disjoint intervals to values.
+///
+/// Entries are kept sorted by key, so point lookups are binary searches.
+/// Unlike the members of an [`IntervalSet`](crate::IntervalSet), keys are
+/// never merged: two keys meeting at a flush seam such as `[1, 3)` and
+/// `[3, 5]` remain separate entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalMap<T, V>
+where
+    T: Ord,
+{
+    entries: Vec<(Interval<T>, V)>,
+}
+
+impl<T: Ord, V> IntervalMap<T, V> {
+    pub fn new() -> Self {
+        IntervalMap {
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds an entry, unless `interval` overlaps an existing key.
+    ///
+    /// Inserting an empty interval is a no-op, since no value could
+    /// ever be looked up through it.
+    pub fn insert(&mut self, interval: Interval<T>, value: V) -> Result<(), OverlapError> {
+        if interval.is_empty() {
+            return Ok(());
+        }
+        let index = self
+            .entries
+            .partition_point(|(key, _)| key.start < interval.start);
+        let overlaps_previous = index > 0 && self.entries[index - 1].0.overlaps(&interval);
+        let overlaps_next = index < self.entries.len() && self.entries[index].0.overlaps(&interval);
+        if overlaps_previous || overlaps_next {
+            return Err(OverlapError);
+        }
+        self.entries.insert(index, (interval, value));
+        Ok(())
+    }
+
+    /// Returns the value whose key contains `value`.
+    pub fn get(&self, value: &T) -> Option<&V> {
+        self.get_key_value(value).map(|(_, v)| v)
+    }
+
+    /// Returns the entry whose key contains `value`.
+    pub fn get_key_value(&self, value: &T) -> Option<(&Interval<T>, &V)> {
+        let index = self
+            .entries
+            .partition_point(|(key, _)| key.start.cmp_value(value).is_le());
+        let (key, v) = self.entries[..index].last()?;
+        key.contains_value(value).then_some((key, v))
+    }
+
+    /// Removes the entry whose key is exactly `interval`
+    /// and returns its value.
+    pub fn remove(&mut self, interval: &Interval<T>) -> Option<V> {
+        let index = self
+            .entries
+            .binary_search_by(|(key, _)| key.start.cmp(&interval.start))
+            .ok()?;
+        if self.entries[index].0 != *interval {
+            return None;
+        }
+        Some(self.entries.remove(index).1)
+    }
+
+    /// Returns the entries whose key overlaps `query`, in key order.
+    ///
+    /// The matching entries form a contiguous run, located with two
+    /// binary searches. Keys merely touching `query` at a flush seam,
+    /// such as `[1, 3)` against `[3, 5]`, do not overlap it.
+    pub fn overlapping(&self, query: &Interval<T>) -> impl Iterator<Item = (&Interval<T>, &V)> {
+        let lo = self
+            .entries
+            .partition_point(|(key, _)| key.end < query.start);
+        let hi = self
+            .entries
+            .partition_point(|(key, _)| key.start <= query.end);
+        self.entries[lo..hi.max(lo)]
+            .iter()
+            .map(|(key, value)| (key, value))
+    }
+
+    /// Returns the single entry whose key contains all of `query`,
+    /// or `None` when `query` is not inside one key, for instance
+    /// because it straddles two of them.
+    pub fn covering(&self, query: &Interval<T>) -> Option<(&Interval<T>, &V)> {
+        let index = self
+            .entries
+            .partition_point(|(key, _)| key.end < query.start);
+        let (key, value) = self.entries.get(index)?;
+        (key.start <= query.start && key.end >= query.end).then_some((key, value))
+    }
+
+    /// Returns the entries in ascending key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Interval<T>, &V)> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+}
+
+impl<T: Ord + Clone, V: Clone> IntervalMap<T, V> {
+    /// Adds an entry, overwriting whatever `interval` covers.
+    ///
+    /// Existing entries partly covered by `interval` are trimmed to the
+    /// uncovered part, keeping their value; an entry covering both sides
+    /// of `interval` is split in two, which clones its value.
+    pub fn insert_replace(&mut self, interval: Interval<T>, value: V) {
+        if interval.is_empty() {
+            return;
+        }
+        let lo = self
+            .entries
+            .partition_point(|(key, _)| key.end < interval.start);
+        let hi = self
+            .entries
+            .partition_point(|(key, _)| key.start <= interval.end);
+
+        let mut replacement = Vec::with_capacity(3);
+        if lo < hi {
+            let (first, first_value) = &self.entries[lo];
+            if first.start < interval.start {
+                if let Some(end) = interval.start.clone().adjacent_end_before() {
+                    let left = Interval {
+                        start: first.start.clone(),
+                        end,
+                    };
+                    replacement.push((left, first_value.clone()));
+                }
+            }
+        }
+        let right = if lo < hi {
+            let (last, last_value) = &self.entries[hi - 1];
+            if last.end > interval.end {
+                interval.end.clone().adjacent_start_after().map(|start| {
+                    let right = Interval {
+                        start,
+                        end: last.end.clone(),
+                    };
+                    (right, last_value.clone())
+                })
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        replacement.push((interval, value));
+        replacement.extend(right);
+        self.entries.splice(lo..hi, replacement);
+    }
+}
+
+impl<T: Ord, V> Default for IntervalMap<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{closed, end_open, open};
+    use rstest::rstest;
+
+    // [0, 10) -> a, [10, 20] -> b, (20, 30) -> c, [40, +inf) -> d
+    fn tariffs() -> IntervalMap<i32, char> {
+        let mut map = IntervalMap::new();
+        map.insert(closed(10, 20), 'b').unwrap();
+        map.insert(Interval::since_inclusive(40), 'd').unwrap();
+        map.insert(end_open(0, 10), 'a').unwrap();
+        map.insert(open(20, 30), 'c').unwrap();
+        map
+    }
+
+    #[rstest]
+    #[case(-1, None)]
+    #[case(0, Some('a'))]
+    #[case(9, Some('a'))]
+    // exclusive end of 'a' meeting the inclusive start of 'b'
+    #[case(10, Some('b'))]
+    // inclusive end of 'b' meeting the exclusive start of 'c'
+    #[case(20, Some('b'))]
+    #[case(21, Some('c'))]
+    // exclusive end of 'c' with nothing after it
+    #[case(30, None)]
+    #[case(35, None)]
+    #[case(40, Some('d'))]
+    #[case(i32::MAX, Some('d'))]
+    fn test_get(#[case] value: i32, #[case] expected: Option<char>) {
+        let map = tariffs();
+        assert_eq!(map.get(&value), expected.as_ref());
+        assert_eq!(map.get_key_value(&value).map(|(_, v)| *v), expected);
+        if let Some((key, _)) = map.get_key_value(&value) {
+            assert!(key.contains(value));
+        }
+    }
+
+    #[rstest]
+    #[case(closed(5, 6))]
+    #[case(closed(30, 40))]
+    #[case(closed(20, 20))]
+    #[case(Interval::until_inclusive(0))]
+    #[case(Interval::since_exclusive(100))]
+    fn test_insert_rejects_overlaps(#[case] interval: Interval<i32>) {
+        let mut map = tariffs();
+        assert_eq!(map.insert(interval, 'x'), Err(OverlapError));
+        assert_eq!(map, tariffs());
+    }
+
+    #[rstest]
+    #[case(open(30, 40), 3)]
+    #[case(closed(30, 30), 3)]
+    #[case(Interval::until_exclusive(0), 0)]
+    fn test_insert_into_gap(#[case] interval: Interval<i32>, #[case] index: usize) {
+        let mut map = tariffs();
+        map.insert(interval, 'x').unwrap();
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.iter().nth(index), Some((&interval, &'x')));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut map = tariffs();
+        assert_eq!(map.remove(&closed(10, 19)), None);
+        assert_eq!(map.remove(&end_open(10, 20)), None);
+        assert_eq!(map.remove(&closed(10, 20)), Some('b'));
+        assert_eq!(map.get(&15), None);
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn test_iter_is_ordered() {
+        let keys: Vec<_> = tariffs().iter().map(|(_, v)| *v).collect();
+        assert_eq!(keys, vec!['a', 'b', 'c', 'd']);
+    }
+
+    #[rstest]
+    // splitting one entry in two
+    #[case(closed(12, 14), vec![(end_open(0, 10), 'a'), (end_open(10, 12), 'b'), (closed(12, 14), 'x'), (Interval::from_to(14, 20, IntervalType::StartOpen).unwrap(), 'b'), (open(20, 30), 'c'), (Interval::since_inclusive(40), 'd')])]
+    // trimming two entries and dropping a covered one
+    #[case(end_open(5, 25), vec![(end_open(0, 5), 'a'), (end_open(5, 25), 'x'), (end_open(25, 30), 'c'), (Interval::since_inclusive(40), 'd')])]
+    // exactly replacing an entry
+    #[case(closed(10, 20), vec![(end_open(0, 10), 'a'), (closed(10, 20), 'x'), (open(20, 30), 'c'), (Interval::since_inclusive(40), 'd')])]
+    // a flush neighbour is left untouched
+    #[case(closed(30, 35), vec![(end_open(0, 10), 'a'), (closed(10, 20), 'b'), (open(20, 30), 'c'), (closed(30, 35), 'x'), (Interval::since_inclusive(40), 'd')])]
+    // into an unbounded entry
+    #[case(closed(50, 60), vec![(end_open(0, 10), 'a'), (closed(10, 20), 'b'), (open(20, 30), 'c'), (end_open(40, 50), 'd'), (closed(50, 60), 'x'), (Interval::since_exclusive(60), 'd')])]
+    fn test_insert_replace(
+        #[case] interval: Interval<i32>,
+        #[case] expected: Vec<(Interval<i32>, char)>,
+    ) {
+        let mut map = tariffs();
+        map.insert_replace(interval, 'x');
+        assert_eq!(map.entries, expected);
+    }
+
+    #[rstest]
+    #[case(closed(-5, -1), vec![])]
+    #[case(closed(-5, 0), vec!['a'])]
+    #[case(closed(5, 15), vec!['a', 'b'])]
+    // the exclusive end of 'a' does not reach 10
+    #[case(closed(10, 15), vec!['b'])]
+    // the inclusive end of 'b' reaches 20, the exclusive start of 'c' does not
+    #[case(closed(20, 20), vec!['b'])]
+    #[case(Interval::from_to(20, 25, IntervalType::StartOpen).unwrap(), vec!['c'])]
+    #[case(closed(30, 39), vec![])]
+    #[case(end_open(25, 40), vec!['c'])]
+    #[case(closed(25, 40), vec!['c', 'd'])]
+    #[case(Interval::since_inclusive(0), vec!['a', 'b', 'c', 'd'])]
+    #[case(Interval::until_exclusive(0), vec![])]
+    fn test_overlapping(#[case] query: Interval<i32>, #[case] expected: Vec<char>) {
+        let map = tariffs();
+        let actual: Vec<_> = map.overlapping(&query).map(|(_, v)| *v).collect();
+        assert_eq!(actual, expected);
+        let scanned: Vec<_> = map
+            .iter()
+            .filter(|(key, _)| key.overlaps(&query))
+            .map(|(_, v)| *v)
+            .collect();
+        assert_eq!(actual, scanned);
+    }
+
+    #[rstest]
+    #[case(closed(1, 5), Some('a'))]
+    #[case(end_open(0, 10), Some('a'))]
+    #[case(closed(0, 10), None)]
+    #[case(closed(5, 15), None)]
+    #[case(closed(10, 20), Some('b'))]
+    #[case(Interval::from_to(20, 30, IntervalType::Open).unwrap(), Some('c'))]
+    #[case(Interval::from_to(20, 30, IntervalType::StartOpen).unwrap(), None)]
+    #[case(closed(31, 35), None)]
+    #[case(Interval::since_exclusive(50), Some('d'))]
+    #[case(Interval::until_exclusive(5), None)]
+    fn test_covering(#[case] query: Interval<i32>, #[case] expected: Option<char>) {
+        assert_eq!(tariffs().covering(&query).map(|(_, v)| *v), expected);
+    }
+}
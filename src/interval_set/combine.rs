@@ -0,0 +1,436 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use std::cmp::Ordering;
+use std::iter::{once, Peekable};
+
+/// The result of [`IntervalSet::diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetDiff<T>
+where
+    T: Ord,
+{
+    /// The values only in the left set, `self`.
+    pub only_left: IntervalSet<T>,
+
+    /// The values only in the right set, `other`.
+    pub only_right: IntervalSet<T>,
+
+    /// The values in both sets.
+    pub both: IntervalSet<T>,
+}
+
+impl<T: Ord + Clone> IntervalSet<T> {
+    /// Returns the values covered by `self`, `other` or both.
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.combine(other, |in_self, in_other| in_self || in_other)
+    }
+
+    /// Returns the values covered by both `self` and `other`.
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.combine(other, |in_self, in_other| in_self && in_other)
+    }
+
+    /// Returns the values covered by `self` but not by `other`.
+    ///
+    /// Use [`IntervalSet::remove`] to take a single interval out of a set
+    /// in place.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.combine(other, |in_self, in_other| in_self && !in_other)
+    }
+
+    /// Returns the values covered by exactly one of `self` and `other`.
+    ///
+    /// Runs as a single merge over both member lists, so a member that
+    /// merely moved an endpoint contributes only the sliver in between:
+    /// `{[1, 5]}` against `{[1, 5)}` yields `{[5, 5]}`.
+    pub fn symmetric_difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.combine(other, |in_self, in_other| in_self != in_other)
+    }
+
+    /// Returns every pair of overlapping members, one from each set, as
+    /// `(index in self, index in other, shared piece)` in ascending order.
+    ///
+    /// Runs as a single two-pointer sweep over both member lists, in
+    /// `O(n + m + k)` for `k` pairs. The pieces keep the exact bounds of
+    /// the members they come from.
+    pub fn intersection_pairs<'a>(
+        &'a self,
+        other: &'a IntervalSet<T>,
+    ) -> impl Iterator<Item = (usize, usize, Interval<T>)> + 'a {
+        let (mut i, mut j) = (0, 0);
+        std::iter::from_fn(move || {
+            while let (Some(a), Some(b)) = (self.members.get(i), other.members.get(j)) {
+                let pair = (i, j);
+                // the member ending first cannot overlap anything further
+                if a.end <= b.end {
+                    i += 1;
+                } else {
+                    j += 1;
+                }
+                if let Some(piece) = a.intersection(b) {
+                    return Some((pair.0, pair.1, piece));
+                }
+            }
+            None
+        })
+    }
+
+    /// Splits the values covered by `self` or `other` into those only in
+    /// `self`, those only in `other` and those in both.
+    ///
+    /// All three come out of a single merge over both member lists. They
+    /// are pairwise disjoint, and their union is the union of the inputs.
+    pub fn diff(&self, other: &IntervalSet<T>) -> SetDiff<T> {
+        let [only_left, only_right, both] = self.combine_each(
+            other,
+            [
+                &|in_self, in_other| in_self && !in_other,
+                &|in_self, in_other| !in_self && in_other,
+                &|in_self, in_other| in_self && in_other,
+            ],
+        );
+        SetDiff {
+            only_left,
+            only_right,
+            both,
+        }
+    }
+
+    /// Sweeps the cuts of both sets in order and keeps the stretches for
+    /// which `keep(in_self, in_other)` holds. `keep(false, false)` must be
+    /// `false`, as nothing is emitted before the first cut.
+    pub(super) fn combine(
+        &self,
+        other: &IntervalSet<T>,
+        keep: impl Fn(bool, bool) -> bool,
+    ) -> IntervalSet<T> {
+        let [combined] = self.combine_each(other, [&keep]);
+        combined
+    }
+
+    /// Like [`IntervalSet::combine`], but builds one set for each of
+    /// `keeps` in the same sweep.
+    fn combine_each<const N: usize>(
+        &self,
+        other: &IntervalSet<T>,
+        keeps: [&dyn Fn(bool, bool) -> bool; N],
+    ) -> [IntervalSet<T>; N] {
+        debug_assert!(keeps.iter().all(|keep| !keep(false, false)));
+        let mut self_cuts = self.cuts().peekable();
+        let mut other_cuts = other.cuts().peekable();
+        let (mut in_self, mut in_other) = (false, false);
+        let mut opens: [Option<BoundPoint<T>>; N] = std::array::from_fn(|_| None);
+        let mut sets: [IntervalSet<T>; N] = std::array::from_fn(|_| IntervalSet::new());
+        loop {
+            let order = match (self_cuts.peek(), other_cuts.peek()) {
+                (None, None) => break,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(a), Some(b)) => a.cmp(b),
+            };
+            let cut = match order {
+                Ordering::Less => toggle(&mut self_cuts, &mut in_self),
+                Ordering::Greater => toggle(&mut other_cuts, &mut in_other),
+                Ordering::Equal => {
+                    toggle(&mut other_cuts, &mut in_other);
+                    toggle(&mut self_cuts, &mut in_self)
+                }
+            };
+            let kept: [bool; N] = std::array::from_fn(|i| keeps[i](in_self, in_other));
+            // sets opening or closing here take the cut, the last one by move
+            let mut takers = (0..N).filter(|&i| opens[i].is_some() != kept[i]).count();
+            let mut cut = Some(cut);
+            for ((kept, open), set) in kept.into_iter().zip(&mut opens).zip(&mut sets) {
+                if open.is_some() == kept {
+                    continue;
+                }
+                takers -= 1;
+                let cut = if takers == 0 { cut.take() } else { cut.clone() }
+                    .expect("only the last taker moves the cut out");
+                match open.take() {
+                    None => *open = Some(cut),
+                    Some(start) => set.members.push(Interval {
+                        start,
+                        end: cut
+                            .adjacent_end_before()
+                            .expect("a closing cut follows an opening one"),
+                    }),
+                }
+            }
+        }
+        for (open, set) in opens.into_iter().zip(&mut sets) {
+            if let Some(start) = open {
+                set.members.push(Interval {
+                    start,
+                    end: BoundPoint::pos_infinity(),
+                });
+            }
+        }
+        sets
+    }
+
+    /// Yields, in ascending order, the start bounds where coverage by the
+    /// set switches on or off: each member's start, and the start right
+    /// after each member's end.
+    fn cuts(&self) -> impl Iterator<Item = BoundPoint<T>> + '_ {
+        self.members.iter().flat_map(|member| {
+            once(member.start.clone()).chain(member.end.clone().adjacent_start_after())
+        })
+    }
+}
+
+fn toggle<T, I: Iterator<Item = T>>(cuts: &mut Peekable<I>, inside: &mut bool) -> T {
+    *inside = !*inside;
+    cuts.next().expect("peeked before")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{contains_half_step, set, Rng};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![], vec![], vec![])]
+    #[case(vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()], vec![],
+           vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()])]
+    #[case(vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()], vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()], vec![])]
+    // an end moved from inclusive to exclusive leaves the single point
+    #[case(vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()], vec![Interval::from_to(1, 5, IntervalType::EndOpen).unwrap()],
+           vec![Interval::from_to(5, 5, IntervalType::Close).unwrap()])]
+    #[case(vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()], vec![Interval::from_to(1, 5, IntervalType::Open).unwrap()],
+           vec![Interval::from_to(1, 1, IntervalType::Close).unwrap(), Interval::from_to(5, 5, IntervalType::Close).unwrap()])]
+    // a shrunk end leaves the stretch it gave up
+    #[case(vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()], vec![Interval::from_to(1, 3, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()])]
+    #[case(vec![Interval::from_to(1, 5, IntervalType::EndOpen).unwrap()], vec![Interval::from_to(3, 8, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 8, IntervalType::Close).unwrap()])]
+    // flush neighbours from different sets form one member
+    #[case(vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap()], vec![Interval::from_to(3, 5, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()])]
+    #[case(vec![Interval::until_exclusive(3)], vec![Interval::since_inclusive(1)],
+           vec![Interval::until_exclusive(1), Interval::since_inclusive(3)])]
+    fn test_symmetric_difference(
+        #[case] left: Vec<Interval<i32>>,
+        #[case] right: Vec<Interval<i32>>,
+        #[case] expected: Vec<Interval<i32>>,
+    ) {
+        let (left, right) = (set(left), set(right));
+        assert_eq!(left.symmetric_difference(&right).members, expected);
+        assert_eq!(right.symmetric_difference(&left).members, expected);
+    }
+
+    #[test]
+    fn test_intersection_pairs() {
+        let bookings = set(vec![
+            Interval::from_to(9, 10, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(11, 13, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(14, 15, IntervalType::Close).unwrap(),
+        ]);
+        let requested = set(vec![
+            Interval::from_to(8, 9, IntervalType::Close).unwrap(),
+            Interval::from_to(10, 12, IntervalType::Open).unwrap(),
+            Interval::from_to(12, 14, IntervalType::StartOpen).unwrap(),
+        ]);
+        assert_eq!(
+            requested.intersection_pairs(&bookings).collect::<Vec<_>>(),
+            vec![
+                (0, 0, Interval::from_to(9, 9, IntervalType::Close).unwrap()),
+                (1, 1, Interval::from_to(11, 12, IntervalType::EndOpen).unwrap()),
+                (2, 1, Interval::from_to(12, 13, IntervalType::Open).unwrap()),
+                (2, 2, Interval::from_to(14, 14, IntervalType::Close).unwrap()),
+            ]
+        );
+        assert_eq!(requested.intersection_pairs(&IntervalSet::new()).count(), 0);
+    }
+
+    #[test]
+    fn test_intersection_pairs_matches_brute_force() {
+        let mut rng = Rng::new(386);
+        for _ in 0..500 {
+            let left = set((0..rng.range(0, 6))
+                .map(|_| rng.interval(-20, 20))
+                .collect());
+            let right = set((0..rng.range(0, 6))
+                .map(|_| rng.interval(-20, 20))
+                .collect());
+            let mut expected = Vec::new();
+            for (i, a) in left.members.iter().enumerate() {
+                for (j, b) in right.members.iter().enumerate() {
+                    if let Some(piece) = a.intersection(b) {
+                        expected.push((i, j, piece));
+                    }
+                }
+            }
+            let mut pairs: Vec<_> = left.intersection_pairs(&right).collect();
+            pairs.sort_by_key(|&(i, j, _)| (i, j));
+            assert_eq!(pairs, expected, "failed: {:?}, {:?}", left, right);
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let left = set(vec![
+            Interval::from_to(1, 5, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(8, 10, IntervalType::Close).unwrap(),
+        ]);
+        let right = set(vec![
+            Interval::from_to(3, 9, IntervalType::Close).unwrap(),
+            Interval::since_exclusive(10),
+        ]);
+        let diff = left.diff(&right);
+        assert_eq!(
+            diff.only_left.members,
+            vec![
+                Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(),
+                Interval::from_to(9, 10, IntervalType::StartOpen).unwrap(),
+            ]
+        );
+        assert_eq!(
+            diff.only_right.members,
+            vec![
+                Interval::from_to(5, 8, IntervalType::EndOpen).unwrap(),
+                Interval::since_exclusive(10),
+            ]
+        );
+        assert_eq!(
+            diff.both.members,
+            vec![
+                Interval::from_to(3, 5, IntervalType::EndOpen).unwrap(),
+                Interval::from_to(8, 9, IntervalType::Close).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_matches_brute_force() {
+        let mut rng = Rng::new(390);
+        for _ in 0..500 {
+            let left = set((0..rng.range(0, 5))
+                .map(|_| rng.interval(-20, 20))
+                .collect());
+            let right = set((0..rng.range(0, 5))
+                .map(|_| rng.interval(-20, 20))
+                .collect());
+            let diff = left.diff(&right);
+            for part in [&diff.only_left, &diff.only_right, &diff.both] {
+                assert_eq!(part.members, set(part.members.clone()).members);
+            }
+            let covers = |set: &IntervalSet<i32>, half_steps| {
+                set.members
+                    .iter()
+                    .any(|member| contains_half_step(member, half_steps))
+            };
+            for half_steps in -50..50 {
+                let (in_left, in_right) = (covers(&left, half_steps), covers(&right, half_steps));
+                let parts = [
+                    covers(&diff.only_left, half_steps),
+                    covers(&diff.only_right, half_steps),
+                    covers(&diff.both, half_steps),
+                ];
+                assert_eq!(
+                    parts,
+                    [in_left && !in_right, !in_left && in_right, in_left && in_right],
+                    "failed: {:?}, {:?}, {}",
+                    left,
+                    right,
+                    half_steps
+                );
+                assert!(parts.iter().filter(|&&part| part).count() <= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_union_and_intersection_match_brute_force() {
+        let mut rng = Rng::new(394);
+        for _ in 0..500 {
+            let left = set((0..rng.range(0, 5))
+                .map(|_| rng.interval(-20, 20))
+                .collect());
+            let right = set((0..rng.range(0, 5))
+                .map(|_| rng.interval(-20, 20))
+                .collect());
+            let union = left.union(&right);
+            let intersection = left.intersection(&right);
+            assert_eq!(union.members, set(union.members.clone()).members);
+            assert_eq!(
+                intersection.members,
+                set(intersection.members.clone()).members
+            );
+            let covers = |set: &IntervalSet<i32>, half_steps| {
+                set.members
+                    .iter()
+                    .any(|member| contains_half_step(member, half_steps))
+            };
+            for half_steps in -50..50 {
+                let (in_left, in_right) = (covers(&left, half_steps), covers(&right, half_steps));
+                assert_eq!(covers(&union, half_steps), in_left || in_right);
+                assert_eq!(covers(&intersection, half_steps), in_left && in_right);
+            }
+        }
+    }
+
+    #[test]
+    fn test_symmetric_difference_matches_brute_force() {
+        let mut rng = Rng::new(364);
+        for _ in 0..500 {
+            let left = set((0..rng.range(0, 5))
+                .map(|_| rng.interval(-20, 20))
+                .collect());
+            let right = set((0..rng.range(0, 5))
+                .map(|_| rng.interval(-20, 20))
+                .collect());
+            let result = left.symmetric_difference(&right);
+            assert_eq!(result.members, set(result.members.clone()).members);
+            let covers = |set: &IntervalSet<i32>, half_steps| {
+                set.members
+                    .iter()
+                    .any(|member| contains_half_step(member, half_steps))
+            };
+            for half_steps in -50..50 {
+                assert_eq!(
+                    covers(&result, half_steps),
+                    covers(&left, half_steps) != covers(&right, half_steps),
+                    "failed: {:?}, {:?}, {}",
+                    left,
+                    right,
+                    half_steps
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_difference_matches_brute_force() {
+        let mut rng = Rng::new(395);
+        for _ in 0..500 {
+            let left = set((0..rng.range(0, 5))
+                .map(|_| rng.interval(-20, 20))
+                .collect());
+            let right = set((0..rng.range(0, 5))
+                .map(|_| rng.interval(-20, 20))
+                .collect());
+            let difference = left.difference(&right);
+            let mut removed = left.clone();
+            for member in right.iter() {
+                removed.remove(member);
+            }
+            assert_eq!(removed, difference);
+            assert_eq!(difference.members, set(difference.members.clone()).members);
+            let covers = |set: &IntervalSet<i32>, half_steps| {
+                set.members
+                    .iter()
+                    .any(|member| contains_half_step(member, half_steps))
+            };
+            for half_steps in -50..50 {
+                assert_eq!(
+                    covers(&difference, half_steps),
+                    covers(&left, half_steps) && !covers(&right, half_steps)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+
+impl<T: Ord + Clone> IntervalSet<T> {
+    /// Returns every value of the whole axis that is not in the set.
+    ///
+    /// The pieces before the first member and after the last member
+    /// are unbounded. Each piece takes the flipped proximity of the
+    /// member bound it touches: a member ending `before(x)` is followed
+    /// by a piece starting `at(x)`, and a member ending `at(x)` by a
+    /// piece starting `after(x)`.
+    pub fn complement(&self) -> IntervalSet<T> {
+        IntervalSet {
+            members: self.complement_pieces().collect(),
+        }
+    }
+
+    /// Returns every value of `universe` that is not in the set.
+    ///
+    /// Complementing an empty set yields `universe` itself.
+    pub fn complement_within(&self, universe: &Interval<T>) -> IntervalSet<T> {
+        IntervalSet {
+            members: self.gaps_within(universe).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bound_point::BoundPoint;
+    use crate::interval::IntervalType;
+    use crate::test_support::set;
+    use rstest::rstest;
+
+    fn full() -> Interval<i32> {
+        Interval {
+            start: BoundPoint::neg_infinity(),
+            end: BoundPoint::pos_infinity(),
+        }
+    }
+
+    #[rstest]
+    #[case(vec![], vec![full()])]
+    #[case(vec![full()], vec![])]
+    #[case(vec![Interval::from_to(1, 3, IntervalType::Close).unwrap()],
+           vec![Interval::until_exclusive(1), Interval::since_exclusive(3)])]
+    #[case(vec![Interval::from_to(1, 3, IntervalType::Open).unwrap()],
+           vec![Interval::until_inclusive(1), Interval::since_inclusive(3)])]
+    #[case(vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 8, IntervalType::StartOpen).unwrap()],
+           vec![Interval::until_exclusive(1), Interval::from_to(3, 5, IntervalType::Close).unwrap(), Interval::since_exclusive(8)])]
+    #[case(vec![Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(3, 5, IntervalType::Open).unwrap()],
+           vec![Interval::until_exclusive(1), Interval::since_inclusive(5)])]
+    #[case(vec![Interval::until_exclusive(1), Interval::since_exclusive(3)],
+           vec![Interval::from_to(1, 3, IntervalType::Close).unwrap()])]
+    fn test_complement(#[case] members: Vec<Interval<i32>>, #[case] expected: Vec<Interval<i32>>) {
+        let set = set(members);
+        assert_eq!(set.complement().members, expected);
+        assert_eq!(set.complement().complement(), set);
+    }
+
+    #[rstest]
+    // free time within working hours
+    #[case(vec![Interval::from_to(9, 12, IntervalType::EndOpen).unwrap(), Interval::from_to(13, 17, IntervalType::EndOpen).unwrap()],
+           Interval::from_to(9, 18, IntervalType::Close).unwrap(),
+           vec![Interval::from_to(12, 13, IntervalType::EndOpen).unwrap(), Interval::from_to(17, 18, IntervalType::Close).unwrap()])]
+    // inclusive member ends yield exclusive free starts
+    #[case(vec![Interval::from_to(10, 12, IntervalType::Close).unwrap()],
+           Interval::from_to(9, 17, IntervalType::EndOpen).unwrap(),
+           vec![Interval::from_to(9, 10, IntervalType::EndOpen).unwrap(), Interval::from_to(12, 17, IntervalType::Open).unwrap()])]
+    // exclusive member bounds leave the boundary values free
+    #[case(vec![Interval::from_to(10, 12, IntervalType::Open).unwrap()],
+           Interval::from_to(9, 17, IntervalType::Close).unwrap(),
+           vec![Interval::from_to(9, 10, IntervalType::Close).unwrap(), Interval::from_to(12, 17, IntervalType::Close).unwrap()])]
+    // members extending past the universe are clipped
+    #[case(vec![Interval::from_to(5, 10, IntervalType::Close).unwrap(), Interval::from_to(16, 20, IntervalType::Close).unwrap()],
+           Interval::from_to(9, 17, IntervalType::Close).unwrap(),
+           vec![Interval::from_to(10, 16, IntervalType::Open).unwrap()])]
+    // a member flush with the universe end leaves nothing behind
+    #[case(vec![Interval::from_to(12, 17, IntervalType::Close).unwrap()],
+           Interval::from_to(9, 17, IntervalType::Close).unwrap(),
+           vec![Interval::from_to(9, 12, IntervalType::EndOpen).unwrap()])]
+    // empty set yields the universe itself
+    #[case(vec![],
+           Interval::from_to(9, 17, IntervalType::StartOpen).unwrap(),
+           vec![Interval::from_to(9, 17, IntervalType::StartOpen).unwrap()])]
+    // fully covered universe
+    #[case(vec![Interval::from_to(0, 20, IntervalType::Close).unwrap()],
+           Interval::from_to(9, 17, IntervalType::Close).unwrap(),
+           vec![])]
+    fn test_complement_within(
+        #[case] members: Vec<Interval<i32>>,
+        #[case] universe: Interval<i32>,
+        #[case] expected: Vec<Interval<i32>>,
+    ) {
+        assert_eq!(set(members).complement_within(&universe).members, expected);
+    }
+}
@@ -0,0 +1,290 @@
+use crate::bound_point::BoundPoint;
+use crate::discrete::Discrete;
+use crate::interval::Interval;
+use crate::interval_set::{IntervalSet, SetPosition};
+use std::fmt::{self, Display, Formatter};
+
+/// Error returned by [`IntervalSet::to_pairs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalSetExportError {
+    /// The member at this zero-based position is unbounded.
+    Unbounded(usize),
+
+    /// The member at this zero-based position holds the top value of the
+    /// type, so its half-open end would overflow.
+    EndOverflow(usize),
+}
+
+impl Display for IntervalSetExportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            IntervalSetExportError::Unbounded(position) => {
+                write!(f, "member {} is unbounded", position)
+            }
+            IntervalSetExportError::EndOverflow(position) => {
+                write!(f, "member {} ends at the top of the type", position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IntervalSetExportError {}
+
+impl<T: Discrete + Clone> IntervalSet<T> {
+    /// Adds the single value `value`, coalescing with any member holding
+    /// the value just below or just above it, so that inserting `4` into
+    /// `{[1, 3]}` gives `{[1, 4]}` rather than `{[1, 3], [4, 4]}`.
+    pub fn insert_point(&mut self, value: T) {
+        let start = value
+            .pred()
+            .and_then(|previous| self.find(&previous))
+            .map_or_else(
+                || BoundPoint::at(value.clone()),
+                |member| member.start.clone(),
+            );
+        let end = value.succ().and_then(|next| self.find(&next)).map_or_else(
+            || BoundPoint::at(value.clone()),
+            |member| member.end.clone(),
+        );
+        self.insert(Interval { start, end });
+    }
+
+    /// Removes the single value `value`, splitting the member holding it
+    /// in two when `value` lies in its interior. The remnants end at
+    /// `value - 1` and start at `value + 1`, both inclusive.
+    pub fn remove_point(&mut self, value: &T) {
+        let SetPosition::Inside(index) = self.position_of(value) else {
+            return;
+        };
+        let member = self.members[index].clone();
+        let left = value
+            .pred()
+            .map(|previous| Interval {
+                start: member.start,
+                end: BoundPoint::at(previous),
+            })
+            .filter(|piece| !piece.is_empty());
+        let right = value
+            .succ()
+            .map(|next| Interval {
+                start: BoundPoint::at(next),
+                end: member.end,
+            })
+            .filter(|piece| !piece.is_empty());
+        self.members
+            .splice(index..=index, left.into_iter().chain(right));
+    }
+
+    /// Returns the smallest value at or above `from` that the set does not
+    /// contain, or `None` when every such value is covered.
+    ///
+    /// Members that are adjacent only in the discrete sense, such as
+    /// `[1, 3]` and `[4, 5]`, are stepped over in turn.
+    pub fn first_absent_at_or_after(&self, from: &T) -> Option<T> {
+        let mut candidate = from.clone();
+        while let Some(member) = self.find(&candidate) {
+            candidate = member.last_value()?.succ()?;
+        }
+        Some(candidate)
+    }
+
+    /// Returns the members as sorted half-open `(start, end)` pairs, each
+    /// member canonicalized to `[first, last + 1)` first.
+    ///
+    /// Members holding no value, such as `(1, 2)`, are skipped, and
+    /// members adjacent only in the discrete sense, such as `[1, 3]` and
+    /// `[4, 5]`, come out as the single pair `(1, 6)`. The pairs are
+    /// therefore sorted, non-empty and never touch, and
+    /// [`from_pairs`](Self::from_pairs) turns them back into a set
+    /// holding the same values.
+    pub fn to_pairs(&self) -> Result<Vec<(T, T)>, IntervalSetExportError> {
+        let mut pairs: Vec<(T, T)> = Vec::with_capacity(self.members.len());
+        for (position, member) in self.members.iter().enumerate() {
+            if member.start.finite_value().is_none() || member.end.finite_value().is_none() {
+                return Err(IntervalSetExportError::Unbounded(position));
+            }
+            let (Some(first), Some(last)) = (member.first_value(), member.last_value()) else {
+                continue;
+            };
+            let end = last
+                .succ()
+                .ok_or(IntervalSetExportError::EndOverflow(position))?;
+            match pairs.last_mut() {
+                Some(previous) if previous.1 == first => previous.1 = end,
+                _ => pairs.push((first, end)),
+            }
+        }
+        Ok(pairs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::Rng;
+    use rstest::rstest;
+
+    fn set(members: &[(i32, i32)]) -> IntervalSet<i32> {
+        members
+            .iter()
+            .map(|&(start, end)| Interval::from_to(start, end, IntervalType::Close).unwrap())
+            .collect()
+    }
+
+    #[rstest]
+    #[case(&[(1, 3)], 4, &[(1, 4)])]
+    #[case(&[(1, 3)], 0, &[(0, 3)])]
+    #[case(&[(1, 3)], 2, &[(1, 3)])]
+    #[case(&[(1, 3), (5, 7)], 4, &[(1, 7)])]
+    #[case(&[(1, 3)], 6, &[(1, 3), (6, 6)])]
+    #[case(&[], 6, &[(6, 6)])]
+    fn test_insert_point(
+        #[case] members: &[(i32, i32)],
+        #[case] value: i32,
+        #[case] expected: &[(i32, i32)],
+    ) {
+        let mut members = set(members);
+        members.insert_point(value);
+        assert_eq!(members, set(expected));
+    }
+
+    #[test]
+    fn test_insert_point_extends_half_open_member() {
+        let mut members: IntervalSet<i32> =
+            [Interval::from_to(1, 3, IntervalType::EndOpen).unwrap()]
+                .into_iter()
+                .collect();
+        members.insert_point(3);
+        assert_eq!(members, set(&[(1, 3)]));
+    }
+
+    #[rstest]
+    #[case(&[(1, 5)], 3, &[(1, 2), (4, 5)])]
+    #[case(&[(1, 5)], 1, &[(2, 5)])]
+    #[case(&[(1, 5)], 5, &[(1, 4)])]
+    #[case(&[(3, 3)], 3, &[])]
+    #[case(&[(1, 5)], 7, &[(1, 5)])]
+    fn test_remove_point(
+        #[case] members: &[(i32, i32)],
+        #[case] value: i32,
+        #[case] expected: &[(i32, i32)],
+    ) {
+        let mut members = set(members);
+        members.remove_point(&value);
+        assert_eq!(members, set(expected));
+    }
+
+    #[test]
+    fn test_remove_point_at_type_bounds() {
+        let mut members: IntervalSet<i8> =
+            [Interval::from_to(i8::MIN, i8::MAX, IntervalType::Close).unwrap()]
+                .into_iter()
+                .collect();
+        members.remove_point(&i8::MIN);
+        members.remove_point(&i8::MAX);
+        assert_eq!(
+            members.members,
+            &[Interval::from_to(i8::MIN + 1, i8::MAX - 1, IntervalType::Close).unwrap()]
+        );
+    }
+
+    #[rstest]
+    #[case(&[], 5, Some(5))]
+    #[case(&[(1, 3)], 0, Some(0))]
+    #[case(&[(1, 3)], 1, Some(4))]
+    #[case(&[(1, 3), (4, 6), (8, 9)], 2, Some(7))]
+    #[case(&[(1, 3)], 4, Some(4))]
+    fn test_first_absent_at_or_after(
+        #[case] members: &[(i32, i32)],
+        #[case] from: i32,
+        #[case] expected: Option<i32>,
+    ) {
+        assert_eq!(set(members).first_absent_at_or_after(&from), expected);
+    }
+
+    #[test]
+    fn test_first_absent_at_or_after_unbounded() {
+        let members: IntervalSet<i8> = [
+            Interval::since_inclusive(0),
+            Interval::from_to(-5, -2, IntervalType::Close).unwrap(),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(members.first_absent_at_or_after(&3), None);
+        assert_eq!(members.first_absent_at_or_after(&-3), Some(-1));
+    }
+
+    #[rstest]
+    #[case(&[], Ok(vec![]))]
+    #[case(&[(1, 3), (5, 5)], Ok(vec![(1, 4), (5, 6)]))]
+    #[case(&[(1, 3), (4, 5)], Ok(vec![(1, 6)]))]
+    fn test_to_pairs(
+        #[case] members: &[(i32, i32)],
+        #[case] expected: Result<Vec<(i32, i32)>, IntervalSetExportError>,
+    ) {
+        assert_eq!(set(members).to_pairs(), expected);
+    }
+
+    #[test]
+    fn test_to_pairs_canonicalizes_members() {
+        let members = IntervalSet::from(vec![
+            Interval::from_to(1, 2, IntervalType::Open).unwrap(),
+            Interval::from_to(3, 6, IntervalType::StartOpen).unwrap(),
+            Interval::from_to(10, 12, IntervalType::EndOpen).unwrap(),
+        ]);
+        assert_eq!(members.to_pairs(), Ok(vec![(4, 7), (10, 12)]));
+    }
+
+    #[test]
+    fn test_to_pairs_errors() {
+        let mut members = set(&[(1, 3)]);
+        members.insert(Interval::since_exclusive(10));
+        assert_eq!(
+            members.to_pairs(),
+            Err(IntervalSetExportError::Unbounded(1))
+        );
+        let top: IntervalSet<i8> = IntervalSet::from(vec![
+            Interval::from_to(0, 1, IntervalType::Close).unwrap(),
+            Interval::from_to(100, i8::MAX, IntervalType::Close).unwrap(),
+        ]);
+        assert_eq!(top.to_pairs(), Err(IntervalSetExportError::EndOverflow(1)));
+    }
+
+    #[test]
+    fn test_pairs_round_trip() {
+        let mut rng = Rng::new(413);
+        for _ in 0..500 {
+            let members: IntervalSet<i32> = (0..rng.range(0, 6))
+                .map(|_| {
+                    let start = rng.range(-20, 20);
+                    let end = rng.range(start, 21);
+                    Interval::from_to(start, end, rng.interval_type()).unwrap()
+                })
+                .collect();
+            let pairs = members.to_pairs().unwrap();
+            let restored = IntervalSet::from_pairs(pairs.clone()).unwrap();
+            for value in -25..25 {
+                assert_eq!(members.contains(&value), restored.contains(&value));
+            }
+            assert_eq!(restored.to_pairs(), Ok(pairs));
+        }
+    }
+
+    #[test]
+    fn test_id_allocator_round_trip() {
+        let mut used = IntervalSet::new();
+        let mut ids = Vec::new();
+        for _ in 0..5 {
+            let id = used.first_absent_at_or_after(&0).unwrap();
+            used.insert_point(id);
+            ids.push(id);
+        }
+        assert_eq!(ids, [0, 1, 2, 3, 4]);
+        assert_eq!(used, set(&[(0, 4)]));
+        used.remove_point(&2);
+        assert_eq!(used.first_absent_at_or_after(&0), Some(2));
+        used.insert_point(2);
+        assert_eq!(used, set(&[(0, 4)]));
+    }
+}
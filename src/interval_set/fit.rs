@@ -0,0 +1,148 @@
+use crate::bound_point::BoundPoint;
+use crate::integer::Integer;
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use std::fmt::{self, Display, Formatter};
+
+/// Error returned by [`IntervalSet::first_fit`] when the duration is zero
+/// or negative, which no slot `[s, s + duration)` could hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDurationError;
+
+impl Display for InvalidDurationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "slot duration must be positive")
+    }
+}
+
+impl std::error::Error for InvalidDurationError {}
+
+impl<T: Integer> IntervalSet<T> {
+    /// Returns the earliest slot `[s, s + duration)` lying inside a
+    /// member with `s >= not_before`, or `None` if no member is long
+    /// enough.
+    ///
+    /// The slot starts at the later of `not_before` and the member start.
+    /// As the slot includes `s`, a member with an exclusive start `(a, …`
+    /// can only host a slot starting strictly after `a`: it is used when
+    /// `not_before > a` and skipped otherwise, since a continuous type
+    /// has no earliest value after `a`. Canonicalize the members of a
+    /// discrete set first to have `(a, …` read as `[a + 1, …`.
+    ///
+    /// A slot whose end would overflow `T` does not fit. Fails with
+    /// [`InvalidDurationError`] when `duration` is not positive.
+    pub fn first_fit(
+        &self,
+        duration: T,
+        not_before: &T,
+    ) -> Result<Option<Interval<T>>, InvalidDurationError> {
+        if duration <= T::ZERO {
+            return Err(InvalidDurationError);
+        }
+        let first = self
+            .members
+            .partition_point(|member| member.end.cmp_value(not_before).is_lt());
+        Ok(self.members[first..].iter().find_map(|member| {
+            let start = match member.start.finite_value() {
+                Some(value) if value > not_before => *value,
+                _ => *not_before,
+            };
+            if member.start.cmp_value(&start).is_gt() {
+                return None;
+            }
+            let end = BoundPoint::before(start.checked_add(duration)?);
+            (end <= member.end).then(|| Interval {
+                start: BoundPoint::at(start),
+                end,
+            })
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::end_open;
+    use rstest::rstest;
+
+    fn free() -> IntervalSet<i32> {
+        [
+            end_open(9, 10),
+            Interval::from_to(11, 14, IntervalType::Open).unwrap(),
+            Interval::from_to(15, 17, IntervalType::Close).unwrap(),
+            Interval::since_inclusive(20),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[rstest]
+    #[case(1, 0, Some(end_open(9, 10)))]
+    #[case(1, 9, Some(end_open(9, 10)))]
+    // the exclusive start of (11, 14) cannot start a slot
+    #[case(2, 9, Some(end_open(15, 17)))]
+    #[case(1, 11, Some(end_open(15, 16)))]
+    // the slot may end at the exclusive member end
+    #[case(2, 12, Some(end_open(12, 14)))]
+    #[case(3, 11, Some(end_open(20, 23)))]
+    #[case(2, 15, Some(end_open(15, 17)))]
+    #[case(2, 16, Some(end_open(20, 22)))]
+    #[case(100, 0, Some(end_open(20, 120)))]
+    #[case(100, 50, Some(end_open(50, 150)))]
+    fn test_first_fit(
+        #[case] duration: i32,
+        #[case] not_before: i32,
+        #[case] expected: Option<Interval<i32>>,
+    ) {
+        assert_eq!(free().first_fit(duration, &not_before), Ok(expected));
+    }
+
+    #[rstest]
+    // a member starting exactly at `not_before`, with each proximity
+    #[case(IntervalType::Close, Some(end_open(5, 7)))]
+    #[case(IntervalType::EndOpen, Some(end_open(5, 7)))]
+    #[case(IntervalType::StartOpen, None)]
+    #[case(IntervalType::Open, None)]
+    fn test_first_fit_member_starting_at_not_before(
+        #[case] interval_type: IntervalType,
+        #[case] expected: Option<Interval<i32>>,
+    ) {
+        let set = IntervalSet::from(vec![Interval::from_to(5, 8, interval_type).unwrap()]);
+        assert_eq!(set.first_fit(2, &5), Ok(expected));
+    }
+
+    #[test]
+    fn test_first_fit_without_room() {
+        assert_eq!(IntervalSet::new().first_fit(1, &0), Ok(None));
+        let set = IntervalSet::from(vec![end_open(0, 10)]);
+        assert_eq!(set.first_fit(11, &0), Ok(None));
+        assert_eq!(set.first_fit(1, &10), Ok(None));
+        assert_eq!(set.first_fit(10, &0), Ok(Some(end_open(0, 10))));
+    }
+
+    #[test]
+    fn test_first_fit_unbounded_below() {
+        let set = IntervalSet::from(vec![Interval::until_exclusive(10)]);
+        assert_eq!(set.first_fit(3, &-100), Ok(Some(end_open(-100, -97))));
+        assert_eq!(set.first_fit(3, &7), Ok(Some(end_open(7, 10))));
+        assert_eq!(set.first_fit(3, &8), Ok(None));
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(-1)]
+    fn test_first_fit_rejects_non_positive_duration(#[case] duration: i32) {
+        assert_eq!(free().first_fit(duration, &0), Err(InvalidDurationError));
+    }
+
+    #[test]
+    fn test_first_fit_end_overflow() {
+        let set = IntervalSet::from(vec![Interval::since_inclusive(i32::MAX - 5)]);
+        assert_eq!(
+            set.first_fit(5, &0),
+            Ok(Some(end_open(i32::MAX - 5, i32::MAX)))
+        );
+        assert_eq!(set.first_fit(6, &0), Ok(None));
+    }
+}
@@ -0,0 +1,176 @@
+use crate::format::ParseIntervalError;
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Error returned when parsing an [`IntervalSet`] from text fails.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseIntervalSetError {
+    /// The segment at this zero-based position holds no interval,
+    /// e.g. the middle of `"[1, 2] ∪ ∪ [3, 4]"`.
+    EmptySegment(usize),
+
+    /// The segment at this zero-based position is not a valid interval.
+    InvalidInterval(usize, ParseIntervalError),
+}
+
+impl Display for ParseIntervalSetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseIntervalSetError::EmptySegment(position) => {
+                write!(f, "segment {} is empty", position)
+            }
+            ParseIntervalSetError::InvalidInterval(position, error) => {
+                write!(f, "segment {}: {}", position, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseIntervalSetError {}
+
+/// Displays an [`IntervalSet`] with a custom separator between members.
+/// Returned by [`IntervalSet::display_with`].
+pub struct DisplayWith<'a, T: Ord> {
+    set: &'a IntervalSet<T>,
+    separator: &'a str,
+}
+
+impl<T: Ord + Display> Display for DisplayWith<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.set.is_empty() {
+            return write!(f, "∅");
+        }
+        for (index, member) in self.set.iter().enumerate() {
+            if index > 0 {
+                write!(f, "{}", self.separator)?;
+            }
+            write!(f, "{}", member)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Ord> IntervalSet<T> {
+    /// Formats the members joined by `separator`, such as `" u "` or
+    /// `"; "` for ASCII-only output. An empty set displays as `∅`.
+    pub fn display_with<'a>(&'a self, separator: &'a str) -> DisplayWith<'a, T> {
+        DisplayWith {
+            set: self,
+            separator,
+        }
+    }
+}
+
+/// Formats the members joined by `" ∪ "`, such as
+/// `[1, 3) ∪ [5, 8] ∪ (10, +inf)`. An empty set displays as `∅`.
+impl<T: Ord + Display> Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.display_with(" ∪ ").fmt(f)
+    }
+}
+
+/// Parses members separated by `∪`, `;` or ` u `, in any order, and
+/// normalizes them. The empty set is written as `∅`, `{}` or an empty
+/// (or blank) string.
+impl<T: Ord + FromStr> FromStr for IntervalSet<T> {
+    type Err = ParseIntervalSetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() || s == "∅" || s == "{}" {
+            return Ok(IntervalSet::new());
+        }
+        s.split(['∪', ';'])
+            .flat_map(|segment| segment.split(" u "))
+            .enumerate()
+            .map(|(position, segment)| {
+                if segment.trim().is_empty() {
+                    return Err(ParseIntervalSetError::EmptySegment(position));
+                }
+                segment
+                    .parse::<Interval<T>>()
+                    .map_err(|error| ParseIntervalSetError::InvalidInterval(position, error))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use rstest::rstest;
+
+    fn set() -> IntervalSet<i32> {
+        IntervalSet::from(vec![
+            Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(5, 8, IntervalType::Close).unwrap(),
+            Interval::since_exclusive(10),
+        ])
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(set().to_string(), "[1, 3) ∪ [5, 8] ∪ (10, +inf)");
+        assert_eq!(
+            set().display_with(" u ").to_string(),
+            "[1, 3) u [5, 8] u (10, +inf)"
+        );
+        assert_eq!(
+            set().display_with("; ").to_string(),
+            "[1, 3); [5, 8]; (10, +inf)"
+        );
+        assert_eq!(IntervalSet::<i32>::new().to_string(), "∅");
+    }
+
+    #[rstest]
+    #[case("[1, 3) ∪ [5, 8] ∪ (10, +inf)")]
+    #[case("[1,3)∪[5,8]∪(10,+inf)")]
+    #[case("[1, 3) u [5, 8] u (10, +inf)")]
+    #[case("[1,3);[5,8] ; (10,inf)")]
+    #[case("(10, +inf) ∪ [5, 8] ∪ [1, 3)")]
+    #[case("[1, 2] ∪ [1, 3) ∪ [5, 6) ∪ [6, 8] ∪ (10, +inf)")]
+    fn test_parse(#[case] text: &str) {
+        assert_eq!(text.parse::<IntervalSet<i32>>(), Ok(set()));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("   ")]
+    #[case("∅")]
+    #[case("{}")]
+    fn test_parse_empty_set(#[case] text: &str) {
+        assert_eq!(text.parse::<IntervalSet<i32>>(), Ok(IntervalSet::new()));
+    }
+
+    #[rstest]
+    #[case("[1, 2] ∪ ∪ [3, 4]", ParseIntervalSetError::EmptySegment(1))]
+    #[case("[1, 2];", ParseIntervalSetError::EmptySegment(1))]
+    #[case("; [1, 2]", ParseIntervalSetError::EmptySegment(0))]
+    #[case(
+        "[1, 2] ∪ [4, 3]",
+        ParseIntervalSetError::InvalidInterval(
+            1,
+            ParseIntervalError::Invalid(crate::interval::IntervalError::StartMustBeMinorThanEnd)
+        )
+    )]
+    #[case(
+        "[1, 2] ∪ 3",
+        ParseIntervalSetError::InvalidInterval(1, ParseIntervalError::MissingOpeningBracket)
+    )]
+    fn test_parse_errors(#[case] text: &str, #[case] expected: ParseIntervalSetError) {
+        assert_eq!(text.parse::<IntervalSet<i32>>(), Err(expected));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for separator in [" ∪ ", " u ", "; "] {
+            let text = set().display_with(separator).to_string();
+            assert_eq!(text.parse::<IntervalSet<i32>>(), Ok(set()));
+        }
+        let text = IntervalSet::<i32>::new().to_string();
+        assert_eq!(text.parse::<IntervalSet<i32>>(), Ok(IntervalSet::new()));
+    }
+}
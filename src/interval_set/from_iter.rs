@@ -0,0 +1,159 @@
+use crate::interval::{Interval, IntervalError, IntervalType};
+use crate::interval_set::IntervalSet;
+
+impl<T: Ord> IntervalSet<T> {
+    /// Builds a set from half-open `(start, end)` pairs, the inverse of
+    /// [`to_pairs`](Self::to_pairs).
+    ///
+    /// The pairs may come in any order and may overlap; they are
+    /// normalized like any other intervals, and pairs with
+    /// `start == end` hold no value and are dropped. Fails on the first
+    /// pair whose start exceeds its end.
+    pub fn from_pairs(pairs: Vec<(T, T)>) -> Result<IntervalSet<T>, IntervalError> {
+        let intervals = pairs
+            .into_iter()
+            .map(|(start, end)| Interval::from_to(start, end, IntervalType::EndOpen))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(IntervalSet::from_unsorted(intervals))
+    }
+}
+
+impl<T: Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        IntervalSet::from_unsorted(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord> From<Vec<Interval<T>>> for IntervalSet<T> {
+    fn from(intervals: Vec<Interval<T>>) -> Self {
+        IntervalSet::from_unsorted(intervals)
+    }
+}
+
+impl<T: Ord> Extend<Interval<T>> for IntervalSet<T> {
+    /// Adds all intervals at once with a single sort-and-sweep over the
+    /// existing members and the new intervals, rather than one
+    /// insertion per interval.
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        let mut intervals = std::mem::take(&mut self.members);
+        intervals.extend(iter);
+        *self = IntervalSet::from_unsorted(intervals);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Rng;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec![Interval::from_to(5, 8, IntervalType::Close).unwrap(), Interval::from_to(1, 3, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(5, 8, IntervalType::Close).unwrap()])]
+    #[case(vec![Interval::from_to(3, 8, IntervalType::Close).unwrap(), Interval::from_to(1, 5, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(1, 8, IntervalType::Close).unwrap()])]
+    #[case(vec![Interval::from_to(3, 5, IntervalType::Close).unwrap(), Interval::from_to(1, 3, IntervalType::EndOpen).unwrap()],
+           vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()])]
+    #[case(vec![Interval::from_to(3, 5, IntervalType::StartOpen).unwrap(), Interval::from_to(1, 3, IntervalType::EndOpen).unwrap()],
+           vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()])]
+    #[case(vec![Interval::from_to(1, 10, IntervalType::Close).unwrap(), Interval::from_to(2, 3, IntervalType::Close).unwrap(), Interval::from_to(4, 12, IntervalType::Open).unwrap()],
+           vec![Interval::from_to(1, 12, IntervalType::EndOpen).unwrap()])]
+    #[case(vec![Interval::from_to(1, 1, IntervalType::Open).unwrap(), Interval::from_to(2, 2, IntervalType::EndOpen).unwrap()],
+           vec![])]
+    fn test_from_vec(#[case] intervals: Vec<Interval<i32>>, #[case] expected: Vec<Interval<i32>>) {
+        assert_eq!(IntervalSet::from(intervals.clone()).members, expected);
+        assert_eq!(
+            intervals.into_iter().collect::<IntervalSet<_>>().members,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut set = IntervalSet::from(vec![
+            Interval::from_to(1, 3, IntervalType::Close).unwrap(),
+            Interval::from_to(10, 12, IntervalType::Close).unwrap(),
+        ]);
+        set.extend(vec![
+            Interval::from_to(3, 5, IntervalType::StartOpen).unwrap(),
+            Interval::since_exclusive(12),
+        ]);
+        assert_eq!(
+            set.members,
+            vec![
+                Interval::from_to(1, 5, IntervalType::Close).unwrap(),
+                Interval::since_inclusive(10),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(vec![], Ok(vec![]))]
+    #[case(vec![(5, 8), (1, 3), (3, 4)], Ok(vec![Interval::from_to(1, 4, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 8, IntervalType::EndOpen).unwrap()]))]
+    #[case(vec![(2, 2), (1, 6), (4, 9)], Ok(vec![Interval::from_to(1, 9, IntervalType::EndOpen).unwrap()]))]
+    #[case(vec![(1, 3), (4, 2)], Err(IntervalError::StartMustBeMinorThanEnd))]
+    fn test_from_pairs(
+        #[case] pairs: Vec<(i32, i32)>,
+        #[case] expected: Result<Vec<Interval<i32>>, IntervalError>,
+    ) {
+        assert_eq!(
+            IntervalSet::from_pairs(pairs).map(|set| set.members),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_collect_matches_insertion_on_large_input() {
+        let mut rng = Rng::new(347);
+        // short intervals over a wide axis, so the result keeps many members
+        let intervals: Vec<_> = (0..100_000)
+            .map(|_| {
+                let start = rng.range(0, 1_000_000);
+                let end = start + rng.range(0, 20);
+                Interval::from_to(start, end, rng.interval_type()).unwrap()
+            })
+            .collect();
+
+        let collected: IntervalSet<i32> = intervals.iter().cloned().collect();
+        let mut inserted = IntervalSet::new();
+        for interval in intervals.iter().cloned() {
+            inserted.insert(interval);
+        }
+        assert_eq!(collected, inserted);
+
+        for pair in collected.members.windows(2) {
+            assert!(pair[0].end < pair[1].start && !pair[0].end.meets(&pair[1].start));
+        }
+        for value in (0..1_000_000).step_by(4_999) {
+            let expected = intervals.iter().any(|interval| interval.contains(value));
+            assert_eq!(collected.contains(&value), expected, "failed: {}", value);
+        }
+    }
+
+    #[test]
+    fn test_collect_matches_insertion_on_random_shapes() {
+        let mut rng = Rng::new(3_470);
+        for _ in 0..500 {
+            let intervals: Vec<_> = (0..rng.range(0, 12))
+                .map(|_| rng.interval(-20, 20))
+                .collect();
+            let collected: IntervalSet<i32> = intervals.iter().cloned().collect();
+            let mut inserted = IntervalSet::new();
+            for interval in intervals.iter().cloned() {
+                inserted.insert(interval);
+            }
+            assert_eq!(collected, inserted, "failed: {:?}", intervals);
+            for value in -25..25 {
+                let expected = intervals.iter().any(|interval| interval.contains(value));
+                assert_eq!(
+                    collected.contains(&value),
+                    expected,
+                    "failed: {:?}, {}",
+                    intervals,
+                    value
+                );
+            }
+        }
+    }
+}
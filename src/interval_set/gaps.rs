@@ -0,0 +1,115 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+
+impl<T: Ord + Clone> IntervalSet<T> {
+    /// Returns the maximal intervals lying between consecutive members,
+    /// in ascending order.
+    ///
+    /// The unbounded regions before the first member and after the last
+    /// one are not gaps; see [`IntervalSet::gaps_within`] for those.
+    /// Each gap takes the flipped proximities of its neighbours' bounds,
+    /// so the gap between `[1, 3)` and `(5, 8]` is `[3, 5]`.
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.members.windows(2).filter_map(|pair| {
+            Some(Interval {
+                start: pair[0].end.clone().adjacent_start_after()?,
+                end: pair[1].start.clone().adjacent_end_before()?,
+            })
+        })
+    }
+
+    /// Returns the maximal intervals of `universe` not covered by any
+    /// member, in ascending order, including the edge gaps before the
+    /// first member and after the last one clipped to `universe`.
+    pub fn gaps_within<'a>(
+        &'a self,
+        universe: &'a Interval<T>,
+    ) -> impl Iterator<Item = Interval<T>> + 'a {
+        self.complement_pieces()
+            .filter_map(move |piece| piece.intersection(universe))
+    }
+
+    /// Lazily yields the pieces of the whole axis not covered by any member.
+    pub(crate) fn complement_pieces(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        let leading = match self.members.first() {
+            None => Some(Interval {
+                start: BoundPoint::neg_infinity(),
+                end: BoundPoint::pos_infinity(),
+            }),
+            Some(first) => first
+                .start
+                .clone()
+                .adjacent_end_before()
+                .map(|end| Interval {
+                    start: BoundPoint::neg_infinity(),
+                    end,
+                }),
+        };
+        let trailing = self.members.last().and_then(|last| {
+            last.end
+                .clone()
+                .adjacent_start_after()
+                .map(|start| Interval {
+                    start,
+                    end: BoundPoint::pos_infinity(),
+                })
+        });
+        leading.into_iter().chain(self.gaps()).chain(trailing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::set;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec![Interval::from_to(1, 3, IntervalType::Close).unwrap()], vec![])]
+    #[case(vec![Interval::until_exclusive(1)], vec![])]
+    #[case(vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 8, IntervalType::StartOpen).unwrap()],
+           vec![Interval::from_to(3, 5, IntervalType::Close).unwrap()])]
+    #[case(vec![Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(5, 8, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(3, 5, IntervalType::Open).unwrap()])]
+    #[case(vec![Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(5, 8, IntervalType::Open).unwrap()],
+           vec![Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()])]
+    // a single missing point
+    #[case(vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()],
+           vec![Interval::from_to(3, 3, IntervalType::Close).unwrap()])]
+    #[case(vec![Interval::until_inclusive(0), Interval::from_to(2, 4, IntervalType::Close).unwrap(), Interval::since_exclusive(6)],
+           vec![Interval::from_to(0, 2, IntervalType::Open).unwrap(), Interval::from_to(4, 6, IntervalType::StartOpen).unwrap()])]
+    fn test_gaps(#[case] members: Vec<Interval<i32>>, #[case] expected: Vec<Interval<i32>>) {
+        assert_eq!(set(members).gaps().collect::<Vec<_>>(), expected);
+    }
+
+    #[rstest]
+    #[case(vec![], Interval::from_to(0, 10, IntervalType::EndOpen).unwrap(),
+           vec![Interval::from_to(0, 10, IntervalType::EndOpen).unwrap()])]
+    #[case(vec![Interval::from_to(2, 4, IntervalType::Close).unwrap()], Interval::from_to(0, 10, IntervalType::EndOpen).unwrap(),
+           vec![Interval::from_to(0, 2, IntervalType::EndOpen).unwrap(), Interval::from_to(4, 10, IntervalType::Open).unwrap()])]
+    // members straddling the universe edges
+    #[case(vec![Interval::from_to(-5, 2, IntervalType::EndOpen).unwrap(), Interval::from_to(4, 6, IntervalType::Close).unwrap(), Interval::from_to(8, 20, IntervalType::Close).unwrap()],
+           Interval::from_to(0, 10, IntervalType::Close).unwrap(),
+           vec![Interval::from_to(2, 4, IntervalType::EndOpen).unwrap(), Interval::from_to(6, 8, IntervalType::Open).unwrap()])]
+    // members outside the universe
+    #[case(vec![Interval::from_to(-5, -1, IntervalType::Close).unwrap(), Interval::from_to(20, 30, IntervalType::Close).unwrap()],
+           Interval::from_to(0, 10, IntervalType::Close).unwrap(),
+           vec![Interval::from_to(0, 10, IntervalType::Close).unwrap()])]
+    // exclusive universe edge meeting a member
+    #[case(vec![Interval::from_to(-5, 0, IntervalType::Close).unwrap()],
+           Interval::from_to(0, 10, IntervalType::Open).unwrap(),
+           vec![Interval::from_to(0, 10, IntervalType::Open).unwrap()])]
+    fn test_gaps_within(
+        #[case] members: Vec<Interval<i32>>,
+        #[case] universe: Interval<i32>,
+        #[case] expected: Vec<Interval<i32>>,
+    ) {
+        assert_eq!(
+            set(members).gaps_within(&universe).collect::<Vec<_>>(),
+            expected
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+
+impl<T: Ord> IntervalSet<T> {
+    /// Returns the members in ascending order.
+    ///
+    /// Members are sorted by start bound and pairwise disjoint, so the
+    /// end bounds come out in ascending order as well.
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval<T>> {
+        self.members.iter()
+    }
+
+    /// Returns the number of members, not the number of values covered.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns the number of members, the same as [`len`](Self::len).
+    ///
+    /// The name spells out that it counts stored intervals, which is what
+    /// the memory used by the set grows with.
+    pub fn member_count(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Releases the spare capacity left behind by removals and merges.
+    pub fn shrink_to_fit(&mut self) {
+        self.members.shrink_to_fit();
+    }
+
+    /// Returns `true` if the set covers no value at all.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Returns `true` if the set covers the whole axis.
+    ///
+    /// Normalization merges members that together cover everything, such
+    /// as `(-inf, 0)` and `[0, +inf)`, so only the single member
+    /// `(-inf, +inf)` needs checking.
+    pub fn is_full(&self) -> bool {
+        matches!(
+            self.members.as_slice(),
+            [only] if only.start == BoundPoint::neg_infinity() && only.end == BoundPoint::pos_infinity()
+        )
+    }
+
+    /// Returns the lowest member.
+    pub fn first(&self) -> Option<&Interval<T>> {
+        self.members.first()
+    }
+
+    /// Returns the highest member.
+    pub fn last(&self) -> Option<&Interval<T>> {
+        self.members.last()
+    }
+}
+
+impl<T: Ord + Clone> IntervalSet<T> {
+    /// Returns the smallest interval covering every member, from the start
+    /// of the first member to the end of the last one, or `None` for an
+    /// empty set.
+    pub fn span(&self) -> Option<Interval<T>> {
+        Some(Interval {
+            start: self.first()?.start.clone(),
+            end: self.last()?.end.clone(),
+        })
+    }
+}
+
+impl<T: Ord> IntoIterator for IntervalSet<T> {
+    type Item = Interval<T>;
+    type IntoIter = std::vec::IntoIter<Interval<T>>;
+
+    /// Yields the members in ascending order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.into_iter()
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = std::slice::Iter<'a, Interval<T>>;
+
+    /// Yields the members in ascending order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+
+    fn set() -> IntervalSet<i32> {
+        IntervalSet::from(vec![
+            Interval::from_to(10, 12, IntervalType::Open).unwrap(),
+            Interval::until_exclusive(0),
+            Interval::from_to(3, 5, IntervalType::Close).unwrap(),
+        ])
+    }
+
+    #[test]
+    fn test_iter_is_ascending() {
+        let expected = vec![
+            Interval::until_exclusive(0),
+            Interval::from_to(3, 5, IntervalType::Close).unwrap(),
+            Interval::from_to(10, 12, IntervalType::Open).unwrap(),
+        ];
+        let set = set();
+        assert_eq!(set.iter().cloned().collect::<Vec<_>>(), expected);
+        assert_eq!((&set).into_iter().cloned().collect::<Vec<_>>(), expected);
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_len_and_is_empty() {
+        assert_eq!(set().len(), 3);
+        assert!(!set().is_empty());
+        assert_eq!(IntervalSet::<i32>::new().len(), 0);
+        assert!(IntervalSet::<i32>::new().is_empty());
+        assert!(
+            IntervalSet::from(vec![Interval::from_to(1, 1, IntervalType::Open).unwrap()])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_first_and_last() {
+        assert_eq!(set().first(), Some(&Interval::until_exclusive(0)));
+        assert_eq!(
+            set().last(),
+            Some(&Interval::from_to(10, 12, IntervalType::Open).unwrap())
+        );
+        assert_eq!(IntervalSet::<i32>::new().first(), None);
+        assert_eq!(IntervalSet::<i32>::new().last(), None);
+    }
+
+    #[test]
+    fn test_span() {
+        assert_eq!(set().span(), Some(Interval::until_exclusive(12)));
+        assert_eq!(
+            IntervalSet::from(vec![
+                Interval::from_to(1, 2, IntervalType::StartOpen).unwrap(),
+                Interval::from_to(5, 8, IntervalType::EndOpen).unwrap(),
+            ])
+            .span(),
+            Some(Interval::from_to(1, 8, IntervalType::Open).unwrap())
+        );
+        assert_eq!(IntervalSet::<i32>::new().span(), None);
+    }
+}
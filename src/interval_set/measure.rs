@@ -0,0 +1,156 @@
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use std::ops::{Add, Sub};
+
+impl<T> IntervalSet<T>
+where
+    T: Ord + Clone + Add<Output = T> + Sub<Output = T>,
+{
+    /// Returns the total length covered by the set, or `None` when a
+    /// member is unbounded.
+    ///
+    /// Members are disjoint, so no length is counted twice.
+    ///
+    /// `T: Default` supplies the zero an empty set measures, as there is
+    /// no value to derive it from; this is right for the integer, float
+    /// and duration types the crate works with, whose default is zero.
+    pub fn measure(&self) -> Option<T>
+    where
+        T: Default,
+    {
+        self.members
+            .iter()
+            .try_fold(T::default(), |total, member| Some(total + member.length()?))
+    }
+
+    /// Returns the total length of the set within `window`, clipping every
+    /// member to the window first. Returns `None` only when a clipped
+    /// member is still unbounded, which requires an unbounded `window`.
+    ///
+    /// As for [`measure`](Self::measure), `T::default()` is the zero
+    /// returned when no member reaches into the window.
+    pub fn measure_within(&self, window: &Interval<T>) -> Option<T>
+    where
+        T: Default,
+    {
+        self.members
+            .iter()
+            .filter_map(|member| member.intersection(window))
+            .try_fold(T::default(), |total, piece| Some(total + piece.length()?))
+    }
+
+    /// Merges every pair of consecutive members separated by a gap
+    /// shorter than `tolerance`, filling the gap in.
+    ///
+    /// The set only grows, trading precision for fewer members. Merging
+    /// is transitive: members joined by a chain of short gaps all become
+    /// one. The gap length ignores proximity, as [`Interval::length`]
+    /// does, so `[1, 3)` and `(3, 5]` are always merged for a positive
+    /// `tolerance`.
+    pub fn simplify_within(&mut self, tolerance: T) {
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(self.members.len());
+        for member in self.members.drain(..) {
+            if let Some(last) = merged.last_mut() {
+                let gap = match (last.end.finite_value(), member.start.finite_value()) {
+                    (Some(end), Some(start)) => Some(start.clone() - end.clone()),
+                    _ => None,
+                };
+                if gap.is_some_and(|gap| gap < tolerance) {
+                    last.end = member.end;
+                    continue;
+                }
+            }
+            merged.push(member);
+        }
+        self.members = merged;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use rstest::rstest;
+
+    fn booked() -> IntervalSet<i32> {
+        IntervalSet::from(vec![
+            Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(5, 8, IntervalType::Close).unwrap(),
+            Interval::from_to(10, 20, IntervalType::Open).unwrap(),
+        ])
+    }
+
+    #[test]
+    fn test_measure() {
+        assert_eq!(booked().measure(), Some(15));
+        assert_eq!(IntervalSet::<i32>::new().measure(), Some(0));
+    }
+
+    #[test]
+    fn test_measure_unbounded() {
+        let mut set = booked();
+        set.insert(Interval::since_inclusive(30));
+        assert_eq!(set.measure(), None);
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(0, 30, IntervalType::Close).unwrap(), Some(15))]
+    #[case(Interval::from_to(2, 12, IntervalType::EndOpen).unwrap(), Some(1 + 3 + 2))]
+    #[case(Interval::from_to(3, 5, IntervalType::Close).unwrap(), Some(0))]
+    #[case(Interval::from_to(21, 30, IntervalType::Close).unwrap(), Some(0))]
+    #[case(Interval::until_exclusive(6), Some(2 + 1))]
+    #[case(Interval::since_inclusive(15), Some(5))]
+    fn test_measure_within(#[case] window: Interval<i32>, #[case] expected: Option<i32>) {
+        assert_eq!(booked().measure_within(&window), expected);
+    }
+
+    #[test]
+    fn test_measure_within_unbounded_member() {
+        let mut set = booked();
+        set.insert(Interval::since_inclusive(30));
+        assert_eq!(
+            set.measure_within(&Interval::from_to(0, 40, IntervalType::Close).unwrap()),
+            Some(25)
+        );
+        assert_eq!(set.measure_within(&Interval::since_inclusive(0)), None);
+    }
+
+    #[rstest]
+    #[case(0, 3)]
+    #[case(2, 3)]
+    #[case(3, 1)]
+    fn test_simplify_within(#[case] tolerance: i32, #[case] expected_count: usize) {
+        let before = booked();
+        let mut after = before.clone();
+        after.simplify_within(tolerance);
+        assert_eq!(after.member_count(), expected_count);
+        for value in -5..=25 {
+            assert!(
+                !before.contains(&value) || after.contains(&value),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_simplify_within_merges_transitively() {
+        let mut set = IntervalSet::from(vec![
+            Interval::from_to(0, 10, IntervalType::Close).unwrap(),
+            Interval::from_to(11, 20, IntervalType::Close).unwrap(),
+            Interval::from_to(22, 30, IntervalType::EndOpen).unwrap(),
+            Interval::since_exclusive(40),
+        ]);
+        set.simplify_within(3);
+        assert_eq!(
+            set,
+            IntervalSet::from(vec![
+                Interval::from_to(0, 30, IntervalType::EndOpen).unwrap(),
+                Interval::since_exclusive(40),
+            ])
+        );
+        set.simplify_within(11);
+        assert_eq!(set, IntervalSet::from(vec![Interval::since_inclusive(0)]));
+        set.shrink_to_fit();
+        assert_eq!(set.member_count(), 1);
+    }
+}
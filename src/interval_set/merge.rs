@@ -0,0 +1,178 @@
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// The next unmerged member of one of the sets, ordered so that the
+/// max-heap pops the lowest start first.
+struct Head<T: Ord> {
+    member: Interval<T>,
+    source: usize,
+}
+
+impl<T: Ord> PartialEq for Head<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.member.start == other.member.start
+    }
+}
+
+impl<T: Ord> Eq for Head<T> {}
+
+impl<T: Ord> PartialOrd for Head<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for Head<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.member.start.cmp(&self.member.start)
+    }
+}
+
+impl<T: Ord> IntervalSet<T> {
+    /// Returns the values covered by at least one of `sets`.
+    ///
+    /// Runs as a k-way merge of the member lists, popping members in
+    /// start order from a heap holding one per set and coalescing each
+    /// into the last output member it overlaps or meets. This takes
+    /// `O(n log k)` for `n` members over `k` sets and allocates the
+    /// output once, instead of the intermediate sets of folding
+    /// [`IntervalSet::union`].
+    pub fn union_all<I: IntoIterator<Item = IntervalSet<T>>>(sets: I) -> IntervalSet<T> {
+        let mut sources: Vec<_> = sets
+            .into_iter()
+            .map(|set| set.members.into_iter())
+            .collect();
+        let mut members: Vec<Interval<T>> =
+            Vec::with_capacity(sources.iter().map(|s| s.len()).sum());
+        let mut heads: BinaryHeap<Head<T>> = sources
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(source, members)| {
+                Some(Head {
+                    member: members.next()?,
+                    source,
+                })
+            })
+            .collect();
+
+        while let Some(Head { member, source }) = heads.pop() {
+            if let Some(next) = sources[source].next() {
+                heads.push(Head {
+                    member: next,
+                    source,
+                });
+            }
+            match members.last_mut() {
+                Some(last) if last.end >= member.start || last.end.meets(&member.start) => {
+                    if member.end > last.end {
+                        last.end = member.end;
+                    }
+                }
+                _ => members.push(member),
+            }
+        }
+        IntervalSet { members }
+    }
+}
+
+impl<T: Ord + Clone> IntervalSet<T> {
+    /// Returns the values covered by every one of `sets`, or the whole
+    /// axis when there are none.
+    ///
+    /// Intersects the sets one after another, which never grows the
+    /// running result, and stops as soon as it is empty without looking
+    /// at the remaining sets.
+    pub fn intersection_all<I: IntoIterator<Item = IntervalSet<T>>>(sets: I) -> IntervalSet<T> {
+        let mut sets = sets.into_iter();
+        let Some(mut running) = sets.next() else {
+            return IntervalSet::full();
+        };
+        for set in sets {
+            if running.is_empty() {
+                break;
+            }
+            running = running.intersection(&set);
+        }
+        running
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{contains_half_step, Rng};
+
+    fn random_sets(rng: &mut Rng, count: usize) -> Vec<IntervalSet<i32>> {
+        (0..count)
+            .map(|_| {
+                (0..rng.range(0, 6))
+                    .map(|_| rng.interval(-100, 100))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_union_all_and_intersection_all_match_brute_force() {
+        let mut rng = Rng::new(429);
+        for round in 0..100 {
+            let count = if round == 0 {
+                50
+            } else {
+                rng.range(0, 50) as usize
+            };
+            let sets = random_sets(&mut rng, count);
+            let union = IntervalSet::union_all(sets.clone());
+            let intersection = IntervalSet::intersection_all(sets.clone());
+            for result in [&union, &intersection] {
+                assert_eq!(
+                    result.members,
+                    IntervalSet::from_unsorted(result.members.clone()).members
+                );
+            }
+            for half_steps in -210..210 {
+                let covered: Vec<bool> = sets
+                    .iter()
+                    .map(|set| {
+                        set.iter()
+                            .any(|member| contains_half_step(member, half_steps))
+                    })
+                    .collect();
+                let covers = |set: &IntervalSet<i32>| {
+                    set.iter()
+                        .any(|member| contains_half_step(member, half_steps))
+                };
+                assert_eq!(covers(&union), covered.iter().any(|&c| c));
+                assert_eq!(covers(&intersection), covered.iter().all(|&c| c));
+            }
+        }
+    }
+
+    #[test]
+    fn test_union_all_allocates_once() {
+        let mut rng = Rng::new(429);
+        let sets = random_sets(&mut rng, 50);
+        let total = sets.iter().map(IntervalSet::member_count).sum::<usize>();
+        let union = IntervalSet::union_all(sets);
+        // the output keeps the one buffer sized for every input member,
+        // where a fold would have grown it from the running union
+        assert_eq!(union.members.capacity(), total);
+    }
+
+    #[test]
+    fn test_intersection_all_stops_at_empty() {
+        let sets = vec![
+            IntervalSet::from(vec![Interval::from_to(0, 5, IntervalType::Close).unwrap()]),
+            IntervalSet::new(),
+        ];
+        // an unbounded iterator would never end without the early exit
+        let intersection =
+            IntervalSet::intersection_all(sets.into_iter().chain(std::iter::repeat_with(|| {
+                IntervalSet::from(vec![Interval::since_inclusive(0)])
+            })));
+        assert!(intersection.is_empty());
+    }
+}
@@ -0,0 +1,428 @@
+use crate::algorithms::merge_intervals;
+use crate::bound_point::BoundPoint;
+use crate::interval::Interval;
+
+mod combine;
+mod complement;
+mod discrete;
+mod fit;
+mod format;
+mod from_iter;
+mod gaps;
+mod iter;
+mod measure;
+mod merge;
+mod search;
+mod shared;
+mod subset;
+
+pub use combine::SetDiff;
+pub use discrete::IntervalSetExportError;
+pub use fit::InvalidDurationError;
+pub use format::{DisplayWith, ParseIntervalSetError};
+pub use search::SetPosition;
+pub use shared::SharedIntervalSet;
+
+/// A set of values on an ordered axis, stored as a sorted list
+/// of disjoint intervals.
+///
+/// The members are kept normalized at all times: no member is empty,
+/// members are sorted by their start bound, and no two members
+/// overlap or meet at a flush seam such as `[1, 3)` and `[3, 5]`.
+/// Such neighbours are coalesced into a single member on insertion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalSet<T>
+where
+    T: Ord,
+{
+    members: Vec<Interval<T>>,
+}
+
+impl<T: Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet {
+            members: Vec::new(),
+        }
+    }
+
+    /// Returns the set covering no value, the same as [`new`](Self::new).
+    ///
+    /// It is the identity of [`union`](Self::union) and the complement of
+    /// [`full`](Self::full).
+    pub fn empty() -> Self {
+        Self::new()
+    }
+
+    /// Returns the set covering the whole axis, whose single member is
+    /// `(-inf, +inf)`.
+    ///
+    /// It is the identity of [`intersection`](Self::intersection) and the
+    /// complement of [`empty`](Self::empty).
+    pub fn full() -> Self {
+        IntervalSet {
+            members: vec![Interval {
+                start: BoundPoint::neg_infinity(),
+                end: BoundPoint::pos_infinity(),
+            }],
+        }
+    }
+
+    /// Adds every value of `interval` to the set, merging it with
+    /// the members it overlaps or meets.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let lo = self.members.partition_point(|member| {
+            member.end < interval.start && !member.end.meets(&interval.start)
+        });
+        let hi = self.members.partition_point(|member| {
+            member.start <= interval.end || interval.end.meets(&member.start)
+        });
+
+        let mut merged = interval;
+        for member in self.members.drain(lo..hi) {
+            if member.start < merged.start {
+                merged.start = member.start;
+            }
+            if member.end > merged.end {
+                merged.end = member.end;
+            }
+        }
+        self.members.insert(lo, merged);
+    }
+
+    /// Removes every value of `interval` from the set, trimming the
+    /// members it overlaps and splitting one it falls strictly inside.
+    pub fn remove(&mut self, interval: &Interval<T>)
+    where
+        T: Clone,
+    {
+        if interval.is_empty() {
+            return;
+        }
+        let lo = self
+            .members
+            .partition_point(|member| member.end < interval.start);
+        let hi = self
+            .members
+            .partition_point(|member| member.start <= interval.end);
+        if lo >= hi {
+            return;
+        }
+        let left = interval
+            .start
+            .clone()
+            .adjacent_end_before()
+            .map(|end| Interval {
+                start: self.members[lo].start.clone(),
+                end,
+            });
+        let right = interval
+            .end
+            .clone()
+            .adjacent_start_after()
+            .map(|start| Interval {
+                start,
+                end: self.members[hi - 1].end.clone(),
+            });
+        let kept: Vec<_> = left
+            .into_iter()
+            .chain(right)
+            .filter(|piece| !piece.is_empty())
+            .collect();
+        self.members.splice(lo..hi, kept);
+    }
+
+    /// Splits the set at `at`, like `BTreeMap::split_off`: the set keeps
+    /// the values below `at` and the values from `at` upwards are
+    /// returned.
+    ///
+    /// A member straddling `at` is cut into a piece ending `before(at)`
+    /// and one starting `at(at)`; only that cut clones `at`. Both halves
+    /// stay normalized, and their union is the original set.
+    pub fn split_off(&mut self, at: &T) -> IntervalSet<T>
+    where
+        T: Clone,
+    {
+        let index = self
+            .members
+            .partition_point(|member| member.start.cmp_value(at).is_lt());
+        let mut upper = self.members.split_off(index);
+        if let Some(last) = self.members.last_mut() {
+            if last.end.cmp_value(at).is_ge() {
+                let end = std::mem::replace(&mut last.end, BoundPoint::before(at.clone()));
+                upper.insert(
+                    0,
+                    Interval {
+                        start: BoundPoint::at(at.clone()),
+                        end,
+                    },
+                );
+            }
+        }
+        IntervalSet { members: upper }
+    }
+
+    /// Keeps only the members for which `f` returns `true`.
+    ///
+    /// Dropping whole members cannot break the order or make members
+    /// touch, so the set needs no re-normalization.
+    pub fn retain<F: FnMut(&Interval<T>) -> bool>(&mut self, f: F) {
+        self.members.retain(f);
+    }
+
+    /// Replaces every member with the result of `f`, dropping those for
+    /// which it returns `None`.
+    ///
+    /// `f` may move, grow or shrink members arbitrarily, so the results
+    /// are re-sorted and overlapping or flush neighbours merged again,
+    /// as when collecting a set from intervals.
+    pub fn filter_map_members<F>(self, f: F) -> IntervalSet<T>
+    where
+        F: FnMut(Interval<T>) -> Option<Interval<T>>,
+    {
+        Self::from_unsorted(self.members.into_iter().filter_map(f).collect())
+    }
+
+    /// Builds a set from intervals in any order by sorting them by start
+    /// bound and coalescing overlapping or flush neighbours in one sweep.
+    pub(crate) fn from_unsorted(intervals: Vec<Interval<T>>) -> Self {
+        IntervalSet {
+            members: merge_intervals(intervals),
+        }
+    }
+}
+
+impl<T: Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{contains_half_step, set, Rng};
+    use rstest::rstest;
+
+    #[test]
+    fn test_empty_and_full_identities() {
+        let mut rng = Rng::new(430);
+        for _ in 0..200 {
+            let any = set((0..rng.range(0, 5))
+                .map(|_| rng.interval(-20, 20))
+                .collect());
+            assert_eq!(any.union(&IntervalSet::empty()), any);
+            assert_eq!(any.intersection(&IntervalSet::full()), any);
+            assert_eq!(
+                any.intersection(&IntervalSet::empty()),
+                IntervalSet::empty()
+            );
+            assert_eq!(any.union(&IntervalSet::full()), IntervalSet::full());
+            assert_eq!(any.union(&any.complement()), IntervalSet::full());
+            assert!(any.intersection(&any.complement()).is_empty());
+        }
+        assert_eq!(
+            IntervalSet::<i32>::empty().complement(),
+            IntervalSet::full()
+        );
+        assert_eq!(
+            IntervalSet::<i32>::full().complement(),
+            IntervalSet::empty()
+        );
+        assert!(IntervalSet::<i32>::empty().is_empty());
+        assert!(!IntervalSet::<i32>::empty().is_full());
+        assert!(IntervalSet::<i32>::full().is_full());
+        assert!(!IntervalSet::<i32>::full().is_empty());
+    }
+
+    #[rstest]
+    #[case(vec![Interval::until_exclusive(0), Interval::since_inclusive(0)], true)]
+    #[case(vec![Interval::until_inclusive(0), Interval::since_exclusive(0)], true)]
+    #[case(vec![Interval::until_inclusive(5), Interval::since_inclusive(-5)], true)]
+    // a single missing value
+    #[case(vec![Interval::until_exclusive(0), Interval::since_exclusive(0)], false)]
+    #[case(vec![Interval::since_inclusive(i32::MIN)], false)]
+    fn test_covering_members_normalize_to_full(
+        #[case] members: Vec<Interval<i32>>,
+        #[case] full: bool,
+    ) {
+        let from_insert = set(members.clone());
+        let from_vec = IntervalSet::from(members);
+        assert_eq!(from_insert, from_vec);
+        assert_eq!(from_insert.is_full(), full);
+        assert_eq!(from_insert == IntervalSet::full(), full);
+        if full {
+            assert_eq!(from_insert.len(), 1);
+        }
+    }
+
+    #[rstest]
+    // disjoint members stay apart, in order
+    #[case(vec![Interval::from_to(5, 8, IntervalType::Close).unwrap(), Interval::from_to(1, 3, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(5, 8, IntervalType::Close).unwrap()])]
+    // overlapping members merge
+    #[case(vec![Interval::from_to(1, 5, IntervalType::Close).unwrap(), Interval::from_to(3, 8, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(1, 8, IntervalType::Close).unwrap()])]
+    // flush seams merge
+    #[case(vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(3, 5, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()])]
+    #[case(vec![Interval::from_to(3, 5, IntervalType::StartOpen).unwrap(), Interval::from_to(1, 3, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()])]
+    // a missing point keeps members apart
+    #[case(vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()],
+           vec![Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap()])]
+    // one insertion bridging several members
+    #[case(vec![Interval::from_to(1, 2, IntervalType::Close).unwrap(), Interval::from_to(4, 5, IntervalType::Close).unwrap(), Interval::from_to(7, 8, IntervalType::Close).unwrap(), Interval::from_to(2, 7, IntervalType::Open).unwrap()],
+           vec![Interval::from_to(1, 8, IntervalType::Close).unwrap()])]
+    // empty intervals are ignored
+    #[case(vec![Interval::from_to(1, 1, IntervalType::Open).unwrap()], vec![])]
+    // unbounded members
+    #[case(vec![Interval::since_inclusive(5), Interval::until_exclusive(5)],
+           vec![Interval { start: crate::bound_point::BoundPoint::neg_infinity(), end: crate::bound_point::BoundPoint::pos_infinity() }])]
+    fn test_insert(#[case] inserted: Vec<Interval<i32>>, #[case] expected: Vec<Interval<i32>>) {
+        assert_eq!(set(inserted).members, expected);
+    }
+
+    #[rstest]
+    // trims both neighbours and drops the members in between
+    #[case(Interval::from_to(2, 7, IntervalType::Open).unwrap(),
+           vec![Interval::from_to(1, 2, IntervalType::Close).unwrap(), Interval::from_to(7, 8, IntervalType::Close).unwrap()])]
+    // splits the member it falls inside
+    #[case(Interval::from_to(4, 5, IntervalType::Close).unwrap(),
+           vec![Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(7, 8, IntervalType::Close).unwrap()])]
+    #[case(Interval::from_to(7, 7, IntervalType::Close).unwrap(),
+           vec![Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(4, 5, IntervalType::Close).unwrap(), Interval::from_to(7, 8, IntervalType::StartOpen).unwrap()])]
+    // gaps and empty intervals remove nothing
+    #[case(Interval::from_to(3, 4, IntervalType::Open).unwrap(),
+           vec![Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(4, 5, IntervalType::Close).unwrap(), Interval::from_to(7, 8, IntervalType::Close).unwrap()])]
+    #[case(Interval::from_to(2, 2, IntervalType::Open).unwrap(),
+           vec![Interval::from_to(1, 3, IntervalType::Close).unwrap(), Interval::from_to(4, 5, IntervalType::Close).unwrap(), Interval::from_to(7, 8, IntervalType::Close).unwrap()])]
+    #[case(Interval::since_exclusive(3), vec![Interval::from_to(1, 3, IntervalType::Close).unwrap()])]
+    fn test_remove(#[case] removed: Interval<i32>, #[case] expected: Vec<Interval<i32>>) {
+        let mut set = set(vec![
+            Interval::from_to(1, 3, IntervalType::Close).unwrap(),
+            Interval::from_to(4, 5, IntervalType::Close).unwrap(),
+            Interval::from_to(7, 8, IntervalType::Close).unwrap(),
+        ]);
+        set.remove(&removed);
+        assert_eq!(set.members, expected);
+    }
+
+    #[rstest]
+    // a member straddling the split point is cut in two
+    #[case(4, vec![Interval::from_to(1, 4, IntervalType::EndOpen).unwrap()],
+           vec![Interval::from_to(4, 5, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 8, IntervalType::StartOpen).unwrap(), Interval::since_inclusive(10)])]
+    // a member starting at the split point moves over whole
+    #[case(1, vec![],
+           vec![Interval::from_to(1, 5, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 8, IntervalType::StartOpen).unwrap(), Interval::since_inclusive(10)])]
+    // the exclusive end and start around 5 leave no piece behind
+    #[case(5, vec![Interval::from_to(1, 5, IntervalType::EndOpen).unwrap()],
+           vec![Interval::from_to(5, 8, IntervalType::StartOpen).unwrap(), Interval::since_inclusive(10)])]
+    #[case(8, vec![Interval::from_to(1, 5, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 8, IntervalType::Open).unwrap()],
+           vec![Interval::from_to(8, 8, IntervalType::Close).unwrap(), Interval::since_inclusive(10)])]
+    #[case(9, vec![Interval::from_to(1, 5, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 8, IntervalType::StartOpen).unwrap()],
+           vec![Interval::since_inclusive(10)])]
+    #[case(100, vec![Interval::from_to(1, 5, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 8, IntervalType::StartOpen).unwrap(), Interval::from_to(10, 100, IntervalType::EndOpen).unwrap()],
+           vec![Interval::since_inclusive(100)])]
+    fn test_split_off(#[case] at: i32, #[case] lower: Vec<Interval<i32>>, #[case] upper: Vec<Interval<i32>>) {
+        let mut set = set(vec![
+            Interval::from_to(1, 5, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(5, 8, IntervalType::StartOpen).unwrap(),
+            Interval::since_inclusive(10),
+        ]);
+        let split = set.split_off(&at);
+        assert_eq!(set.members, lower);
+        assert_eq!(split.members, upper);
+    }
+
+    #[test]
+    fn test_split_off_matches_brute_force() {
+        let mut rng = Rng::new(404);
+        for _ in 0..500 {
+            let original = set((0..rng.range(0, 5))
+                .map(|_| rng.interval(-20, 20))
+                .collect());
+            let at = rng.range(-22, 22);
+            let mut lower = original.clone();
+            let upper = lower.split_off(&at);
+            assert_eq!(lower.members, set(lower.members.clone()).members);
+            assert_eq!(upper.members, set(upper.members.clone()).members);
+            assert_eq!(lower.union(&upper), original);
+            for half_steps in -50..50 {
+                let in_original = original
+                    .iter()
+                    .any(|member| contains_half_step(member, half_steps));
+                let in_lower = lower
+                    .iter()
+                    .any(|member| contains_half_step(member, half_steps));
+                let in_upper = upper
+                    .iter()
+                    .any(|member| contains_half_step(member, half_steps));
+                assert_eq!(in_lower, in_original && half_steps < at * 2);
+                assert_eq!(in_upper, in_original && half_steps >= at * 2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut set = set(vec![
+            Interval::from_to(1, 2, IntervalType::Close).unwrap(),
+            Interval::from_to(4, 9, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(12, 13, IntervalType::Open).unwrap(),
+            Interval::since_inclusive(20),
+        ]);
+        set.retain(|member| member.length().is_none_or(|length| length >= 2));
+        assert_eq!(
+            set.members,
+            vec![
+                Interval::from_to(4, 9, IntervalType::EndOpen).unwrap(),
+                Interval::since_inclusive(20),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_map_members() {
+        let members = set(vec![
+            Interval::from_to(1, 3, IntervalType::Close).unwrap(),
+            Interval::from_to(5, 8, IntervalType::Close).unwrap(),
+            Interval::from_to(10, 12, IntervalType::Close).unwrap(),
+        ]);
+        // clipping to a window drops and trims members
+        let window = Interval::from_to(2, 6, IntervalType::EndOpen).unwrap();
+        assert_eq!(
+            members
+                .clone()
+                .filter_map_members(|member| member.intersection(&window))
+                .members,
+            vec![
+                Interval::from_to(2, 3, IntervalType::Close).unwrap(),
+                Interval::from_to(5, 6, IntervalType::EndOpen).unwrap(),
+            ]
+        );
+        // scaling members down shrinks them until `[0, 1]` meets `(1, 3]`
+        let scaled = set(vec![
+            Interval::from_to(0, 2, IntervalType::Close).unwrap(),
+            Interval::from_to(3, 6, IntervalType::StartOpen).unwrap(),
+        ])
+        .filter_map_members(|member| Some(member.map_key(|value| value / 2)));
+        assert_eq!(
+            scaled.members,
+            vec![Interval::from_to(0, 3, IntervalType::Close).unwrap()]
+        );
+        // growing members makes them overlap or meet, so they merge
+        let grown = members.filter_map_members(|member| {
+            let start = *member.infimum().unwrap();
+            let end = *member.supremum().unwrap();
+            Interval::from_to(start, end + 2, IntervalType::EndOpen).ok()
+        });
+        assert_eq!(
+            grown.members,
+            vec![Interval::from_to(1, 14, IntervalType::EndOpen).unwrap()]
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// Where a value falls relative to the members of an [`IntervalSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetPosition {
+    /// The value lies within the member at this index.
+    Inside(usize),
+
+    /// The value lies within no member. `previous` is the index of the
+    /// closest member below the value and `next` the index of the closest
+    /// member above it, each `None` when there is no such member.
+    Between {
+        previous: Option<usize>,
+        next: Option<usize>,
+    },
+}
+
+impl<T: Ord> IntervalSet<T> {
+    /// Returns `true` if `value` lies within one of the members.
+    ///
+    /// Runs in `O(log n)` over the number of members.
+    pub fn contains(&self, value: &T) -> bool {
+        matches!(self.position_of(value), SetPosition::Inside(_))
+    }
+
+    /// Returns [`contains`](Self::contains) for each of `points`.
+    pub fn contains_many(&self, points: &[T]) -> Vec<bool> {
+        points.iter().map(|point| self.contains(point)).collect()
+    }
+
+    /// Returns the member containing `value`, if any.
+    pub fn find(&self, value: &T) -> Option<&Interval<T>> {
+        match self.position_of(value) {
+            SetPosition::Inside(index) => Some(&self.members[index]),
+            SetPosition::Between { .. } => None,
+        }
+    }
+
+    /// Locates `value` among the members with a binary search over
+    /// their start bounds followed by a single check of the end bound
+    /// of the last member starting at or below `value`.
+    pub fn position_of(&self, value: &T) -> SetPosition {
+        let index = self
+            .members
+            .partition_point(|member| member.start.cmp_value(value) != Ordering::Greater);
+        let next = (index < self.members.len()).then_some(index);
+        if index == 0 {
+            return SetPosition::Between {
+                previous: None,
+                next,
+            };
+        }
+        let candidate = index - 1;
+        if self.members[candidate].end.cmp_value(value) != Ordering::Less {
+            SetPosition::Inside(candidate)
+        } else {
+            SetPosition::Between {
+                previous: Some(candidate),
+                next,
+            }
+        }
+    }
+
+    /// Returns `true` if `interval` shares at least one value with a member.
+    ///
+    /// Runs in `O(log n)`. Touching a member at a flush seam, such as
+    /// `[3, 5]` against a member `[1, 3)`, is not an overlap.
+    pub fn overlaps(&self, interval: &Interval<T>) -> bool {
+        !self.overlapping_range(interval).is_empty()
+    }
+
+    /// Returns the members sharing at least one value with `interval`,
+    /// in ascending order.
+    pub fn overlapping<'a>(
+        &'a self,
+        interval: &Interval<T>,
+    ) -> impl Iterator<Item = &'a Interval<T>> + 'a {
+        self.members_overlapping(interval).iter()
+    }
+
+    /// Like [`IntervalSet::overlapping`], but returns the members as a
+    /// slice of the set, found with two binary searches.
+    ///
+    /// The first and last members may reach past `interval`; use
+    /// [`IntervalSet::clipped_to`] to cut them to it.
+    pub fn members_overlapping(&self, interval: &Interval<T>) -> &[Interval<T>] {
+        &self.members[self.overlapping_range(interval)]
+    }
+
+    /// Members are sorted by both start and end bound, so the ones
+    /// overlapping `interval` form a contiguous run.
+    fn overlapping_range(&self, interval: &Interval<T>) -> Range<usize> {
+        if interval.is_empty() {
+            return 0..0;
+        }
+        let lo = self
+            .members
+            .partition_point(|member| member.end < interval.start);
+        let hi = self
+            .members
+            .partition_point(|member| member.start <= interval.end);
+        lo..hi.max(lo)
+    }
+}
+
+impl<T: Ord + Clone> IntervalSet<T> {
+    /// Returns the part of the set within `window`: the members it
+    /// overlaps, with the first and last clipped to it. Only those
+    /// members are visited and cloned.
+    pub fn clipped_to(&self, window: &Interval<T>) -> IntervalSet<T> {
+        IntervalSet {
+            members: self
+                .members_overlapping(window)
+                .iter()
+                .filter_map(|member| member.intersection(window))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use rstest::rstest;
+
+    // [1, 3) (3, 5] [7, 7] (9, 11) [13, +inf)
+    fn set() -> IntervalSet<i32> {
+        let mut set = IntervalSet::new();
+        set.insert(Interval::from_to(1, 3, IntervalType::EndOpen).unwrap());
+        set.insert(Interval::from_to(3, 5, IntervalType::StartOpen).unwrap());
+        set.insert(Interval::from_to(7, 7, IntervalType::Close).unwrap());
+        set.insert(Interval::from_to(9, 11, IntervalType::Open).unwrap());
+        set.insert(Interval::since_inclusive(13));
+        set
+    }
+
+    #[rstest]
+    #[case(0, SetPosition::Between { previous: None, next: Some(0) })]
+    // inclusive start
+    #[case(1, SetPosition::Inside(0))]
+    #[case(2, SetPosition::Inside(0))]
+    // exclusive end meeting an exclusive start
+    #[case(3, SetPosition::Between { previous: Some(0), next: Some(1) })]
+    #[case(4, SetPosition::Inside(1))]
+    // inclusive end
+    #[case(5, SetPosition::Inside(1))]
+    #[case(6, SetPosition::Between { previous: Some(1), next: Some(2) })]
+    // single point member
+    #[case(7, SetPosition::Inside(2))]
+    #[case(8, SetPosition::Between { previous: Some(2), next: Some(3) })]
+    // exclusive start
+    #[case(9, SetPosition::Between { previous: Some(2), next: Some(3) })]
+    #[case(10, SetPosition::Inside(3))]
+    // exclusive end
+    #[case(11, SetPosition::Between { previous: Some(3), next: Some(4) })]
+    #[case(12, SetPosition::Between { previous: Some(3), next: Some(4) })]
+    // inclusive start of an unbounded member
+    #[case(13, SetPosition::Inside(4))]
+    #[case(i32::MAX, SetPosition::Inside(4))]
+    #[case(i32::MIN, SetPosition::Between { previous: None, next: Some(0) })]
+    fn test_position_of(#[case] value: i32, #[case] expected: SetPosition) {
+        let set = set();
+        assert_eq!(set.position_of(&value), expected);
+        assert_eq!(
+            set.contains(&value),
+            matches!(expected, SetPosition::Inside(_))
+        );
+        assert_eq!(
+            set.find(&value),
+            match expected {
+                SetPosition::Inside(index) => Some(&set.members[index]),
+                SetPosition::Between { .. } => None,
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(Interval::until_exclusive(0), -1, SetPosition::Inside(0))]
+    #[case(Interval::until_exclusive(0), 0, SetPosition::Between { previous: Some(0), next: None })]
+    #[case(Interval::until_inclusive(0), 0, SetPosition::Inside(0))]
+    #[case(Interval::since_exclusive(0), 0, SetPosition::Between { previous: None, next: Some(0) })]
+    fn test_position_of_single_unbounded_member(
+        #[case] member: Interval<i32>,
+        #[case] value: i32,
+        #[case] expected: SetPosition,
+    ) {
+        let mut set = IntervalSet::new();
+        set.insert(member);
+        assert_eq!(set.position_of(&value), expected);
+    }
+
+    #[rstest]
+    // flush with the exclusive end of [1, 3)
+    #[case(Interval::from_to(3, 3, IntervalType::Close).unwrap(), vec![])]
+    #[case(Interval::from_to(0, 1, IntervalType::EndOpen).unwrap(), vec![])]
+    #[case(Interval::from_to(0, 1, IntervalType::Close).unwrap(), vec![0])]
+    // flush with the inclusive end of (3, 5]
+    #[case(Interval::from_to(5, 7, IntervalType::Open).unwrap(), vec![])]
+    #[case(Interval::from_to(5, 7, IntervalType::Close).unwrap(), vec![1, 2])]
+    // flush with the exclusive start of (9, 11)
+    #[case(Interval::from_to(8, 9, IntervalType::Close).unwrap(), vec![])]
+    #[case(Interval::from_to(2, 10, IntervalType::Open).unwrap(), vec![0, 1, 2, 3])]
+    #[case(Interval::since_exclusive(11), vec![4])]
+    #[case(Interval::until_exclusive(100), vec![0, 1, 2, 3, 4])]
+    #[case(Interval::from_to(4, 4, IntervalType::Open).unwrap(), vec![])]
+    fn test_overlapping(#[case] interval: Interval<i32>, #[case] expected: Vec<usize>) {
+        let set = set();
+        let expected: Vec<_> = expected.into_iter().map(|index| &set.members[index]).collect();
+        assert_eq!(set.overlapping(&interval).collect::<Vec<_>>(), expected);
+        assert_eq!(
+            set.members_overlapping(&interval).iter().collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(set.overlaps(&interval), !expected.is_empty());
+    }
+
+    #[rstest]
+    // straddling members at both window edges are clipped
+    #[case(Interval::from_to(2, 10, IntervalType::Close).unwrap(),
+           vec![Interval::from_to(2, 3, IntervalType::EndOpen).unwrap(), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap(), Interval::from_to(7, 7, IntervalType::Close).unwrap(), Interval::from_to(9, 10, IntervalType::StartOpen).unwrap()])]
+    #[case(Interval::from_to(10, 20, IntervalType::Open).unwrap(),
+           vec![Interval::from_to(10, 11, IntervalType::Open).unwrap(), Interval::from_to(13, 20, IntervalType::EndOpen).unwrap()])]
+    // exclusive window edges touching member bounds
+    #[case(Interval::from_to(5, 9, IntervalType::Open).unwrap(), vec![Interval::from_to(7, 7, IntervalType::Close).unwrap()])]
+    #[case(Interval::from_to(1, 13, IntervalType::Open).unwrap(),
+           vec![Interval::from_to(1, 3, IntervalType::Open).unwrap(), Interval::from_to(3, 5, IntervalType::StartOpen).unwrap(), Interval::from_to(7, 7, IntervalType::Close).unwrap(), Interval::from_to(9, 11, IntervalType::Open).unwrap()])]
+    #[case(Interval::from_to(5, 13, IntervalType::Close).unwrap(),
+           vec![Interval::from_to(5, 5, IntervalType::Close).unwrap(), Interval::from_to(7, 7, IntervalType::Close).unwrap(), Interval::from_to(9, 11, IntervalType::Open).unwrap(), Interval::from_to(13, 13, IntervalType::Close).unwrap()])]
+    #[case(Interval::from_to(11, 13, IntervalType::EndOpen).unwrap(), vec![])]
+    #[case(Interval::until_inclusive(0), vec![])]
+    fn test_clipped_to(#[case] window: Interval<i32>, #[case] expected: Vec<Interval<i32>>) {
+        let clipped = set().clipped_to(&window);
+        assert_eq!(clipped.members, expected);
+        assert_eq!(clipped, set().intersection(&IntervalSet::from(vec![window])));
+    }
+
+    #[test]
+    fn test_position_of_empty_set() {
+        let set = IntervalSet::<i32>::new();
+        assert_eq!(
+            set.position_of(&0),
+            SetPosition::Between {
+                previous: None,
+                next: None
+            }
+        );
+        assert!(!set.contains(&0));
+    }
+
+    #[test]
+    fn test_contains_many_members() {
+        let mut set = IntervalSet::new();
+        for start in (0..10_000).step_by(3) {
+            set.insert(Interval::from_to(start, start + 1, IntervalType::Close).unwrap());
+        }
+        for value in 0..10_000 {
+            assert_eq!(set.contains(&value), value % 3 != 2, "failed: {}", value);
+        }
+    }
+}
@@ -0,0 +1,654 @@
+use crate::interval::Interval;
+use crate::interval_set::{IntervalSet, SetPosition};
+use std::cmp::Ordering;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// The most members a chunk holds.
+const CHUNK: usize = 32;
+
+/// The fewest members a chunk holds, unless it is the only one.
+const MIN_CHUNK: usize = CHUNK / 2;
+
+type Chunk<T> = Arc<[Interval<T>]>;
+type Tree<T> = Option<Arc<Node<T>>>;
+
+/// An [`IntervalSet`] for readers taking snapshots while a writer keeps
+/// editing it.
+///
+/// The members are stored in shared chunks of 16 to 32 members, held in
+/// order by a persistent AVL tree, so `clone` is a snapshot costing one
+/// reference count. An `insert` or `remove` on a set whose storage is
+/// shared with a snapshot copies the chunks holding the members it
+/// touches and the `O(log n)` tree nodes on the paths to them; all other
+/// chunks and nodes stay shared with the snapshot. A chunk left
+/// under-full by an edit is merged with a neighbour.
+#[derive(Debug, Clone)]
+pub struct SharedIntervalSet<T>
+where
+    T: Ord,
+{
+    root: Tree<T>,
+}
+
+/// A node of the tree, holding one chunk between the chunks of its
+/// subtrees.
+#[derive(Debug)]
+struct Node<T: Ord> {
+    left: Tree<T>,
+    chunk: Chunk<T>,
+    right: Tree<T>,
+    height: u8,
+    /// The number of chunks in the subtree.
+    chunks: usize,
+    /// The number of members in the subtree.
+    members: usize,
+}
+
+impl<T: Ord + Clone> SharedIntervalSet<T> {
+    pub fn new() -> Self {
+        SharedIntervalSet { root: None }
+    }
+
+    /// Adds every value of `interval` to the set, merging it with the
+    /// members it overlaps or meets, like [`IntervalSet::insert`].
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let lo = partition_point(&self.root, |chunk| {
+            let last = &chunk[chunk.len() - 1];
+            last.end < interval.start && !last.end.meets(&interval.start)
+        });
+        let hi = partition_point(&self.root, |chunk| {
+            chunk[0].start <= interval.end || interval.end.meets(&chunk[0].start)
+        });
+        // an interval touching no member goes into a neighbouring chunk
+        let touched = match chunk_count(&self.root) {
+            0 => 0..0,
+            len if lo == hi => lo.min(len - 1)..lo.min(len - 1) + 1,
+            _ => lo..hi,
+        };
+        self.edit(touched, |members| members.insert(interval));
+    }
+
+    /// Removes every value of `interval` from the set, like
+    /// [`IntervalSet::remove`]. Storage shared with snapshots is left
+    /// alone when no member overlaps `interval`.
+    pub fn remove(&mut self, interval: &Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let lo = partition_point(&self.root, |chunk| {
+            chunk[chunk.len() - 1].end < interval.start
+        });
+        let hi = partition_point(&self.root, |chunk| chunk[0].start <= interval.end);
+        if lo >= hi {
+            return;
+        }
+        // only the first touched chunk can miss `interval`, in a gap
+        let (first, _) = chunk_at(&self.root, lo);
+        let overlapping = first.partition_point(|member| member.end < interval.start)
+            < first.partition_point(|member| member.start <= interval.end);
+        if overlapping {
+            self.edit(lo..hi, |members| members.remove(interval));
+        }
+    }
+
+    /// Replaces the chunks in `touched` with the result of applying `f`
+    /// to their members, re-cut into chunks of [`MIN_CHUNK`] to
+    /// [`CHUNK`] members.
+    fn edit<F>(&mut self, touched: Range<usize>, f: F)
+    where
+        F: FnOnce(&mut IntervalSet<T>),
+    {
+        let (mut left, rest) = split(self.root.take(), touched.start);
+        let (middle, mut right) = split(rest, touched.len());
+        let mut members = IntervalSet {
+            members: Chunks::new(&middle)
+                .flat_map(|chunk| chunk.iter().cloned())
+                .collect(),
+        };
+        f(&mut members);
+        let mut members = members.members;
+        // too few members for a chunk of their own take in a neighbour,
+        // which holds at least `MIN_CHUNK` as it is not the only chunk
+        if (1..MIN_CHUNK).contains(&members.len()) {
+            if let Some((rest, last)) = split_last(left.take()) {
+                members.splice(0..0, last.iter().cloned());
+                left = rest;
+            } else if let Some((first, rest)) = split_first(right.take()) {
+                members.extend(first.iter().cloned());
+                right = rest;
+            }
+        }
+        self.root = join_trees(join_trees(left, build(&recut(&members))), right);
+    }
+
+    /// Returns `true` if `value` lies within one of the members.
+    ///
+    /// Runs in `O(log n)` over the number of members.
+    pub fn contains(&self, value: &T) -> bool {
+        matches!(self.position_of(value), SetPosition::Inside(_))
+    }
+
+    /// Locates `value` among the members like
+    /// [`IntervalSet::position_of`], with indices counting the members
+    /// in ascending order.
+    ///
+    /// Descends the tree to the last chunk starting at or below `value`,
+    /// then binary-searches within it.
+    pub fn position_of(&self, value: &T) -> SetPosition {
+        let starts_at_or_below =
+            |member: &Interval<T>| member.start.cmp_value(value) != Ordering::Greater;
+        let chunks = partition_point(&self.root, |chunk| starts_at_or_below(&chunk[0]));
+        let (index, candidate) = match chunks.checked_sub(1) {
+            Some(last) => {
+                let (chunk, offset) = chunk_at(&self.root, last);
+                let index = chunk.partition_point(starts_at_or_below);
+                (offset + index, Some(&chunk[index - 1]))
+            }
+            None => (0, None),
+        };
+        let next = (index < self.member_count()).then_some(index);
+        match candidate {
+            Some(member) if member.end.cmp_value(value) != Ordering::Less => {
+                SetPosition::Inside(index - 1)
+            }
+            Some(_) => SetPosition::Between {
+                previous: Some(index - 1),
+                next,
+            },
+            None => SetPosition::Between {
+                previous: None,
+                next,
+            },
+        }
+    }
+
+    /// Returns `true` if `interval` shares at least one value with a
+    /// member.
+    ///
+    /// Runs in `O(log n)`. Touching a member at a flush seam, such as
+    /// `[3, 5]` against a member `[1, 3)`, is not an overlap.
+    pub fn overlaps(&self, interval: &Interval<T>) -> bool {
+        self.overlapping(interval).next().is_some()
+    }
+
+    /// Returns the members sharing at least one value with `interval`,
+    /// in ascending order, like [`IntervalSet::overlapping`].
+    ///
+    /// Descends the tree to the first chunk ending at or above the start
+    /// of `interval` and walks the members from there.
+    pub fn overlapping<'a>(
+        &'a self,
+        interval: &Interval<T>,
+    ) -> impl Iterator<Item = &'a Interval<T>> + 'a {
+        let first = if interval.is_empty() {
+            chunk_count(&self.root)
+        } else {
+            partition_point(&self.root, |chunk| {
+                chunk[chunk.len() - 1].end < interval.start
+            })
+        };
+        let (start, end) = (interval.start.clone(), interval.end.clone());
+        Chunks::starting_at(&self.root, first)
+            .flat_map(|chunk| chunk.iter())
+            .skip_while(move |member| member.end < start)
+            .take_while(move |member| member.start <= end)
+    }
+
+    /// Returns the members in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.chunks().flat_map(|chunk| chunk.iter())
+    }
+
+    /// Returns the number of disjoint members.
+    pub fn member_count(&self) -> usize {
+        member_count(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Copies the members into a plain, unshared set.
+    pub fn to_interval_set(&self) -> IntervalSet<T> {
+        IntervalSet {
+            members: self.iter().cloned().collect(),
+        }
+    }
+
+    fn chunks(&self) -> Chunks<'_, T> {
+        Chunks::new(&self.root)
+    }
+}
+
+impl<T: Ord + Clone> Default for SharedIntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> PartialEq for SharedIntervalSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Ord + Clone> From<IntervalSet<T>> for SharedIntervalSet<T> {
+    fn from(set: IntervalSet<T>) -> Self {
+        SharedIntervalSet {
+            root: build(&recut(&set.members)),
+        }
+    }
+}
+
+/// Cuts `members` into as few chunks as fit them, of sizes differing by
+/// at most one, so that every chunk holds at least [`MIN_CHUNK`] members
+/// unless there is a single one.
+fn recut<T: Ord + Clone>(members: &[Interval<T>]) -> Vec<Chunk<T>> {
+    let count = members.len().div_ceil(CHUNK);
+    let mut rest = members;
+    (0..count)
+        .map(|i| {
+            let (chunk, tail) = rest.split_at(rest.len() / (count - i));
+            rest = tail;
+            Arc::from(chunk)
+        })
+        .collect()
+}
+
+/// In-order iterator over the chunks of a tree.
+struct Chunks<'a, T: Ord> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T: Ord> Chunks<'a, T> {
+    fn new(tree: &'a Tree<T>) -> Self {
+        let mut chunks = Chunks { stack: Vec::new() };
+        chunks.descend(tree);
+        chunks
+    }
+
+    /// Starts the iteration at the chunk at `index`.
+    fn starting_at(mut tree: &'a Tree<T>, mut index: usize) -> Self {
+        let mut stack = Vec::new();
+        while let Some(node) = tree {
+            let left = chunk_count(&node.left);
+            if index <= left {
+                stack.push(&**node);
+                if index == left {
+                    break;
+                }
+                tree = &node.left;
+            } else {
+                index -= left + 1;
+                tree = &node.right;
+            }
+        }
+        Chunks { stack }
+    }
+
+    fn descend(&mut self, mut tree: &'a Tree<T>) {
+        while let Some(node) = tree {
+            self.stack.push(node);
+            tree = &node.left;
+        }
+    }
+}
+
+impl<'a, T: Ord> Iterator for Chunks<'a, T> {
+    type Item = &'a Chunk<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.descend(&node.right);
+        Some(&node.chunk)
+    }
+}
+
+fn height<T: Ord>(tree: &Tree<T>) -> u8 {
+    tree.as_ref().map_or(0, |node| node.height)
+}
+
+fn chunk_count<T: Ord>(tree: &Tree<T>) -> usize {
+    tree.as_ref().map_or(0, |node| node.chunks)
+}
+
+fn member_count<T: Ord>(tree: &Tree<T>) -> usize {
+    tree.as_ref().map_or(0, |node| node.members)
+}
+
+fn node<T: Ord>(left: Tree<T>, chunk: Chunk<T>, right: Tree<T>) -> Tree<T> {
+    Some(Arc::new(Node {
+        height: height(&left).max(height(&right)) + 1,
+        chunks: chunk_count(&left) + 1 + chunk_count(&right),
+        members: member_count(&left) + chunk.len() + member_count(&right),
+        left,
+        chunk,
+        right,
+    }))
+}
+
+/// Returns the parts of a node, sharing them with it.
+fn expose<T: Ord>(node: &Node<T>) -> (Tree<T>, Chunk<T>, Tree<T>) {
+    (node.left.clone(), node.chunk.clone(), node.right.clone())
+}
+
+/// Returns the number of leading chunks for which `pred` holds, given
+/// that it holds for a prefix of them.
+fn partition_point<T: Ord>(tree: &Tree<T>, pred: impl Fn(&[Interval<T>]) -> bool) -> usize {
+    let (mut count, mut tree) = (0, tree);
+    while let Some(node) = tree {
+        if pred(&node.chunk) {
+            count += chunk_count(&node.left) + 1;
+            tree = &node.right;
+        } else {
+            tree = &node.left;
+        }
+    }
+    count
+}
+
+/// Returns the chunk at `index` and the number of members before it.
+fn chunk_at<T: Ord>(tree: &Tree<T>, mut index: usize) -> (&Chunk<T>, usize) {
+    let mut node = tree.as_ref().expect("index within the tree");
+    let mut offset = 0;
+    loop {
+        let left = chunk_count(&node.left);
+        node = match index.cmp(&left) {
+            Ordering::Less => node.left.as_ref().expect("index within the tree"),
+            Ordering::Equal => return (&node.chunk, offset + member_count(&node.left)),
+            Ordering::Greater => {
+                index -= left + 1;
+                offset += member_count(&node.left) + node.chunk.len();
+                node.right.as_ref().expect("index within the tree")
+            }
+        };
+    }
+}
+
+fn rotate_left<T: Ord>(tree: Tree<T>) -> Tree<T> {
+    let (a, x, right) = expose(&tree.expect("rotating a node"));
+    let (b, y, c) = expose(&right.expect("rotating left needs a right child"));
+    node(node(a, x, b), y, c)
+}
+
+fn rotate_right<T: Ord>(tree: Tree<T>) -> Tree<T> {
+    let (left, y, c) = expose(&tree.expect("rotating a node"));
+    let (a, x, b) = expose(&left.expect("rotating right needs a left child"));
+    node(a, x, node(b, y, c))
+}
+
+/// Returns the tree holding the chunks of `left`, then `chunk`, then
+/// those of `right`, in time proportional to their height difference.
+fn join<T: Ord>(left: Tree<T>, chunk: Chunk<T>, right: Tree<T>) -> Tree<T> {
+    if height(&left) > height(&right) + 1 {
+        join_right(left, chunk, right)
+    } else if height(&right) > height(&left) + 1 {
+        join_left(left, chunk, right)
+    } else {
+        node(left, chunk, right)
+    }
+}
+
+/// Joins down the right spine of `left`, the taller tree.
+fn join_right<T: Ord>(left: Tree<T>, chunk: Chunk<T>, right: Tree<T>) -> Tree<T> {
+    let (a, x, b) = expose(left.as_ref().expect("the taller tree has a root"));
+    if height(&b) <= height(&right) + 1 {
+        let joined = node(b, chunk, right);
+        if height(&joined) <= height(&a) + 1 {
+            node(a, x, joined)
+        } else {
+            rotate_left(node(a, x, rotate_right(joined)))
+        }
+    } else {
+        let joined = join_right(b, chunk, right);
+        let balanced = height(&joined) <= height(&a) + 1;
+        let joined = node(a, x, joined);
+        if balanced {
+            joined
+        } else {
+            rotate_left(joined)
+        }
+    }
+}
+
+/// Joins down the left spine of `right`, the taller tree.
+fn join_left<T: Ord>(left: Tree<T>, chunk: Chunk<T>, right: Tree<T>) -> Tree<T> {
+    let (b, x, c) = expose(right.as_ref().expect("the taller tree has a root"));
+    if height(&b) <= height(&left) + 1 {
+        let joined = node(left, chunk, b);
+        if height(&joined) <= height(&c) + 1 {
+            node(joined, x, c)
+        } else {
+            rotate_right(node(rotate_left(joined), x, c))
+        }
+    } else {
+        let joined = join_left(left, chunk, b);
+        let balanced = height(&joined) <= height(&c) + 1;
+        let joined = node(joined, x, c);
+        if balanced {
+            joined
+        } else {
+            rotate_right(joined)
+        }
+    }
+}
+
+/// Returns the tree holding the chunks of `left`, then those of `right`.
+fn join_trees<T: Ord>(left: Tree<T>, right: Tree<T>) -> Tree<T> {
+    match split_first(right) {
+        Some((first, rest)) => join(left, first, rest),
+        None => left,
+    }
+}
+
+/// Splits the tree into its first `index` chunks and the rest.
+fn split<T: Ord>(tree: Tree<T>, index: usize) -> (Tree<T>, Tree<T>) {
+    let Some(root) = tree else {
+        return (None, None);
+    };
+    let (left, chunk, right) = expose(&root);
+    let before = chunk_count(&left);
+    if index <= before {
+        let (first, rest) = split(left, index);
+        (first, join(rest, chunk, right))
+    } else {
+        let (first, rest) = split(right, index - before - 1);
+        (join(left, chunk, first), rest)
+    }
+}
+
+fn split_first<T: Ord>(tree: Tree<T>) -> Option<(Chunk<T>, Tree<T>)> {
+    let root = tree?;
+    let (left, chunk, right) = expose(&root);
+    Some(match split_first(left) {
+        Some((first, rest)) => (first, join(rest, chunk, right)),
+        None => (chunk, right),
+    })
+}
+
+fn split_last<T: Ord>(tree: Tree<T>) -> Option<(Tree<T>, Chunk<T>)> {
+    let root = tree?;
+    let (left, chunk, right) = expose(&root);
+    Some(match split_last(right) {
+        Some((rest, last)) => (join(left, chunk, rest), last),
+        None => (left, chunk),
+    })
+}
+
+/// Builds a perfectly balanced tree over `chunks`.
+fn build<T: Ord>(chunks: &[Chunk<T>]) -> Tree<T> {
+    if chunks.is_empty() {
+        return None;
+    }
+    let middle = chunks.len() / 2;
+    node(
+        build(&chunks[..middle]),
+        chunks[middle].clone(),
+        build(&chunks[middle + 1..]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::Rng;
+    use std::collections::HashSet;
+
+    fn spaced(count: i32) -> IntervalSet<i32> {
+        IntervalSet::from(
+            (0..count)
+                .map(|i| Interval::from_to(i * 10, i * 10 + 5, IntervalType::Close).unwrap())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Checks the balance and cached counts of the tree and the chunk
+    /// sizes.
+    fn check<T: Ord + Clone>(set: &SharedIntervalSet<T>) {
+        fn walk<T: Ord>(tree: &Tree<T>) -> (u8, usize, usize) {
+            let Some(node) = tree else {
+                return (0, 0, 0);
+            };
+            let (left_height, left_chunks, left_members) = walk(&node.left);
+            let (right_height, right_chunks, right_members) = walk(&node.right);
+            assert!(left_height.abs_diff(right_height) <= 1);
+            let counts = (
+                left_height.max(right_height) + 1,
+                left_chunks + 1 + right_chunks,
+                left_members + node.chunk.len() + right_members,
+            );
+            assert_eq!((node.height, node.chunks, node.members), counts);
+            counts
+        }
+        walk(&set.root);
+        let sizes: Vec<usize> = set.chunks().map(|chunk| chunk.len()).collect();
+        let min = if sizes.len() == 1 { 1 } else { MIN_CHUNK };
+        assert!(
+            sizes.iter().all(|size| (min..=CHUNK).contains(size)),
+            "{:?}",
+            sizes
+        );
+    }
+
+    /// Collects the nodes of `tree` into `found`.
+    fn nodes<T: Ord>(tree: &Tree<T>, found: &mut Vec<*const Node<T>>) {
+        if let Some(node) = tree {
+            found.push(Arc::as_ptr(node));
+            nodes(&node.left, found);
+            nodes(&node.right, found);
+        }
+    }
+
+    #[test]
+    fn test_matches_plain_set() {
+        let mut rng = Rng::new(425);
+        for _ in 0..50 {
+            let mut plain = IntervalSet::new();
+            let mut shared = SharedIntervalSet::new();
+            let mut snapshots = Vec::new();
+            for _ in 0..200 {
+                let interval = rng.interval(-1000, 1000);
+                if rng.range(0, 3) == 0 {
+                    plain.remove(&interval);
+                    shared.remove(&interval);
+                } else {
+                    plain.insert(interval);
+                    shared.insert(interval);
+                }
+                assert_eq!(shared.to_interval_set(), plain);
+                assert_eq!(shared.member_count(), plain.member_count());
+                check(&shared);
+                for _ in 0..5 {
+                    let value = rng.range(-1100, 1100);
+                    assert_eq!(shared.position_of(&value), plain.position_of(&value));
+                    assert_eq!(shared.contains(&value), plain.contains(&value));
+                    let probe = rng.interval(-1100, 1100);
+                    assert!(shared.overlapping(&probe).eq(plain.overlapping(&probe)));
+                    assert_eq!(shared.overlaps(&probe), plain.overlaps(&probe));
+                }
+                if rng.range(0, 10) == 0 {
+                    snapshots.push((shared.clone(), plain.clone()));
+                }
+            }
+            for (shared, plain) in snapshots {
+                assert_eq!(shared.to_interval_set(), plain);
+            }
+        }
+    }
+
+    #[test]
+    fn test_removals_merge_under_full_chunks_and_lookups_agree() {
+        let mut rng = Rng::new(425);
+        let mut plain = spaced(1000);
+        let mut shared = SharedIntervalSet::from(plain.clone());
+        let mut order: Vec<i32> = (0..1000).collect();
+        for i in (1..order.len()).rev() {
+            order.swap(i, rng.range(0, i as i32 + 1) as usize);
+        }
+        for i in order {
+            let member = Interval::from_to(i * 10, i * 10 + 5, IntervalType::Close).unwrap();
+            plain.remove(&member);
+            shared.remove(&member);
+            assert_eq!(shared.to_interval_set(), plain);
+            check(&shared);
+            let value = rng.range(-10, 10_010);
+            assert_eq!(shared.position_of(&value), plain.position_of(&value));
+            let probe = rng.interval(-10, 10_010);
+            assert!(shared.overlapping(&probe).eq(plain.overlapping(&probe)));
+        }
+        assert!(shared.is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_shares_untouched_chunks() {
+        let mut current = SharedIntervalSet::from(spaced(200));
+        let snapshot = current.clone();
+        let root = |set: &SharedIntervalSet<i32>| Arc::as_ptr(set.root.as_ref().unwrap());
+        assert_eq!(root(&current), root(&snapshot));
+
+        current.insert(Interval::from_to(1990, 2100, IntervalType::Close).unwrap());
+        assert_eq!(snapshot.to_interval_set(), spaced(200));
+        assert_eq!(current.member_count(), 200);
+        let last = chunk_count(&current.root) - 1;
+        for (i, (chunk, old)) in current.chunks().zip(snapshot.chunks()).enumerate() {
+            assert_eq!(Arc::ptr_eq(chunk, old), i != last);
+        }
+
+        // removing nothing copies nothing
+        let before = current.clone();
+        current.remove(&Interval::from_to(6, 9, IntervalType::Close).unwrap());
+        assert_eq!(root(&current), root(&before));
+    }
+
+    #[test]
+    fn test_edit_copies_a_logarithmic_path() {
+        let mut current = SharedIntervalSet::from(spaced(100_000));
+        let snapshot = current.clone();
+        current.insert(Interval::from_to(500_003, 500_017, IntervalType::Close).unwrap());
+
+        let mut shared = Vec::new();
+        nodes(&snapshot.root, &mut shared);
+        let shared: HashSet<_> = shared.into_iter().collect();
+        let mut copied = Vec::new();
+        nodes(&current.root, &mut copied);
+        copied.retain(|node| !shared.contains(node));
+        // a few nodes per level of the tree, out of thousands
+        let height = usize::from(height(&snapshot.root));
+        assert!(
+            copied.len() <= 4 * height,
+            "{} of height {}",
+            copied.len(),
+            height
+        );
+        let new_chunks = current
+            .chunks()
+            .filter(|chunk| !snapshot.chunks().any(|old| Arc::ptr_eq(chunk, old)))
+            .count();
+        assert_eq!(new_chunks, 1);
+    }
+}
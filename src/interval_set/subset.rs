@@ -0,0 +1,161 @@
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+
+impl<T: Ord> IntervalSet<T> {
+    /// Returns `true` if every value of `interval` lies within the set.
+    ///
+    /// Since members never touch, the whole interval must fit inside a
+    /// single member, found with one binary search. An empty interval is
+    /// always covered, while `{[1, 5)}` does not cover `[1, 5]`.
+    pub fn covers(&self, interval: &Interval<T>) -> bool {
+        if interval.is_empty() {
+            return true;
+        }
+        let index = self
+            .members
+            .partition_point(|member| member.start <= interval.start);
+        index > 0 && self.members[index - 1].end >= interval.end
+    }
+
+    /// Returns `true` if every value in `self` also lies within `other`.
+    ///
+    /// Walks both member lists once, stopping at the first member of
+    /// `self` that no member of `other` contains.
+    pub fn is_subset_of(&self, other: &IntervalSet<T>) -> bool {
+        let mut candidates = other.members.iter().peekable();
+        self.members.iter().all(|member| {
+            while candidates
+                .next_if(|candidate| candidate.end < member.start)
+                .is_some()
+            {}
+            candidates.peek().is_some_and(|candidate| {
+                candidate.start <= member.start && member.end <= candidate.end
+            })
+        })
+    }
+
+    /// Returns `true` if every value in `other` also lies within `self`.
+    pub fn is_superset_of(&self, other: &IntervalSet<T>) -> bool {
+        other.is_subset_of(self)
+    }
+}
+
+impl<T: Ord + Clone> IntervalSet<T> {
+    /// Returns the values of `interval` not covered by the set, which is
+    /// empty exactly when [`IntervalSet::covers`] holds.
+    ///
+    /// Members reaching past `interval` are clipped to it, so `{[1, 5)}`
+    /// leaves `{[5, 5]}` of `[1, 5]` uncovered. The same as
+    /// [`IntervalSet::complement_within`], named for reporting what is
+    /// missing from a request.
+    pub fn uncovered_within(&self, interval: &Interval<T>) -> IntervalSet<T> {
+        self.complement_within(interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{contains_half_step, set, Rng};
+    use rstest::rstest;
+
+    fn availability() -> IntervalSet<i32> {
+        set(vec![
+            Interval::from_to(1, 5, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(8, 10, IntervalType::Close).unwrap(),
+            Interval::since_exclusive(20),
+        ])
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(1, 5, IntervalType::EndOpen).unwrap(), true)]
+    #[case(Interval::from_to(2, 3, IntervalType::Close).unwrap(), true)]
+    // the exclusive member end does not cover an inclusive one
+    #[case(Interval::from_to(1, 5, IntervalType::Close).unwrap(), false)]
+    #[case(Interval::from_to(0, 2, IntervalType::Close).unwrap(), false)]
+    // spans the gap between two members
+    #[case(Interval::from_to(4, 9, IntervalType::Close).unwrap(), false)]
+    #[case(Interval::from_to(20, 30, IntervalType::StartOpen).unwrap(), true)]
+    #[case(Interval::from_to(20, 30, IntervalType::Close).unwrap(), false)]
+    #[case(Interval::since_inclusive(21), true)]
+    #[case(Interval::until_inclusive(3), false)]
+    #[case(Interval::from_to(6, 6, IntervalType::Open).unwrap(), true)]
+    fn test_covers(#[case] interval: Interval<i32>, #[case] expected: bool) {
+        assert_eq!(availability().covers(&interval), expected);
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(2, 3, IntervalType::Close).unwrap(), vec![])]
+    // covered except for the excluded member end
+    #[case(Interval::from_to(1, 5, IntervalType::Close).unwrap(), vec![Interval::from_to(5, 5, IntervalType::Close).unwrap()])]
+    #[case(Interval::from_to(0, 9, IntervalType::Close).unwrap(),
+           vec![Interval::from_to(0, 1, IntervalType::EndOpen).unwrap(), Interval::from_to(5, 8, IntervalType::EndOpen).unwrap()])]
+    #[case(Interval::from_to(9, 22, IntervalType::Open).unwrap(), vec![Interval::from_to(10, 20, IntervalType::StartOpen).unwrap()])]
+    #[case(Interval::until_inclusive(3), vec![Interval::until_exclusive(1)])]
+    #[case(Interval::from_to(6, 6, IntervalType::Open).unwrap(), vec![])]
+    fn test_uncovered_within(#[case] interval: Interval<i32>, #[case] expected: Vec<Interval<i32>>) {
+        let uncovered = availability().uncovered_within(&interval);
+        assert_eq!(uncovered.members, expected);
+        assert_eq!(uncovered.is_empty(), availability().covers(&interval));
+    }
+
+    #[rstest]
+    #[case(vec![], vec![], true)]
+    #[case(vec![], vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()], true)]
+    #[case(vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()], vec![], false)]
+    #[case(vec![Interval::from_to(1, 5, IntervalType::EndOpen).unwrap()], vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()], true)]
+    #[case(vec![Interval::from_to(1, 5, IntervalType::Close).unwrap()], vec![Interval::from_to(1, 5, IntervalType::EndOpen).unwrap()], false)]
+    #[case(vec![Interval::from_to(1, 2, IntervalType::Close).unwrap(), Interval::from_to(8, 9, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(0, 3, IntervalType::Close).unwrap(), Interval::from_to(5, 6, IntervalType::Close).unwrap(), Interval::since_inclusive(7)], true)]
+    #[case(vec![Interval::from_to(1, 2, IntervalType::Close).unwrap(), Interval::from_to(4, 5, IntervalType::Close).unwrap()],
+           vec![Interval::from_to(0, 3, IntervalType::Close).unwrap(), Interval::from_to(5, 6, IntervalType::Close).unwrap()], false)]
+    fn test_is_subset_of(
+        #[case] subset: Vec<Interval<i32>>,
+        #[case] superset: Vec<Interval<i32>>,
+        #[case] expected: bool,
+    ) {
+        let (subset, superset) = (set(subset), set(superset));
+        assert_eq!(subset.is_subset_of(&superset), expected);
+        assert_eq!(superset.is_superset_of(&subset), expected);
+    }
+
+    #[test]
+    fn test_is_subset_of_matches_brute_force() {
+        let mut rng = Rng::new(365);
+        let covers = |set: &IntervalSet<i32>, half_steps| {
+            set.members
+                .iter()
+                .any(|member| contains_half_step(member, half_steps))
+        };
+        for _ in 0..1000 {
+            let left = set((0..rng.range(0, 3))
+                .map(|_| rng.interval(-10, 10))
+                .collect());
+            let right = set((0..rng.range(0, 5))
+                .map(|_| rng.interval(-10, 10))
+                .collect());
+            let expected = (-30..30)
+                .all(|half_steps| !covers(&left, half_steps) || covers(&right, half_steps));
+            assert_eq!(
+                left.is_subset_of(&right),
+                expected,
+                "failed: {:?}, {:?}",
+                left,
+                right
+            );
+            let interval = rng.interval(-10, 10);
+            let expected = (-30..30).all(|half_steps| {
+                !contains_half_step(&interval, half_steps) || covers(&right, half_steps)
+            });
+            assert_eq!(
+                right.covers(&interval),
+                expected,
+                "failed: {:?}, {:?}",
+                right,
+                interval
+            );
+            assert_eq!(right.uncovered_within(&interval).is_empty(), expected);
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::Interval;
+use std::cmp::Ordering;
+
+/// A static index over possibly overlapping intervals, each carrying a
+/// value, answering stabbing and overlap queries in `O(log n + k)` for
+/// `k` reported entries.
+///
+/// The tree is a centered interval tree: every node picks a center bound
+/// point and holds the entries containing it, sorted both by start and by
+/// end; entries entirely below or above the center go to the left or
+/// right subtree. An overlap query is answered as the entries containing
+/// the query start (a stabbing query) plus the entries starting within
+/// the query, found by binary search over all entries sorted by start.
+///
+/// Empty intervals contain no value and are never reported.
+#[derive(Debug, Clone)]
+pub struct IntervalTree<T, V>
+where
+    T: Ord,
+{
+    entries: Vec<(Interval<T>, V)>,
+    nodes: Vec<Node>,
+    root: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    /// Index of the entry whose start bound is the center of this node.
+    center: usize,
+    /// Entries containing the center, by ascending start.
+    by_start: Vec<usize>,
+    /// Entries containing the center, by descending end.
+    by_end: Vec<usize>,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+impl<T: Ord, V> IntervalTree<T, V> {
+    pub fn build<I: IntoIterator<Item = (Interval<T>, V)>>(entries: I) -> Self {
+        let mut entries: Vec<_> = entries.into_iter().collect();
+        entries.sort_by(|(a, _), (b, _)| a.start.cmp(&b.start));
+
+        let mut tree = IntervalTree {
+            entries,
+            nodes: Vec::new(),
+            root: None,
+        };
+        let indices: Vec<usize> = (0..tree.entries.len())
+            .filter(|&index| !tree.entries[index].0.is_empty())
+            .collect();
+        tree.root = tree.build_node(indices);
+        tree
+    }
+
+    /// Builds the subtree over `indices`, which are sorted by start.
+    fn build_node(&mut self, indices: Vec<usize>) -> Option<usize> {
+        if indices.is_empty() {
+            return None;
+        }
+        let center = indices[indices.len() / 2];
+        let center_point = &self.entries[center].0.start;
+
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        let mut by_start = Vec::new();
+        for index in indices {
+            let interval = &self.entries[index].0;
+            if interval.end < *center_point {
+                left.push(index);
+            } else if interval.start > *center_point {
+                right.push(index);
+            } else {
+                by_start.push(index);
+            }
+        }
+        let mut by_end = by_start.clone();
+        by_end.sort_by(|&a, &b| self.entries[b].0.end.cmp(&self.entries[a].0.end));
+
+        let left = self.build_node(left);
+        let right = self.build_node(right);
+        self.nodes.push(Node {
+            center,
+            by_start,
+            by_end,
+            left,
+            right,
+        });
+        Some(self.nodes.len() - 1)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns all entries, sorted by the start bound of their interval.
+    pub fn iter(&self) -> std::slice::Iter<'_, (Interval<T>, V)> {
+        self.entries.iter()
+    }
+
+    /// Returns the entries whose interval contains `value`,
+    /// sorted by start bound.
+    pub fn stab(&self, value: &T) -> impl Iterator<Item = &(Interval<T>, V)> {
+        let mut found = Vec::new();
+        self.stab_indices(|point| point.cmp_value(value), &mut found);
+        self.resolve(found)
+    }
+
+    /// Returns the entries whose interval overlaps `query`,
+    /// sorted by start bound.
+    pub fn query_overlapping(
+        &self,
+        query: &Interval<T>,
+    ) -> impl Iterator<Item = &(Interval<T>, V)> {
+        let mut found = Vec::new();
+        if !query.is_empty() {
+            // entries starting at or below the query start must contain it
+            self.stab_indices(|point| point.cmp(&query.start), &mut found);
+            // any other overlapping entry starts within the query
+            let lo = self
+                .entries
+                .partition_point(|(interval, _)| interval.start <= query.start);
+            let hi = self
+                .entries
+                .partition_point(|(interval, _)| interval.start <= query.end);
+            found.extend((lo..hi.max(lo)).filter(|&index| !self.entries[index].0.is_empty()));
+        }
+        self.resolve(found)
+    }
+
+    /// Collects the indices of the entries containing the probe point,
+    /// given as a function comparing a bound point with the probe.
+    fn stab_indices<F>(&self, compare: F, found: &mut Vec<usize>)
+    where
+        F: Fn(&BoundPoint<T>) -> Ordering,
+    {
+        let mut next = self.root;
+        while let Some(index) = next {
+            let node = &self.nodes[index];
+            match compare(&self.entries[node.center].0.start) {
+                // probe below the center: the entries contain it iff they start at or below it
+                Ordering::Greater => {
+                    found.extend(
+                        node.by_start
+                            .iter()
+                            .take_while(|&&entry| compare(&self.entries[entry].0.start).is_le()),
+                    );
+                    next = node.left;
+                }
+                // probe above the center: the entries contain it iff they end at or above it
+                Ordering::Less => {
+                    found.extend(
+                        node.by_end
+                            .iter()
+                            .take_while(|&&entry| compare(&self.entries[entry].0.end).is_ge()),
+                    );
+                    next = node.right;
+                }
+                Ordering::Equal => {
+                    found.extend(&node.by_start);
+                    next = None;
+                }
+            }
+        }
+    }
+
+    fn resolve(&self, mut found: Vec<usize>) -> impl Iterator<Item = &(Interval<T>, V)> {
+        found.sort_unstable();
+        found.into_iter().map(|index| &self.entries[index])
+    }
+}
+
+impl<T: Ord, V> FromIterator<(Interval<T>, V)> for IntervalTree<T, V> {
+    fn from_iter<I: IntoIterator<Item = (Interval<T>, V)>>(iter: I) -> Self {
+        IntervalTree::build(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::Rng;
+    use rstest::rstest;
+
+    fn tree() -> IntervalTree<i32, char> {
+        IntervalTree::build(vec![
+            (Interval::from_to(1, 5, IntervalType::Close).unwrap(), 'a'),
+            (Interval::from_to(3, 5, IntervalType::Open).unwrap(), 'b'),
+            (Interval::from_to(5, 8, IntervalType::EndOpen).unwrap(), 'c'),
+            (
+                Interval::from_to(5, 8, IntervalType::StartOpen).unwrap(),
+                'd',
+            ),
+            (Interval::until_exclusive(3), 'e'),
+            (Interval::since_inclusive(8), 'f'),
+            (Interval::from_to(4, 4, IntervalType::Open).unwrap(), 'g'),
+        ])
+    }
+
+    fn values<'a>(entries: impl Iterator<Item = &'a (Interval<i32>, char)>) -> String {
+        entries.map(|(_, value)| *value).collect()
+    }
+
+    #[rstest]
+    #[case(0, "e")]
+    #[case(1, "ea")]
+    #[case(3, "a")]
+    #[case(4, "ab")]
+    #[case(5, "ac")]
+    #[case(6, "cd")]
+    #[case(8, "df")]
+    #[case(100, "f")]
+    fn test_stab(#[case] value: i32, #[case] expected: &str) {
+        assert_eq!(values(tree().stab(&value)), expected);
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(3, 3, IntervalType::Close).unwrap(), "a")]
+    #[case(Interval::from_to(2, 3, IntervalType::EndOpen).unwrap(), "ea")]
+    #[case(Interval::from_to(5, 8, IntervalType::Open).unwrap(), "cd")]
+    #[case(Interval::from_to(8, 9, IntervalType::StartOpen).unwrap(), "f")]
+    #[case(Interval::until_exclusive(1), "e")]
+    #[case(Interval::since_exclusive(5), "cdf")]
+    #[case(Interval::from_to(4, 4, IntervalType::EndOpen).unwrap(), "")]
+    fn test_query_overlapping(#[case] query: Interval<i32>, #[case] expected: &str) {
+        let mut actual: Vec<_> = tree().query_overlapping(&query).map(|(_, v)| *v).collect();
+        actual.sort();
+        let mut expected: Vec<_> = expected.chars().collect();
+        expected.sort();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let tree = IntervalTree::<i32, ()>::build(vec![]);
+        assert!(tree.is_empty());
+        assert_eq!(tree.stab(&0).count(), 0);
+        assert_eq!(
+            tree.query_overlapping(&Interval::since_inclusive(0))
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let mut rng = Rng::new(360);
+        let entries: Vec<_> = (0..5_000)
+            .map(|index| (rng.interval(-10_000, 10_000), index))
+            .collect();
+        let tree: IntervalTree<i32, usize> = entries.iter().cloned().collect();
+        assert_eq!(tree.len(), entries.len());
+
+        let sorted = |mut found: Vec<usize>| {
+            found.sort_unstable();
+            found
+        };
+        for _ in 0..300 {
+            let value = rng.range(-11_000, 11_000);
+            let expected: Vec<_> = entries
+                .iter()
+                .filter(|(interval, _)| interval.contains(value))
+                .map(|(_, index)| *index)
+                .collect();
+            let actual: Vec<_> = tree.stab(&value).map(|(_, index)| *index).collect();
+            assert_eq!(sorted(actual), expected, "failed: {}", value);
+
+            let query = rng.interval(-11_000, 11_000);
+            let expected: Vec<_> = entries
+                .iter()
+                .filter(|(interval, _)| {
+                    !interval.is_empty() && !query.is_empty() && interval.overlaps(&query)
+                })
+                .map(|(_, index)| *index)
+                .collect();
+            let actual: Vec<_> = tree
+                .query_overlapping(&query)
+                .map(|(_, index)| *index)
+                .collect();
+            assert_eq!(sorted(actual), expected, "failed: {:?}", query);
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! Intervals over IP addresses, and their conversion to and from
+//! `ipnet` CIDR blocks.
+
+use crate::bound_point::BoundPoint;
+use crate::discrete::Discrete;
+use crate::interval::Interval;
+use ipnet::{Ipv4Net, Ipv6Net};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+impl Discrete for Ipv4Addr {
+    fn succ(&self) -> Option<Self> {
+        u32::from(*self).checked_add(1).map(Ipv4Addr::from)
+    }
+
+    fn pred(&self) -> Option<Self> {
+        u32::from(*self).checked_sub(1).map(Ipv4Addr::from)
+    }
+
+    fn steps_to(&self, later: &Self) -> Option<u128> {
+        u32::from(*self).steps_to(&u32::from(*later))
+    }
+
+    fn forward(&self, steps: u128) -> Option<Self> {
+        u32::from(*self).forward(steps).map(Ipv4Addr::from)
+    }
+}
+
+impl Discrete for Ipv6Addr {
+    fn succ(&self) -> Option<Self> {
+        u128::from(*self).checked_add(1).map(Ipv6Addr::from)
+    }
+
+    fn pred(&self) -> Option<Self> {
+        u128::from(*self).checked_sub(1).map(Ipv6Addr::from)
+    }
+
+    fn steps_to(&self, later: &Self) -> Option<u128> {
+        u128::from(*self).steps_to(&u128::from(*later))
+    }
+
+    fn forward(&self, steps: u128) -> Option<Self> {
+        u128::from(*self).forward(steps).map(Ipv6Addr::from)
+    }
+}
+
+/// Converts a CIDR block into the closed interval from its network
+/// address to its broadcast address.
+impl From<Ipv4Net> for Interval<Ipv4Addr> {
+    fn from(net: Ipv4Net) -> Self {
+        Interval {
+            start: BoundPoint::at(net.network()),
+            end: BoundPoint::at(net.broadcast()),
+        }
+    }
+}
+
+/// Converts a CIDR block into the closed interval from its first
+/// to its last address.
+impl From<Ipv6Net> for Interval<Ipv6Addr> {
+    fn from(net: Ipv6Net) -> Self {
+        Interval {
+            start: BoundPoint::at(net.network()),
+            end: BoundPoint::at(net.broadcast()),
+        }
+    }
+}
+
+impl Interval<Ipv4Addr> {
+    /// Returns the fewest CIDR blocks covering exactly the addresses in
+    /// the interval, in ascending order. Unbounded sides reach the ends
+    /// of the address space.
+    pub fn to_cidrs(&self) -> Vec<Ipv4Net> {
+        let Some((first, last)) = address_range(self, Ipv4Addr::UNSPECIFIED, Ipv4Addr::BROADCAST)
+        else {
+            return Vec::new();
+        };
+        cidr_blocks(u32::from(first).into(), u32::from(last).into(), 32)
+            .map(|(network, prefix)| {
+                Ipv4Net::new(Ipv4Addr::from(network as u32), prefix).expect("prefix fits")
+            })
+            .collect()
+    }
+}
+
+impl Interval<Ipv6Addr> {
+    /// Returns the fewest CIDR blocks covering exactly the addresses in
+    /// the interval, in ascending order. Unbounded sides reach the ends
+    /// of the address space.
+    pub fn to_cidrs(&self) -> Vec<Ipv6Net> {
+        let Some((first, last)) =
+            address_range(self, Ipv6Addr::UNSPECIFIED, Ipv6Addr::from(u128::MAX))
+        else {
+            return Vec::new();
+        };
+        cidr_blocks(first.into(), last.into(), 128)
+            .map(|(network, prefix)| {
+                Ipv6Net::new(Ipv6Addr::from(network), prefix).expect("prefix fits")
+            })
+            .collect()
+    }
+}
+
+/// Returns the first and last address of `interval`, taking unbounded
+/// sides to the ends of the address space, or `None` if it is empty.
+fn address_range<T: Discrete + Clone>(
+    interval: &Interval<T>,
+    lowest: T,
+    highest: T,
+) -> Option<(T, T)> {
+    let clipped = Interval {
+        start: std::cmp::max(interval.start.clone(), BoundPoint::at(lowest)),
+        end: std::cmp::min(interval.end.clone(), BoundPoint::at(highest)),
+    };
+    Some((clipped.first_value()?, clipped.last_value()?))
+}
+
+/// Splits `first..=last` into maximal aligned blocks of `2^k` addresses,
+/// each returned as its first address and prefix length.
+fn cidr_blocks(mut first: u128, last: u128, width: u32) -> impl Iterator<Item = (u128, u8)> {
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let span = last - first;
+        // the largest block fitting in the span, and the largest one
+        // aligned at `first`
+        let fitting = if span == u128::MAX {
+            128
+        } else {
+            127 - (span + 1).leading_zeros()
+        };
+        let aligned = first.trailing_zeros().min(width);
+        let bits = fitting.min(aligned);
+        let block = (first, (width - bits) as u8);
+        if bits == 128 || span == (1u128 << bits) - 1 {
+            done = true;
+        } else {
+            first += 1u128 << bits;
+        }
+        Some(block)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::interval_set::IntervalSet;
+    use rstest::rstest;
+
+    fn v4(text: &str) -> Ipv4Addr {
+        text.parse().unwrap()
+    }
+
+    fn nets(texts: &[&str]) -> Vec<Ipv4Net> {
+        texts.iter().map(|text| text.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_from_cidr() {
+        let block: Interval<Ipv4Addr> = "10.0.0.0/24".parse::<Ipv4Net>().unwrap().into();
+        assert_eq!(
+            block,
+            Interval::from_to(v4("10.0.0.0"), v4("10.0.0.255"), IntervalType::Close).unwrap()
+        );
+        assert_eq!(block.count_values(), Some(256));
+        let single: Interval<Ipv4Addr> = "10.0.0.7/32".parse::<Ipv4Net>().unwrap().into();
+        assert_eq!(single.count_values(), Some(1));
+        assert_eq!(single.to_cidrs(), nets(&["10.0.0.7/32"]));
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(v4("10.0.0.0"), v4("10.0.0.255"), IntervalType::Close).unwrap(), &["10.0.0.0/24"])]
+    #[case(Interval::from_to(v4("10.0.0.1"), v4("10.0.0.6"), IntervalType::Close).unwrap(),
+           &["10.0.0.1/32", "10.0.0.2/31", "10.0.0.4/31", "10.0.0.6/32"])]
+    #[case(Interval::from_to(v4("10.0.0.0"), v4("10.0.1.0"), IntervalType::Open).unwrap(),
+           &["10.0.0.1/32", "10.0.0.2/31", "10.0.0.4/30", "10.0.0.8/29", "10.0.0.16/28", "10.0.0.32/27", "10.0.0.64/26", "10.0.0.128/25"])]
+    #[case(Interval::from_to(v4("255.255.255.254"), v4("255.255.255.255"), IntervalType::Close).unwrap(), &["255.255.255.254/31"])]
+    #[case(Interval::since_inclusive(v4("255.255.255.255")), &["255.255.255.255/32"])]
+    #[case(Interval::since_inclusive(v4("128.0.0.0")), &["128.0.0.0/1"])]
+    #[case(Interval::until_exclusive(v4("0.0.0.1")), &["0.0.0.0/32"])]
+    #[case(Interval::since_exclusive(v4("255.255.255.255")), &[])]
+    #[case(Interval { start: BoundPoint::neg_infinity(), end: BoundPoint::pos_infinity() }, &["0.0.0.0/0"])]
+    fn test_to_cidrs(#[case] interval: Interval<Ipv4Addr>, #[case] expected: &[&str]) {
+        assert_eq!(interval.to_cidrs(), nets(expected));
+    }
+
+    #[test]
+    fn test_v6_full_range() {
+        let all: Interval<Ipv6Addr> = "::/0".parse::<Ipv6Net>().unwrap().into();
+        assert_eq!(all.to_cidrs(), vec!["::/0".parse::<Ipv6Net>().unwrap()]);
+        // 2^128 addresses do not fit in a u128
+        assert_eq!(all.count_values(), None);
+        let half: Interval<Ipv6Addr> = "::/1".parse::<Ipv6Net>().unwrap().into();
+        assert_eq!(half.count_values(), Some(1 << 127));
+    }
+
+    #[test]
+    fn test_v6_to_cidrs() {
+        let interval = Interval::from_to(
+            "2001:db8::1".parse::<Ipv6Addr>().unwrap(),
+            "2001:db8::4".parse().unwrap(),
+            IntervalType::Close,
+        )
+        .unwrap();
+        let expected: Vec<Ipv6Net> = ["2001:db8::1/128", "2001:db8::2/127", "2001:db8::4/128"]
+            .iter()
+            .map(|text| text.parse().unwrap())
+            .collect();
+        assert_eq!(interval.to_cidrs(), expected);
+    }
+
+    #[test]
+    fn test_allowlist_merging() {
+        let allowlist: IntervalSet<Ipv4Addr> = nets(&[
+            "10.0.0.0/25",
+            "10.0.0.128/25",
+            "10.0.2.0/24",
+            "255.255.255.0/24",
+        ])
+        .into_iter()
+        .filter_map(|net| Interval::from(net).canonicalize())
+        .collect();
+        let merged: Vec<_> = allowlist
+            .iter()
+            .flat_map(|member| member.to_cidrs())
+            .collect();
+        assert_eq!(
+            merged,
+            nets(&["10.0.0.0/24", "10.0.2.0/24", "255.255.255.0/24"])
+        );
+        assert!(allowlist.contains(&v4("10.0.0.200")));
+        assert!(!allowlist.contains(&v4("10.0.1.1")));
+        assert!(allowlist.contains(&v4("255.255.255.255")));
+    }
+
+    #[test]
+    fn test_to_cidrs_matches_brute_force() {
+        for start in 0u32..40 {
+            for end in start..40 {
+                let interval = Interval::from_to(
+                    Ipv4Addr::from(start),
+                    Ipv4Addr::from(end),
+                    IntervalType::Close,
+                )
+                .unwrap();
+                let blocks = interval.to_cidrs();
+                let covered: Vec<u32> = blocks
+                    .iter()
+                    .flat_map(|net| u32::from(net.network())..=u32::from(net.broadcast()))
+                    .collect();
+                assert_eq!(covered, (start..=end).collect::<Vec<_>>());
+            }
+        }
+    }
+}
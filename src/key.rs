@@ -0,0 +1,150 @@
+//! Intervals over types ordered by a key or in reverse.
+//!
+//! Intervals need `T: Ord`, so a record type ordered only by one of its
+//! fields is handled through an interval over that field, queried with
+//! the field of each record. Intervals in descending order are intervals
+//! over [`Reverse<T>`], which every algorithm of the crate supports like
+//! any other `Ord` type.
+
+use crate::interval::Interval;
+use std::cmp::Reverse;
+
+impl<T: Ord> Interval<T> {
+    /// Applies `f` to both bound values, keeping the inclusiveness.
+    ///
+    /// `f` must preserve order, as a projection from a key type to a
+    /// wider one does; an order-reversing `f` gives a start above the
+    /// end. Use [`Interval::into_reversed`] to flip the order.
+    pub fn map_key<K: Ord>(self, f: impl Fn(T) -> K) -> Interval<K> {
+        Interval {
+            start: self.start.map(&f),
+            end: self.end.map(&f),
+        }
+    }
+
+    /// Returns the same values as an interval over [`Reverse<T>`],
+    /// whose start is the original end.
+    pub fn into_reversed(self) -> Interval<Reverse<T>> {
+        Interval {
+            start: self.end.reflect(Reverse),
+            end: self.start.reflect(Reverse),
+        }
+    }
+}
+
+impl<T: Ord> Interval<Reverse<T>> {
+    /// Undoes [`Interval::into_reversed`].
+    pub fn into_unreversed(self) -> Interval<T> {
+        Interval {
+            start: self.end.reflect(|Reverse(value)| value),
+            end: self.start.reflect(|Reverse(value)| value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::interval_set::IntervalSet;
+    use rstest::rstest;
+
+    #[derive(Debug, PartialEq)]
+    struct Record {
+        name: &'static str,
+        timestamp: u64,
+    }
+
+    #[test]
+    fn test_records_by_key() {
+        let records = [
+            Record {
+                name: "a",
+                timestamp: 5,
+            },
+            Record {
+                name: "b",
+                timestamp: 10,
+            },
+            Record {
+                name: "c",
+                timestamp: 15,
+            },
+            Record {
+                name: "d",
+                timestamp: 20,
+            },
+        ];
+        let window = Interval::from_to(10, 20, IntervalType::EndOpen).unwrap();
+        let names: Vec<_> = records
+            .iter()
+            .filter(|record| window.contains(record.timestamp))
+            .map(|record| record.name)
+            .collect();
+        assert_eq!(names, ["b", "c"]);
+    }
+
+    #[test]
+    fn test_map_key() {
+        let interval = Interval::from_to(1u32, 5, IntervalType::StartOpen).unwrap();
+        assert_eq!(
+            interval.map_key(|value| value as u64 * 1000),
+            Interval::from_to(1000u64, 5000, IntervalType::StartOpen).unwrap()
+        );
+        assert_eq!(
+            Interval::since_inclusive(3u8).map_key(i32::from),
+            Interval::since_inclusive(3)
+        );
+    }
+
+    #[rstest]
+    #[case(IntervalType::Close, IntervalType::Close)]
+    #[case(IntervalType::Open, IntervalType::Open)]
+    #[case(IntervalType::StartOpen, IntervalType::EndOpen)]
+    #[case(IntervalType::EndOpen, IntervalType::StartOpen)]
+    fn test_into_reversed(
+        #[case] interval_type: IntervalType,
+        #[case] reversed_type: IntervalType,
+    ) {
+        let interval = Interval::from_to(1, 5, interval_type).unwrap();
+        let reversed = interval.into_reversed();
+        assert_eq!(
+            reversed,
+            Interval::from_to(Reverse(5), Reverse(1), reversed_type).unwrap()
+        );
+        for value in 0..=6 {
+            assert_eq!(reversed.contains(Reverse(value)), interval.contains(value));
+        }
+        assert_eq!(reversed.into_unreversed(), interval);
+    }
+
+    #[test]
+    fn test_into_reversed_unbounded() {
+        assert_eq!(
+            Interval::since_exclusive(3).into_reversed(),
+            Interval::until_exclusive(Reverse(3))
+        );
+    }
+
+    #[test]
+    fn test_reversed_set() {
+        let set: IntervalSet<Reverse<i32>> = [
+            Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(3, 5, IntervalType::Close).unwrap(),
+            Interval::from_to(8, 9, IntervalType::Open).unwrap(),
+        ]
+        .into_iter()
+        .map(Interval::into_reversed)
+        .collect();
+        let members: Vec<_> = set.iter().cloned().map(Interval::into_unreversed).collect();
+        assert_eq!(
+            members,
+            [
+                Interval::from_to(8, 9, IntervalType::Open).unwrap(),
+                Interval::from_to(1, 5, IntervalType::Close).unwrap(),
+            ]
+        );
+        assert!(set.contains(&Reverse(3)));
+        assert!(!set.contains(&Reverse(6)));
+    }
+}
@@ -1,4 +1,78 @@
 mod interval;
 mod bound_point;
+mod interval_set;
+mod interval_map;
+mod interval_tree;
+mod interval_index;
+mod cyclic;
+mod discrete;
+mod integer;
+mod saturating;
+mod key;
+mod range;
+mod bounding_box;
+mod relation;
+mod constraint;
+mod weights;
+mod arithmetic;
+mod ops;
+mod format;
+mod pg_range;
+mod snap;
+mod algorithms;
+#[cfg(feature = "ordered-float")]
+mod float;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod temporal;
+#[cfg(feature = "ipnet")]
+mod ip;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "semver")]
+mod semver_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(test)]
+mod test_support;
 
 pub use interval::{Interval, IntervalType, IntervalError};
+pub use relation::CoarseRelation;
+pub use constraint::Constraint;
+pub use arithmetic::DivisionError;
+pub use bound_point::{seam, BoundPoint, BoundProximity, BoundValue, Seam};
+pub use interval_map::{IntervalMap, OverlapError};
+pub use interval_tree::IntervalTree;
+pub use interval_index::IntervalIndex;
+pub use bounding_box::BoundingBox;
+pub use cyclic::{CyclicInterval, OutsideDomainError};
+pub use discrete::{Discrete, SampleError, Values};
+pub use integer::Integer;
+pub use interval_set::{
+    DisplayWith, IntervalSet, IntervalSetExportError, InvalidDurationError, ParseIntervalSetError,
+    SetDiff, SetPosition, SharedIntervalSet,
+};
+pub use format::{IntervalFormat, ParseIntervalError};
+pub use pg_range::ParsePgRangeError;
+pub use algorithms::{
+    bins_from_breakpoints, cluster_overlapping, coverage_at, coverage_profile, decompose,
+    dedup_structural, free_slots, hull_of, intersection_of, is_sorted_and_disjoint,
+    max_disjoint_subset, max_disjoint_subset_with, max_overlap, merge_intervals,
+    min_piercing_points, min_piercing_points_discrete, overlap_join,
+    overlap_join_with_intersection, recurring, sort_intervals, weighted_max_disjoint, Binner,
+    CoverageIndex, InvalidBreakpointsError, InvalidPeriodError, PiercingError,
+    RecurrenceEnd,
+};
+#[cfg(feature = "ordered-float")]
+pub use float::{FloatInterval, FloatIntervalError, FloatIntervalSet, RoundToIntError};
+#[cfg(feature = "ordered-float")]
+pub use ordered_float::NotNan;
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use temporal::TemporalPoint;
+#[cfg(feature = "chrono")]
+pub use temporal::{parse_iso8601, ParseIso8601Error};
+#[cfg(feature = "rayon")]
+pub use parallel::par_classify;
+#[cfg(feature = "semver")]
+pub use semver_impl::VersionReqError;
+#[cfg(feature = "serde")]
+pub use serde_impl::strict as serde_strict;
@@ -0,0 +1,398 @@
+//! Operator shorthands for the named interval and set operations.
+//!
+//! Between two plain intervals, `a & b` is [`Interval::intersection`],
+//! returning `None` when they share nothing, and `a | b` is
+//! [`Interval::hull`]: an interval cannot hold two disjoint pieces, so
+//! `[1, 2] | [5, 6]` is `[1, 6]`, which includes the gap between them.
+//!
+//! Between sets, and between a set and an interval, the operators are
+//! true set operations: `&` is [`IntervalSet::intersection`] and `|` is
+//! [`IntervalSet::union`], so `{[1, 2]} | [5, 6]` is `{[1, 2], [5, 6]}`.
+//!
+//! `-` removes values: [`Interval::difference`] between intervals, which
+//! returns the zero to two remaining pieces as a `Vec`, and
+//! [`IntervalSet::difference`] between sets. `!set` is
+//! [`IntervalSet::complement`] over the whole axis.
+//!
+//! `*` and `/` are interval arithmetic rather than set operations:
+//! [`Interval::product`] holds every product of a value of each side,
+//! and [`Interval::quotient`] every quotient, failing when the divisor
+//! contains zero.
+
+use crate::arithmetic::DivisionError;
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Div, Mul, Not, Sub, SubAssign};
+
+/// `a & b` is the intersection of the two intervals, or `None` when they
+/// share nothing. The bounds are moved out of the operands.
+impl<T: Ord> BitAnd for Interval<T> {
+    type Output = Option<Interval<T>>;
+
+    fn bitand(self, other: Interval<T>) -> Option<Interval<T>> {
+        self.into_intersection(other)
+    }
+}
+
+/// `&a & &b` is the intersection of the two intervals, or `None` when
+/// they share nothing.
+impl<T: Ord + Clone> BitAnd for &Interval<T> {
+    type Output = Option<Interval<T>>;
+
+    fn bitand(self, other: &Interval<T>) -> Option<Interval<T>> {
+        self.intersection(other)
+    }
+}
+
+/// `a | b` is the hull of the two intervals, which includes any gap
+/// between them.
+impl<T: Ord + Clone> BitOr for Interval<T> {
+    type Output = Interval<T>;
+
+    fn bitor(mut self, other: Interval<T>) -> Interval<T> {
+        self.hull_with(&other);
+        self
+    }
+}
+
+/// `&a | &b` is the hull of the two intervals, which includes any gap
+/// between them.
+impl<T: Ord + Clone> BitOr for &Interval<T> {
+    type Output = Interval<T>;
+
+    fn bitor(self, other: &Interval<T>) -> Interval<T> {
+        self.hull(other)
+    }
+}
+
+/// `a * b` is the interval of the products of a value of each, like
+/// [`Interval::product`].
+impl<T: Ord + Clone + Default + Mul<Output = T>> Mul for Interval<T> {
+    type Output = Interval<T>;
+
+    fn mul(self, other: Interval<T>) -> Interval<T> {
+        self.product(&other)
+    }
+}
+
+/// `&a * &b` is the interval of the products of a value of each, like
+/// [`Interval::product`].
+impl<T: Ord + Clone + Default + Mul<Output = T>> Mul for &Interval<T> {
+    type Output = Interval<T>;
+
+    fn mul(self, other: &Interval<T>) -> Interval<T> {
+        self.product(other)
+    }
+}
+
+/// `a / b` is the interval of the quotients of a value of each, like
+/// [`Interval::quotient`].
+impl<T> Div for Interval<T>
+where
+    T: Ord + Clone + Default + Mul<Output = T> + Div<Output = T> + From<u8>,
+{
+    type Output = Result<Interval<T>, DivisionError>;
+
+    fn div(self, divisor: Interval<T>) -> Result<Interval<T>, DivisionError> {
+        self.quotient(&divisor)
+    }
+}
+
+/// `&a / &b` is the interval of the quotients of a value of each, like
+/// [`Interval::quotient`].
+impl<T> Div for &Interval<T>
+where
+    T: Ord + Clone + Default + Mul<Output = T> + Div<Output = T> + From<u8>,
+{
+    type Output = Result<Interval<T>, DivisionError>;
+
+    fn div(self, divisor: &Interval<T>) -> Result<Interval<T>, DivisionError> {
+        self.quotient(divisor)
+    }
+}
+
+/// `&a & &b` is the intersection of the two sets.
+impl<T: Ord + Clone> BitAnd for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitand(self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.intersection(other)
+    }
+}
+
+/// `&a | &b` is the union of the two sets.
+impl<T: Ord + Clone> BitOr for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitor(self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.union(other)
+    }
+}
+
+/// `&set & &interval` is the part of the set within the interval, like
+/// [`IntervalSet::clipped_to`].
+impl<T: Ord + Clone> BitAnd<&Interval<T>> for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitand(self, interval: &Interval<T>) -> IntervalSet<T> {
+        self.clipped_to(interval)
+    }
+}
+
+/// `&set | &interval` is the set with the values of the interval added.
+impl<T: Ord + Clone> BitOr<&Interval<T>> for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn bitor(self, interval: &Interval<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        union.insert(interval.clone());
+        union
+    }
+}
+
+/// `a &= &b` keeps only the values also in `b`.
+impl<T: Ord + Clone> BitAndAssign<&IntervalSet<T>> for IntervalSet<T> {
+    fn bitand_assign(&mut self, other: &IntervalSet<T>) {
+        *self = self.intersection(other);
+    }
+}
+
+/// `a |= &b` adds the values of `b`.
+impl<T: Ord + Clone> BitOrAssign<&IntervalSet<T>> for IntervalSet<T> {
+    fn bitor_assign(&mut self, other: &IntervalSet<T>) {
+        *self = self.union(other);
+    }
+}
+
+/// `set &= &interval` keeps only the values within the interval.
+impl<T: Ord + Clone> BitAndAssign<&Interval<T>> for IntervalSet<T> {
+    fn bitand_assign(&mut self, interval: &Interval<T>) {
+        *self = &*self & interval;
+    }
+}
+
+/// `set |= interval` adds the values of the interval, like
+/// [`IntervalSet::insert`].
+impl<T: Ord> BitOrAssign<Interval<T>> for IntervalSet<T> {
+    fn bitor_assign(&mut self, interval: Interval<T>) {
+        self.insert(interval);
+    }
+}
+
+/// `&a - &b` is the pieces of `a` outside `b`, in order.
+impl<T: Ord + Clone> Sub for &Interval<T> {
+    type Output = Vec<Interval<T>>;
+
+    fn sub(self, other: &Interval<T>) -> Vec<Interval<T>> {
+        self.difference(other)
+    }
+}
+
+/// `&a - &b` is the values of `a` not in `b`.
+impl<T: Ord + Clone> Sub for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn sub(self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.difference(other)
+    }
+}
+
+/// `&set - &interval` is the set with the values of the interval
+/// removed.
+impl<T: Ord + Clone> Sub<&Interval<T>> for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn sub(self, interval: &Interval<T>) -> IntervalSet<T> {
+        let mut difference = self.clone();
+        difference.remove(interval);
+        difference
+    }
+}
+
+/// `a -= &b` removes the values of `b`, member by member in place.
+impl<T: Ord + Clone> SubAssign<&IntervalSet<T>> for IntervalSet<T> {
+    fn sub_assign(&mut self, other: &IntervalSet<T>) {
+        for member in other.iter() {
+            self.remove(member);
+        }
+    }
+}
+
+/// `set -= &interval` removes the values of the interval, like
+/// [`IntervalSet::remove`].
+impl<T: Ord + Clone> SubAssign<&Interval<T>> for IntervalSet<T> {
+    fn sub_assign(&mut self, interval: &Interval<T>) {
+        self.remove(interval);
+    }
+}
+
+/// `!&set` is the complement of the set over the whole axis.
+impl<T: Ord + Clone> Not for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn not(self) -> IntervalSet<T> {
+        self.complement()
+    }
+}
+
+/// `!set` is the complement of the set over the whole axis.
+impl<T: Ord + Clone> Not for IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    fn not(self) -> IntervalSet<T> {
+        self.complement()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{closed, end_open, set};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(closed(1, 5), end_open(3, 8), Some(closed(3, 5)))]
+    #[case(end_open(1, 3), closed(3, 5), None)]
+    #[case(closed(1, 2), closed(5, 6), None)]
+    fn test_interval_bitand(
+        #[case] a: Interval<i32>,
+        #[case] b: Interval<i32>,
+        #[case] expected: Option<Interval<i32>>,
+    ) {
+        assert_eq!(BitAnd::bitand(&a, &b), expected);
+        assert_eq!(a & b, expected);
+    }
+
+    #[rstest]
+    #[case(closed(1, 5), end_open(3, 8), end_open(1, 8))]
+    // disjoint intervals give their hull, gap included
+    #[case(closed(1, 2), closed(5, 6), closed(1, 6))]
+    #[case(
+        Interval::until_exclusive(0),
+        closed(5, 6),
+        Interval::until_inclusive(6)
+    )]
+    fn test_interval_bitor(
+        #[case] a: Interval<i32>,
+        #[case] b: Interval<i32>,
+        #[case] expected: Interval<i32>,
+    ) {
+        assert_eq!(BitOr::bitor(&a, &b), expected);
+        assert_eq!(a | b, expected);
+    }
+
+    #[test]
+    fn test_interval_mul() {
+        let a = closed(-2, 3);
+        let b = closed(4, 5);
+        assert_eq!(Mul::mul(&a, &b), closed(-10, 15));
+        assert_eq!(a * b, closed(-10, 15));
+    }
+
+    #[test]
+    fn test_interval_div_by_zero() {
+        let a = closed(1, 2);
+        let b = closed(-1, 1);
+        assert_eq!(Div::div(&a, &b), Err(DivisionError::DivisorContainsZero));
+        assert_eq!(a / b, Err(DivisionError::DivisorContainsZero));
+    }
+
+    #[test]
+    fn test_set_bitand() {
+        let a = set(vec![closed(1, 5), closed(8, 10)]);
+        let b = set(vec![end_open(3, 9)]);
+        let expected = set(vec![closed(3, 5), end_open(8, 9)]);
+        assert_eq!(&a & &b, expected);
+        let mut assigned = a.clone();
+        assigned &= &b;
+        assert_eq!(assigned, expected);
+    }
+
+    #[test]
+    fn test_set_bitor() {
+        let a = set(vec![closed(1, 2)]);
+        let b = set(vec![closed(5, 6)]);
+        // unlike plain intervals, sets keep the gap out
+        let expected = set(vec![closed(1, 2), closed(5, 6)]);
+        assert_eq!(&a | &b, expected);
+        let mut assigned = a.clone();
+        assigned |= &b;
+        assert_eq!(assigned, expected);
+    }
+
+    #[test]
+    fn test_set_bitand_interval() {
+        let a = set(vec![closed(1, 5), closed(8, 10)]);
+        let expected = set(vec![
+            Interval::from_to(4, 5, IntervalType::StartOpen).unwrap(),
+            closed(8, 9),
+        ]);
+        let window = Interval::from_to(4, 9, IntervalType::StartOpen).unwrap();
+        assert_eq!(&a & &window, expected);
+        let mut assigned = a.clone();
+        assigned &= &window;
+        assert_eq!(assigned, expected);
+    }
+
+    #[test]
+    fn test_set_bitor_interval() {
+        let a = set(vec![end_open(1, 3), closed(8, 10)]);
+        let expected = set(vec![closed(1, 5), closed(8, 10)]);
+        assert_eq!(&a | &closed(3, 5), expected);
+        let mut assigned = a.clone();
+        assigned |= closed(3, 5);
+        assert_eq!(assigned, expected);
+    }
+
+    #[rstest]
+    #[case(closed(0, 10), closed(3, 5), vec![end_open(0, 3), Interval::from_to(5, 10, IntervalType::StartOpen).unwrap()])]
+    #[case(closed(0, 10), end_open(0, 5), vec![closed(5, 10)])]
+    #[case(closed(0, 10), Interval::since_exclusive(5), vec![closed(0, 5)])]
+    #[case(closed(0, 10), closed(20, 30), vec![closed(0, 10)])]
+    #[case(closed(0, 10), Interval::until_inclusive(10), vec![])]
+    fn test_interval_sub(
+        #[case] a: Interval<i32>,
+        #[case] b: Interval<i32>,
+        #[case] expected: Vec<Interval<i32>>,
+    ) {
+        assert_eq!(Sub::sub(&a, &b), expected);
+    }
+
+    #[test]
+    fn test_set_sub() {
+        let a = set(vec![closed(1, 5), closed(8, 10)]);
+        let b = set(vec![end_open(3, 9)]);
+        let expected = set(vec![end_open(1, 3), closed(9, 10)]);
+        assert_eq!(&a - &b, expected);
+        let mut assigned = a.clone();
+        assigned -= &b;
+        assert_eq!(assigned, expected);
+        assert_eq!(&a - &end_open(3, 9), expected);
+        let mut assigned = a.clone();
+        assigned -= &end_open(3, 9);
+        assert_eq!(assigned, expected);
+    }
+
+    #[rstest]
+    #[case(set(vec![]))]
+    #[case(set(vec![closed(1, 5), end_open(8, 10)]))]
+    #[case(set(vec![Interval::until_exclusive(0), closed(3, 4), Interval::since_exclusive(8)]))]
+    #[case(set(vec![Interval::since_inclusive(0)]))]
+    fn test_set_sub_self_and_double_not(#[case] a: IntervalSet<i32>) {
+        let same = a.clone();
+        assert!((&a - &same).is_empty());
+        let mut assigned = a.clone();
+        assigned -= &a;
+        assert!(assigned.is_empty());
+        assert_eq!(!!&a, a);
+        assert_eq!(!(!a.clone()), a);
+        assert!((&a & &!&a).is_empty());
+    }
+
+    #[test]
+    fn test_set_not() {
+        let a = set(vec![Interval::until_exclusive(0), closed(3, 4)]);
+        assert_eq!(!&a, set(vec![end_open(0, 3), Interval::since_exclusive(4)]));
+    }
+}
@@ -0,0 +1,69 @@
+//! Bulk queries spread across the rayon thread pool.
+//!
+//! Each helper splits the point slice into chunks and answers every chunk
+//! on its own worker against the shared, immutable structure. The output
+//! is identical to the sequential equivalent.
+
+use crate::interval_index::IntervalIndex;
+use crate::interval_set::IntervalSet;
+use rayon::prelude::*;
+
+/// Points answered by one task; small enough to balance the load,
+/// large enough to amortize the scheduling.
+const CHUNK_LEN: usize = 4096;
+
+/// Parallel version of [`IntervalIndex::classify`]: for each of `points`,
+/// the position of the first indexed interval containing it.
+pub fn par_classify<T: Ord + Sync>(index: &IntervalIndex<T>, points: &[T]) -> Vec<Option<usize>> {
+    let mut classes = vec![None; points.len()];
+    classes
+        .par_chunks_mut(CHUNK_LEN)
+        .zip(points.par_chunks(CHUNK_LEN))
+        .for_each(|(classes, points)| {
+            for (class, point) in classes.iter_mut().zip(points) {
+                *class = index.first_containing(point);
+            }
+        });
+    classes
+}
+
+impl<T: Ord + Sync> IntervalSet<T> {
+    /// Parallel version of [`IntervalSet::contains_many`].
+    pub fn par_contains_many(&self, points: &[T]) -> Vec<bool> {
+        let mut contained = vec![false; points.len()];
+        contained
+            .par_chunks_mut(CHUNK_LEN)
+            .zip(points.par_chunks(CHUNK_LEN))
+            .for_each(|(contained, points)| {
+                for (contained, point) in contained.iter_mut().zip(points) {
+                    *contained = self.contains(point);
+                }
+            });
+        contained
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Rng;
+
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let mut rng = Rng::new(367);
+        for _ in 0..5 {
+            let intervals: Vec<_> = (0..rng.range(0, 1000))
+                .map(|_| rng.interval(-10_000, 10_000))
+                .collect();
+            let points: Vec<_> = (0..rng.range(0, 50_000))
+                .map(|_| rng.range(-11_000, 11_000))
+                .collect();
+
+            let index = IntervalIndex::new(intervals.clone());
+            assert_eq!(par_classify(&index, &points), index.classify(&points));
+
+            let set: IntervalSet<_> = intervals.into_iter().collect();
+            assert_eq!(set.par_contains_many(&points), set.contains_many(&points));
+        }
+    }
+}
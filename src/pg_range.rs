@@ -0,0 +1,314 @@
+//! The text format of PostgreSQL range types, such as `[2024-01-01,2024-02-01)`,
+//! `(,5]` or `empty`.
+//!
+//! An omitted bound is unbounded, whatever its bracket. Bound values may
+//! be double-quoted, with `""` or `\"` for a quote inside; outside quotes,
+//! a backslash takes the next character literally. Whitespace around the
+//! range is ignored, while whitespace inside the brackets belongs to the
+//! bound values, as in PostgreSQL.
+
+use crate::bound_point::{BoundPoint, BoundProximity};
+use crate::interval::{Interval, IntervalError};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Error returned by [`Interval::from_pg_range_str`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsePgRangeError {
+    /// The text does not start with `[` or `(`.
+    MissingOpeningBracket,
+
+    /// The text does not end with `]` or `)`.
+    MissingClosingBracket,
+
+    /// The bounds are not separated by a comma.
+    MissingSeparator,
+
+    /// A quoted bound value is not closed.
+    UnterminatedQuote,
+
+    /// Text follows the upper bound value before the closing bracket.
+    TrailingCharacters,
+
+    /// A bound could not be parsed as a value; holds the bound text.
+    InvalidValue(String),
+
+    /// The lower bound is greater than the upper bound.
+    Invalid(IntervalError),
+}
+
+impl Display for ParsePgRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePgRangeError::MissingOpeningBracket => {
+                write!(f, "range must start with '[' or '('")
+            }
+            ParsePgRangeError::MissingClosingBracket => write!(f, "range must end with ']' or ')'"),
+            ParsePgRangeError::MissingSeparator => {
+                write!(f, "range bounds must be separated by ','")
+            }
+            ParsePgRangeError::UnterminatedQuote => write!(f, "unterminated quoted bound value"),
+            ParsePgRangeError::TrailingCharacters => {
+                write!(f, "unexpected characters after the upper bound")
+            }
+            ParsePgRangeError::InvalidValue(value) => write!(f, "invalid bound value '{}'", value),
+            ParsePgRangeError::Invalid(error) => write!(f, "invalid range: {:?}", error),
+        }
+    }
+}
+
+impl std::error::Error for ParsePgRangeError {}
+
+impl<T: Ord + Display> Interval<T> {
+    /// Formats the interval as a PostgreSQL range literal.
+    ///
+    /// Unbounded sides are left empty with an exclusive bracket, values
+    /// are quoted when they contain characters with a meaning in the
+    /// format, and an empty interval is written `empty`.
+    pub fn to_pg_range_string(&self) -> String {
+        if self.is_empty() {
+            return "empty".to_string();
+        }
+        let mut text = String::new();
+        match (self.start.finite_value(), self.start.proximity()) {
+            (Some(value), Some(BoundProximity::After)) => {
+                text.push('(');
+                write_value(&mut text, value);
+            }
+            (Some(value), _) => {
+                text.push('[');
+                write_value(&mut text, value);
+            }
+            (None, _) => text.push('('),
+        }
+        text.push(',');
+        match (self.end.finite_value(), self.end.proximity()) {
+            (Some(value), Some(BoundProximity::Before)) => {
+                write_value(&mut text, value);
+                text.push(')');
+            }
+            (Some(value), _) => {
+                write_value(&mut text, value);
+                text.push(']');
+            }
+            (None, _) => text.push(')'),
+        }
+        text
+    }
+}
+
+impl<T: Ord + FromStr> Interval<T> {
+    /// Parses a PostgreSQL range literal, returning `None` for `empty`
+    /// and for ranges PostgreSQL reads as empty, such as `[3,3)`.
+    pub fn from_pg_range_str(s: &str) -> Result<Option<Interval<T>>, ParsePgRangeError> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("empty") {
+            return Ok(None);
+        }
+        let mut chars = s.chars();
+        let lower_inclusive = match chars.next() {
+            Some('[') => true,
+            Some('(') => false,
+            _ => return Err(ParsePgRangeError::MissingOpeningBracket),
+        };
+        let upper_inclusive = match chars.next_back() {
+            Some(']') => true,
+            Some(')') => false,
+            _ => return Err(ParsePgRangeError::MissingClosingBracket),
+        };
+        let inner = chars.as_str();
+
+        let (lower, rest) = read_bound(inner, true)?;
+        let rest = rest
+            .strip_prefix(',')
+            .ok_or(ParsePgRangeError::MissingSeparator)?;
+        let (upper, rest) = read_bound(rest, false)?;
+        if !rest.is_empty() {
+            return Err(ParsePgRangeError::TrailingCharacters);
+        }
+
+        let start = match lower {
+            None => BoundPoint::neg_infinity(),
+            Some(value) if lower_inclusive => BoundPoint::at(parse_value(value)?),
+            Some(value) => BoundPoint::after(parse_value(value)?),
+        };
+        let end = match upper {
+            None => BoundPoint::pos_infinity(),
+            Some(value) if upper_inclusive => BoundPoint::at(parse_value(value)?),
+            Some(value) => BoundPoint::before(parse_value(value)?),
+        };
+        if start
+            .finite_value()
+            .zip(end.finite_value())
+            .is_some_and(|(start, end)| start > end)
+        {
+            return Err(ParsePgRangeError::Invalid(
+                IntervalError::StartMustBeMinorThanEnd,
+            ));
+        }
+        let interval = Interval { start, end };
+        Ok((!interval.is_empty()).then_some(interval))
+    }
+}
+
+/// Reads one bound value up to the separator (for the lower bound) or
+/// the end of the text (for the upper bound), resolving quotes and
+/// escapes. Returns `None` for an omitted bound, and the unread rest.
+fn read_bound(text: &str, lower: bool) -> Result<(Option<String>, &str), ParsePgRangeError> {
+    let mut value = String::new();
+    let mut quoted = false;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            ',' if lower => {
+                return Ok((
+                    (!value.is_empty() || quoted).then_some(value),
+                    &text[index..],
+                ))
+            }
+            ',' => return Err(ParsePgRangeError::TrailingCharacters),
+            '\\' => match chars.next() {
+                Some((_, escaped)) => value.push(escaped),
+                None => value.push(c),
+            },
+            '"' => {
+                quoted = true;
+                loop {
+                    match chars.next() {
+                        None => return Err(ParsePgRangeError::UnterminatedQuote),
+                        Some((_, '"')) if chars.next_if(|&(_, c)| c == '"').is_some() => {
+                            value.push('"')
+                        }
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, escaped)) => value.push(escaped),
+                            None => return Err(ParsePgRangeError::UnterminatedQuote),
+                        },
+                        Some((_, c)) => value.push(c),
+                    }
+                }
+            }
+            c => value.push(c),
+        }
+    }
+    Ok(((!value.is_empty() || quoted).then_some(value), ""))
+}
+
+fn parse_value<T: FromStr>(value: String) -> Result<T, ParsePgRangeError> {
+    value
+        .parse()
+        .map_err(|_| ParsePgRangeError::InvalidValue(value))
+}
+
+/// Writes `value`, quoted if it is empty or contains a character with a
+/// meaning in the range format.
+fn write_value<T: Display>(text: &mut String, value: &T) {
+    let value = value.to_string();
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| matches!(c, '"' | '\\' | '(' | ')' | '[' | ']' | ',') || c.is_whitespace());
+    if !needs_quotes {
+        text.push_str(&value);
+        return;
+    }
+    text.push('"');
+    for c in value.chars() {
+        if matches!(c, '"' | '\\') {
+            text.push('\\');
+        }
+        text.push(c);
+    }
+    text.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use rstest::rstest;
+
+    fn full() -> Interval<i32> {
+        Interval {
+            start: BoundPoint::neg_infinity(),
+            end: BoundPoint::pos_infinity(),
+        }
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(3, 7, IntervalType::EndOpen).unwrap(), "[3,7)")]
+    #[case(Interval::from_to(3, 7, IntervalType::Open).unwrap(), "(3,7)")]
+    #[case(Interval::from_to(4, 4, IntervalType::Close).unwrap(), "[4,4]")]
+    #[case(Interval::from_to(-5, 5, IntervalType::StartOpen).unwrap(), "(-5,5]")]
+    #[case(Interval::until_inclusive(5), "(,5]")]
+    #[case(Interval::since_inclusive(3), "[3,)")]
+    #[case(full(), "(,)")]
+    fn test_round_trip(#[case] interval: Interval<i32>, #[case] text: &str) {
+        assert_eq!(interval.to_pg_range_string(), text);
+        assert_eq!(Interval::from_pg_range_str(text), Ok(Some(interval)));
+    }
+
+    #[rstest]
+    #[case("empty")]
+    #[case(" EMPTY ")]
+    #[case("[4,4)")]
+    #[case("(4,4]")]
+    #[case("(4,4)")]
+    fn test_empty(#[case] text: &str) {
+        assert_eq!(Interval::<i32>::from_pg_range_str(text), Ok(None));
+    }
+
+    #[test]
+    fn test_empty_interval_formats_as_empty() {
+        let empty = Interval::from_to(4, 4, IntervalType::EndOpen).unwrap();
+        assert_eq!(empty.to_pg_range_string(), "empty");
+    }
+
+    #[rstest]
+    // the bracket of an omitted bound does not matter
+    #[case("[,5]", Interval::until_inclusive(5))]
+    #[case("[3,]", Interval::since_inclusive(3))]
+    #[case("  [3,7)  ", Interval::from_to(3, 7, IntervalType::EndOpen).unwrap())]
+    #[case(r#"["3","7")"#, Interval::from_to(3, 7, IntervalType::EndOpen).unwrap())]
+    #[case(r#"[\3,7)"#, Interval::from_to(3, 7, IntervalType::EndOpen).unwrap())]
+    fn test_parse(#[case] text: &str, #[case] expected: Interval<i32>) {
+        assert_eq!(Interval::from_pg_range_str(text), Ok(Some(expected)));
+    }
+
+    #[rstest]
+    #[case(Interval::from_to("a b".to_string(), "c".to_string(), IntervalType::EndOpen).unwrap(), r#"["a b",c)"#)]
+    #[case(Interval::from_to("".to_string(), "x,y".to_string(), IntervalType::Close).unwrap(), r#"["","x,y"]"#)]
+    #[case(Interval::from_to(r#"a "q""#.to_string(), r#"b\z"#.to_string(), IntervalType::Open).unwrap(), r#"("a \"q\"","b\\z")"#)]
+    fn test_quoted_values(#[case] interval: Interval<String>, #[case] text: &str) {
+        assert_eq!(interval.to_pg_range_string(), text);
+        assert_eq!(Interval::from_pg_range_str(text), Ok(Some(interval)));
+    }
+
+    #[test]
+    fn test_doubled_quotes() {
+        assert_eq!(
+            Interval::from_pg_range_str(r#"["a""b",c]"#),
+            Ok(Some(
+                Interval::from_to(r#"a"b"#.to_string(), "c".to_string(), IntervalType::Close)
+                    .unwrap()
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case("", ParsePgRangeError::MissingOpeningBracket)]
+    #[case("3,7)", ParsePgRangeError::MissingOpeningBracket)]
+    #[case("[3,7", ParsePgRangeError::MissingClosingBracket)]
+    #[case("[3 7)", ParsePgRangeError::MissingSeparator)]
+    #[case("[3,7,9)", ParsePgRangeError::TrailingCharacters)]
+    #[case(r#"["3,7)"#, ParsePgRangeError::UnterminatedQuote)]
+    #[case("[ 3,7)", ParsePgRangeError::InvalidValue(" 3".to_string()))]
+    #[case("[a,7)", ParsePgRangeError::InvalidValue("a".to_string()))]
+    #[case(
+        "[7,3)",
+        ParsePgRangeError::Invalid(IntervalError::StartMustBeMinorThanEnd)
+    )]
+    fn test_parse_errors(#[case] text: &str, #[case] expected: ParsePgRangeError) {
+        assert_eq!(Interval::<i32>::from_pg_range_str(text), Err(expected));
+    }
+}
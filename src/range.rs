@@ -0,0 +1,272 @@
+//! Comparisons between intervals and the std range types, and
+//! conversions to and from `(start, end)` tuples read as closed intervals.
+//!
+//! The comparison is structural: an interval equals a range when it has
+//! the same bound values with the same inclusiveness, so `[1, 3]` equals
+//! `1..=3` but not `1..4`, even though both hold the same integers.
+
+use crate::bound_point::{BoundPoint, BoundProximity};
+use crate::interval::{Interval, IntervalError, IntervalType};
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// `start..end` is `[start, end)`.
+impl<T: Ord> PartialEq<Range<T>> for Interval<T> {
+    fn eq(&self, range: &Range<T>) -> bool {
+        self.as_ref()
+            == Interval {
+                start: BoundPoint::at(&range.start),
+                end: BoundPoint::before(&range.end),
+            }
+    }
+}
+
+/// `start..=end` is `[start, end]`, whether or not the range has been
+/// iterated.
+impl<T: Ord> PartialEq<RangeInclusive<T>> for Interval<T> {
+    fn eq(&self, range: &RangeInclusive<T>) -> bool {
+        self.as_ref()
+            == Interval {
+                start: BoundPoint::at(range.start()),
+                end: BoundPoint::at(range.end()),
+            }
+    }
+}
+
+/// `start..` is `[start, +inf)`.
+impl<T: Ord> PartialEq<RangeFrom<T>> for Interval<T> {
+    fn eq(&self, range: &RangeFrom<T>) -> bool {
+        self.as_ref()
+            == Interval {
+                start: BoundPoint::at(&range.start),
+                end: BoundPoint::pos_infinity(),
+            }
+    }
+}
+
+/// `..end` is `(-inf, end)`.
+impl<T: Ord> PartialEq<RangeTo<T>> for Interval<T> {
+    fn eq(&self, range: &RangeTo<T>) -> bool {
+        self.as_ref()
+            == Interval {
+                start: BoundPoint::neg_infinity(),
+                end: BoundPoint::before(&range.end),
+            }
+    }
+}
+
+/// `..=end` is `(-inf, end]`.
+impl<T: Ord> PartialEq<RangeToInclusive<T>> for Interval<T> {
+    fn eq(&self, range: &RangeToInclusive<T>) -> bool {
+        self.as_ref()
+            == Interval {
+                start: BoundPoint::neg_infinity(),
+                end: BoundPoint::at(&range.end),
+            }
+    }
+}
+
+/// `..` is `(-inf, +inf)`.
+impl<T: Ord> PartialEq<RangeFull> for Interval<T> {
+    fn eq(&self, _: &RangeFull) -> bool {
+        self.as_ref()
+            == Interval {
+                start: BoundPoint::neg_infinity(),
+                end: BoundPoint::pos_infinity(),
+            }
+    }
+}
+
+/// `(start, end)` is `[start, end]`.
+impl<T: Ord> PartialEq<(T, T)> for Interval<T> {
+    fn eq(&self, (start, end): &(T, T)) -> bool {
+        self.as_ref()
+            == Interval {
+                start: BoundPoint::at(start),
+                end: BoundPoint::at(end),
+            }
+    }
+}
+
+/// `(start, end)` becomes `[start, end]`, failing when `start > end`.
+impl<T: Ord> TryFrom<(T, T)> for Interval<T> {
+    type Error = IntervalError;
+
+    fn try_from((start, end): (T, T)) -> Result<Self, IntervalError> {
+        Interval::from_to(start, end, IntervalType::Close)
+    }
+}
+
+impl<T: Ord + Clone> Interval<T> {
+    /// Returns the bound values as a `(start, end)` tuple when the
+    /// interval is closed, as `[1, 3]` is, and `None` when either bound
+    /// is exclusive or infinite.
+    pub fn to_tuple_closed(&self) -> Option<(T, T)> {
+        match (
+            self.start.as_ref().into_finite()?,
+            self.end.as_ref().into_finite()?,
+        ) {
+            ((start, BoundProximity::At), (end, BoundProximity::At)) => {
+                Some((start.clone(), end.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_range_eq_interval {
+    ($($range:ty),*) => {
+        $(impl<T: Ord> PartialEq<Interval<T>> for $range {
+            fn eq(&self, interval: &Interval<T>) -> bool {
+                interval == self
+            }
+        })*
+    };
+}
+
+impl_range_eq_interval!(
+    Range<T>,
+    RangeInclusive<T>,
+    RangeFrom<T>,
+    RangeTo<T>,
+    RangeToInclusive<T>,
+    RangeFull,
+    (T, T)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use rstest::rstest;
+
+    fn from_to(start: i32, end: i32, interval_type: IntervalType) -> Interval<i32> {
+        Interval::from_to(start, end, interval_type).unwrap()
+    }
+
+    #[rstest]
+    #[case(from_to(3, 7, IntervalType::EndOpen), 3..7, true)]
+    #[case(from_to(3, 7, IntervalType::Close), 3..7, false)]
+    #[case(from_to(3, 6, IntervalType::Close), 3..7, false)]
+    #[case(from_to(3, 7, IntervalType::Open), 3..7, false)]
+    #[case(Interval::since_inclusive(3), 3..7, false)]
+    fn test_eq_range(
+        #[case] interval: Interval<i32>,
+        #[case] range: Range<i32>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(interval == range, expected);
+        assert_eq!(range == interval, expected);
+    }
+
+    #[rstest]
+    #[case(from_to(3, 7, IntervalType::Close), 3..=7, true)]
+    #[case(from_to(3, 3, IntervalType::Close), 3..=3, true)]
+    #[case(from_to(3, 7, IntervalType::EndOpen), 3..=7, false)]
+    #[case(from_to(3, 8, IntervalType::EndOpen), 3..=7, false)]
+    #[case(from_to(3, 7, IntervalType::StartOpen), 3..=7, false)]
+    fn test_eq_range_inclusive(
+        #[case] interval: Interval<i32>,
+        #[case] range: RangeInclusive<i32>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(interval == range, expected);
+        assert_eq!(range == interval, expected);
+    }
+
+    #[rstest]
+    #[case(Interval::since_inclusive(3), 3.., true)]
+    #[case(Interval::since_exclusive(3), 3.., false)]
+    #[case(Interval::since_inclusive(4), 3.., false)]
+    #[case(from_to(3, i32::MAX, IntervalType::Close), 3.., false)]
+    fn test_eq_range_from(
+        #[case] interval: Interval<i32>,
+        #[case] range: RangeFrom<i32>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(interval == range, expected);
+        assert_eq!(range == interval, expected);
+    }
+
+    #[rstest]
+    #[case(Interval::until_exclusive(7), ..7, true)]
+    #[case(Interval::until_inclusive(7), ..7, false)]
+    #[case(Interval::until_inclusive(6), ..7, false)]
+    fn test_eq_range_to(
+        #[case] interval: Interval<i32>,
+        #[case] range: RangeTo<i32>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(interval == range, expected);
+        assert_eq!(range == interval, expected);
+    }
+
+    #[rstest]
+    #[case(Interval::until_inclusive(7), ..=7, true)]
+    #[case(Interval::until_exclusive(7), ..=7, false)]
+    #[case(Interval::until_exclusive(8), ..=7, false)]
+    fn test_eq_range_to_inclusive(
+        #[case] interval: Interval<i32>,
+        #[case] range: RangeToInclusive<i32>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(interval == range, expected);
+        assert_eq!(range == interval, expected);
+    }
+
+    #[rstest]
+    #[case(Interval { start: BoundPoint::neg_infinity(), end: BoundPoint::pos_infinity() }, true)]
+    #[case(Interval::since_inclusive(i32::MIN), false)]
+    #[case(Interval::until_inclusive(i32::MAX), false)]
+    fn test_eq_range_full(#[case] interval: Interval<i32>, #[case] expected: bool) {
+        assert_eq!(interval == RangeFull, expected);
+        assert_eq!(RangeFull == interval, expected);
+    }
+
+    #[rstest]
+    #[case(from_to(3, 7, IntervalType::Close), (3, 7), true)]
+    #[case(from_to(3, 3, IntervalType::Close), (3, 3), true)]
+    #[case(from_to(3, 7, IntervalType::EndOpen), (3, 7), false)]
+    #[case(from_to(3, 7, IntervalType::StartOpen), (3, 7), false)]
+    #[case(from_to(3, 8, IntervalType::Close), (3, 7), false)]
+    #[case(Interval::since_inclusive(3), (3, i32::MAX), false)]
+    fn test_eq_tuple(
+        #[case] interval: Interval<i32>,
+        #[case] tuple: (i32, i32),
+        #[case] expected: bool,
+    ) {
+        assert_eq!(interval == tuple, expected);
+        assert_eq!(tuple == interval, expected);
+    }
+
+    #[rstest]
+    #[case((3, 7), Ok(from_to(3, 7, IntervalType::Close)))]
+    #[case((3, 3), Ok(from_to(3, 3, IntervalType::Close)))]
+    #[case((7, 3), Err(IntervalError::StartMustBeMinorThanEnd))]
+    fn test_try_from_tuple(
+        #[case] tuple: (i32, i32),
+        #[case] expected: Result<Interval<i32>, IntervalError>,
+    ) {
+        assert_eq!(Interval::try_from(tuple), expected);
+        if let Ok(interval) = expected {
+            assert_eq!(interval.to_tuple_closed(), Some(tuple));
+        }
+    }
+
+    #[rstest]
+    #[case(from_to(3, 7, IntervalType::Open))]
+    #[case(from_to(3, 7, IntervalType::StartOpen))]
+    #[case(from_to(3, 7, IntervalType::EndOpen))]
+    #[case(from_to(3, 3, IntervalType::EndOpen))]
+    #[case(Interval::since_inclusive(3))]
+    #[case(Interval::until_inclusive(7))]
+    fn test_to_tuple_closed_rejects_other_bounds(#[case] interval: Interval<i32>) {
+        assert_eq!(interval.to_tuple_closed(), None);
+    }
+
+    #[test]
+    fn test_assert_eq_with_range() {
+        let interval =
+            Interval::from_to("a".to_string(), "c".to_string(), IntervalType::EndOpen).unwrap();
+        assert_eq!(interval, "a".to_string().."c".to_string());
+    }
+}
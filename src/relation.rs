@@ -0,0 +1,161 @@
+use crate::bound_point::{seam, Seam};
+use crate::interval::Interval;
+
+/// Where an interval lies relative to another, in the few cases
+/// application code usually tells apart.
+///
+/// This is a projection of Allen's thirteen interval relations, with
+/// each relation assigned to exactly one variant:
+///
+/// | Allen relation                         | variant         |
+/// |----------------------------------------|-----------------|
+/// | before, meets                          | `Before`        |
+/// | overlaps                               | `OverlapsStart` |
+/// | contains, started by, finished by      | `Contains`      |
+/// | equals                                 | `Equal`         |
+/// | during, starts, finishes               | `ContainedBy`   |
+/// | overlapped by                          | `OverlapsEnd`   |
+/// | met by, after                          | `After`         |
+///
+/// Intervals that meet at a flush seam, such as `[1, 3)` and `[3, 5]`,
+/// share no value, so they are `Before` and `After` each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoarseRelation {
+    /// Every value lies below every value of the other interval.
+    Before,
+
+    /// Starts below the other interval and ends within it, covering
+    /// its start.
+    OverlapsStart,
+
+    /// Covers the whole other interval and more.
+    Contains,
+
+    /// Has the same bounds as the other interval.
+    Equal,
+
+    /// Lies within the other interval, which covers more.
+    ContainedBy,
+
+    /// Starts within the other interval and ends above it, covering
+    /// its end.
+    OverlapsEnd,
+
+    /// Every value lies above every value of the other interval.
+    After,
+}
+
+impl<T: Ord> Interval<T> {
+    /// Classifies this interval relative to `other`, as described on
+    /// [`CoarseRelation`].
+    ///
+    /// Empty intervals are classified by their bounds like any other, so
+    /// `(3, 3)` is contained by `[1, 5]`.
+    pub fn coarse_relation(&self, other: &Interval<T>) -> CoarseRelation {
+        if seam(&self.end, &other.start) != Seam::Overlapping {
+            return CoarseRelation::Before;
+        }
+        if seam(&other.end, &self.start) != Seam::Overlapping {
+            return CoarseRelation::After;
+        }
+        match (self.start.cmp(&other.start), self.end.cmp(&other.end)) {
+            (start, end) if start.is_eq() && end.is_eq() => CoarseRelation::Equal,
+            (start, end) if start.is_le() && end.is_ge() => CoarseRelation::Contains,
+            (start, end) if start.is_ge() && end.is_le() => CoarseRelation::ContainedBy,
+            (start, _) if start.is_lt() => CoarseRelation::OverlapsStart,
+            _ => CoarseRelation::OverlapsEnd,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{closed, contains_half_step, end_open, Rng};
+    use rstest::rstest;
+
+    #[rstest]
+    // before, meets
+    #[case(closed(1, 2), closed(4, 6), CoarseRelation::Before)]
+    #[case(end_open(1, 4), closed(4, 6), CoarseRelation::Before)]
+    // overlaps
+    #[case(closed(1, 5), closed(4, 6), CoarseRelation::OverlapsStart)]
+    #[case(closed(1, 4), closed(4, 6), CoarseRelation::OverlapsStart)]
+    // contains, started by, finished by
+    #[case(closed(1, 9), closed(4, 6), CoarseRelation::Contains)]
+    #[case(closed(4, 9), closed(4, 6), CoarseRelation::Contains)]
+    #[case(closed(1, 6), end_open(4, 6), CoarseRelation::Contains)]
+    // equals
+    #[case(closed(4, 6), closed(4, 6), CoarseRelation::Equal)]
+    // during, starts, finishes
+    #[case(closed(5, 5), closed(4, 6), CoarseRelation::ContainedBy)]
+    #[case(end_open(4, 6), closed(4, 6), CoarseRelation::ContainedBy)]
+    #[case(closed(5, 6), closed(4, 6), CoarseRelation::ContainedBy)]
+    // overlapped by
+    #[case(closed(5, 9), closed(4, 6), CoarseRelation::OverlapsEnd)]
+    // met by, after
+    #[case(closed(6, 9), end_open(4, 6), CoarseRelation::After)]
+    #[case(closed(7, 9), closed(4, 6), CoarseRelation::After)]
+    // unbounded
+    #[case(
+        Interval::until_inclusive(5),
+        closed(4, 6),
+        CoarseRelation::OverlapsStart
+    )]
+    #[case(Interval::since_exclusive(6), closed(4, 6), CoarseRelation::After)]
+    #[case(Interval::since_inclusive(0), closed(4, 6), CoarseRelation::Contains)]
+    fn test_coarse_relation(
+        #[case] interval: Interval<i32>,
+        #[case] other: Interval<i32>,
+        #[case] expected: CoarseRelation,
+    ) {
+        assert_eq!(interval.coarse_relation(&other), expected);
+    }
+
+    #[test]
+    fn test_coarse_relation_matches_brute_force() {
+        let mut rng = Rng::new(418);
+        for _ in 0..2000 {
+            let a = rng.interval(-8, 8);
+            let b = rng.interval(-8, 8);
+            if a.is_empty() || b.is_empty() {
+                continue;
+            }
+            let in_a = |half_steps| contains_half_step(&a, half_steps);
+            let in_b = |half_steps| contains_half_step(&b, half_steps);
+            // whether some value of one interval lies below, or above,
+            // every value of the other
+            let extends_below = |inside: &dyn Fn(i32) -> bool, other: &dyn Fn(i32) -> bool| {
+                (-40..40).any(|x| inside(x) && (-40..=x).all(|y| !other(y)))
+            };
+            let extends_above = |inside: &dyn Fn(i32) -> bool, other: &dyn Fn(i32) -> bool| {
+                (-40..40).any(|x| inside(x) && (x..40).all(|y| !other(y)))
+            };
+            let shared = (-40..40).any(|x| in_a(x) && in_b(x));
+            let expected = match () {
+                _ if !shared && extends_below(&in_a, &in_b) => CoarseRelation::Before,
+                _ if !shared => CoarseRelation::After,
+                _ if a == b => CoarseRelation::Equal,
+                _ if !extends_below(&in_b, &in_a) && !extends_above(&in_b, &in_a) => {
+                    CoarseRelation::Contains
+                }
+                _ if !extends_below(&in_a, &in_b) && !extends_above(&in_a, &in_b) => {
+                    CoarseRelation::ContainedBy
+                }
+                _ if extends_below(&in_a, &in_b) => CoarseRelation::OverlapsStart,
+                _ => CoarseRelation::OverlapsEnd,
+            };
+            assert_eq!(a.coarse_relation(&b), expected, "{:?}, {:?}", a, b);
+            let mirrored = match expected {
+                CoarseRelation::Before => CoarseRelation::After,
+                CoarseRelation::OverlapsStart => CoarseRelation::OverlapsEnd,
+                CoarseRelation::Contains => CoarseRelation::ContainedBy,
+                CoarseRelation::Equal => CoarseRelation::Equal,
+                CoarseRelation::ContainedBy => CoarseRelation::Contains,
+                CoarseRelation::OverlapsEnd => CoarseRelation::OverlapsStart,
+                CoarseRelation::After => CoarseRelation::Before,
+            };
+            assert_eq!(b.coarse_relation(&a), mirrored, "{:?}, {:?}", b, a);
+        }
+    }
+}
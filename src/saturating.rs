@@ -0,0 +1,142 @@
+use crate::bound_point::BoundPoint;
+use crate::integer::Integer;
+use crate::interval::Interval;
+
+impl<T: Integer> Interval<T> {
+    /// Moves the start down and the end up by `amount` without overflowing.
+    ///
+    /// A bound that would pass the limit of `T` stops at it and becomes
+    /// inclusive, since the limit value itself is then covered. Infinite
+    /// bounds are left alone.
+    pub fn saturating_expand(self, amount: T) -> Interval<T> {
+        Interval {
+            start: clamp(self.start, amount, T::checked_sub, T::saturating_sub),
+            end: clamp(self.end, amount, T::checked_add, T::saturating_add),
+        }
+    }
+
+    /// Moves both bounds by `amount` without overflowing.
+    ///
+    /// A bound that would pass the limit of `T` stops at it and becomes
+    /// inclusive, so an interval pushed against a limit shrinks, possibly
+    /// down to the limit value alone.
+    pub fn saturating_shift(self, amount: T) -> Interval<T> {
+        Interval {
+            start: clamp(self.start, amount, T::checked_add, T::saturating_add),
+            end: clamp(self.end, amount, T::checked_add, T::saturating_add),
+        }
+    }
+}
+
+fn clamp<T: Integer>(
+    point: BoundPoint<T>,
+    amount: T,
+    checked: fn(T, T) -> Option<T>,
+    saturating: fn(T, T) -> T,
+) -> BoundPoint<T> {
+    let Some(&value) = point.finite_value() else {
+        return point;
+    };
+    match checked(value, amount) {
+        Some(moved) => point.map(|_| moved),
+        None => BoundPoint::at(saturating(value, amount)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use rstest::rstest;
+
+    fn interval(start: i8, end: i8, interval_type: IntervalType) -> Interval<i8> {
+        Interval::from_to(start, end, interval_type).unwrap()
+    }
+
+    #[rstest]
+    #[case(interval(-10, 10, IntervalType::Open), 5, interval(-15, 15, IntervalType::Open))]
+    // exactly reaching the limits keeps the proximities
+    #[case(interval(-118, 117, IntervalType::Open), 10, interval(i8::MIN, i8::MAX, IntervalType::Open))]
+    // one past the limits clamps to inclusive limits
+    #[case(interval(-117, 117, IntervalType::Open), 12, interval(i8::MIN, i8::MAX, IntervalType::Close))]
+    #[case(interval(-100, 118, IntervalType::Open), 10, interval(-110, i8::MAX, IntervalType::StartOpen))]
+    #[case(
+        interval(i8::MIN, i8::MAX, IntervalType::Open),
+        i8::MAX,
+        interval(i8::MIN, i8::MAX, IntervalType::Close)
+    )]
+    #[case(Interval::until_exclusive(120), 10, Interval::until_inclusive(i8::MAX))]
+    #[case(Interval::since_exclusive(0), 10, Interval::since_exclusive(-10))]
+    fn test_saturating_expand(
+        #[case] input: Interval<i8>,
+        #[case] amount: i8,
+        #[case] expected: Interval<i8>,
+    ) {
+        assert_eq!(input.saturating_expand(amount), expected);
+    }
+
+    #[rstest]
+    #[case(
+        interval(0, 10, IntervalType::EndOpen),
+        5,
+        interval(5, 15, IntervalType::EndOpen)
+    )]
+    #[case(interval(0, 10, IntervalType::EndOpen), -5, interval(-5, 5, IntervalType::EndOpen))]
+    #[case(
+        interval(100, 117, IntervalType::EndOpen),
+        10,
+        interval(110, i8::MAX, IntervalType::EndOpen)
+    )]
+    // the interval shrinks against the limit
+    #[case(
+        interval(100, 118, IntervalType::EndOpen),
+        10,
+        interval(110, i8::MAX, IntervalType::Close)
+    )]
+    #[case(
+        interval(100, 120, IntervalType::Open),
+        100,
+        interval(i8::MAX, i8::MAX, IntervalType::Close)
+    )]
+    #[case(interval(-120, -100, IntervalType::Close), -10, interval(i8::MIN, -110, IntervalType::Close))]
+    #[case(
+        Interval::since_inclusive(100),
+        100,
+        Interval::since_inclusive(i8::MAX)
+    )]
+    fn test_saturating_shift(
+        #[case] input: Interval<i8>,
+        #[case] amount: i8,
+        #[case] expected: Interval<i8>,
+    ) {
+        assert_eq!(input.saturating_shift(amount), expected);
+    }
+
+    #[test]
+    fn test_saturating_shift_matches_wide_arithmetic() {
+        let clamp = |value: i16| value.clamp(i8::MIN as i16, i8::MAX as i16) as i8;
+        for start in i8::MIN..=i8::MAX {
+            for amount in [i8::MIN, -100, -1, 0, 1, 27, i8::MAX] {
+                let end = start.saturating_add(20);
+                let shifted = interval(start, end, IntervalType::Open).saturating_shift(amount);
+                let (wide_start, wide_end) =
+                    (start as i16 + amount as i16, end as i16 + amount as i16);
+                let expected_start = match clamp(wide_start) as i16 == wide_start {
+                    true => BoundPoint::after(clamp(wide_start)),
+                    false => BoundPoint::at(clamp(wide_start)),
+                };
+                let expected_end = match clamp(wide_end) as i16 == wide_end {
+                    true => BoundPoint::before(clamp(wide_end)),
+                    false => BoundPoint::at(clamp(wide_end)),
+                };
+                assert_eq!(
+                    shifted,
+                    Interval {
+                        start: expected_start,
+                        end: expected_end
+                    }
+                );
+            }
+        }
+    }
+}
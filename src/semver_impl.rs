@@ -0,0 +1,313 @@
+//! Conversions between `semver` requirements and sets of versions.
+//!
+//! Pre-release versions are out of scope: a requirement mentioning one is
+//! rejected, and [`IntervalSet::matches`] never matches one. Without
+//! pre-release comparators `semver` excludes them as well, so the two
+//! agree on every version they both accept.
+
+use crate::bound_point::{BoundPoint, BoundProximity};
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use semver::{BuildMetadata, Comparator, Op, Version, VersionReq};
+use std::fmt::{self, Display, Formatter};
+
+/// Error returned when a [`VersionReq`] cannot be converted into
+/// an [`IntervalSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionReqError {
+    /// A comparator carries a pre-release, such as `>=1.0.0-alpha`.
+    Prerelease,
+
+    /// A comparator uses an operator this crate does not know.
+    UnknownOperator,
+}
+
+impl Display for VersionReqError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionReqError::Prerelease => {
+                write!(f, "pre-release requirements are not supported")
+            }
+            VersionReqError::UnknownOperator => write!(f, "unknown requirement operator"),
+        }
+    }
+}
+
+impl std::error::Error for VersionReqError {}
+
+/// Converts the versions matching a requirement into a set.
+///
+/// The comparators of a requirement must all hold, so the set has a
+/// single member, or none for an unsatisfiable requirement. Caret,
+/// tilde, wildcard and partial versions expand to half-open intervals:
+/// `^1.2` is `[1.2.0, 2.0.0)` and `<=1.2` is `(-inf, 1.3.0)`.
+impl TryFrom<&VersionReq> for IntervalSet<Version> {
+    type Error = VersionReqError;
+
+    fn try_from(req: &VersionReq) -> Result<Self, Self::Error> {
+        let mut matching = Some(Interval {
+            start: BoundPoint::neg_infinity(),
+            end: BoundPoint::pos_infinity(),
+        });
+        for comparator in &req.comparators {
+            let interval = comparator_interval(comparator)?;
+            matching = matching
+                .zip(interval)
+                .and_then(|(matching, interval)| matching.intersection(&interval));
+        }
+        Ok(matching.into_iter().collect())
+    }
+}
+
+impl IntervalSet<Version> {
+    /// Returns `true` if `version` lies within the set, ignoring its build
+    /// metadata as `semver` does. Pre-release versions never match.
+    pub fn matches(&self, version: &Version) -> bool {
+        if !version.pre.is_empty() {
+            return false;
+        }
+        if version.build.is_empty() {
+            return self.contains(version);
+        }
+        self.contains(&Version {
+            build: BuildMetadata::EMPTY,
+            ..version.clone()
+        })
+    }
+
+    /// Returns one requirement per member, since a requirement cannot
+    /// express a union. The full set gives `*` and the empty set nothing.
+    pub fn to_version_reqs(&self) -> Vec<VersionReq> {
+        self.iter()
+            .map(|member| {
+                let lower = match (member.start.finite_value(), member.start.proximity()) {
+                    (Some(version), Some(BoundProximity::After)) => Some((Op::Greater, version)),
+                    (Some(version), _) => Some((Op::GreaterEq, version)),
+                    (None, _) => None,
+                };
+                let upper = match (member.end.finite_value(), member.end.proximity()) {
+                    (Some(version), Some(BoundProximity::Before)) => Some((Op::Less, version)),
+                    (Some(version), _) => Some((Op::LessEq, version)),
+                    (None, _) => None,
+                };
+                let comparators = lower
+                    .into_iter()
+                    .chain(upper)
+                    .map(|(op, version)| Comparator {
+                        op,
+                        major: version.major,
+                        minor: Some(version.minor),
+                        patch: Some(version.patch),
+                        pre: version.pre.clone(),
+                    })
+                    .collect();
+                VersionReq { comparators }
+            })
+            .collect()
+    }
+}
+
+/// Returns the versions matching one comparator, following the rules of
+/// Cargo's resolver for partial versions, or `None` if none does.
+fn comparator_interval(
+    comparator: &Comparator,
+) -> Result<Option<Interval<Version>>, VersionReqError> {
+    if !comparator.pre.is_empty() {
+        return Err(VersionReqError::Prerelease);
+    }
+    let major = comparator.major;
+    let minor = comparator.minor.unwrap_or(0);
+    let lowest = Version::new(major, minor, comparator.patch.unwrap_or(0));
+    let next_major = major.checked_add(1).map(|major| Version::new(major, 0, 0));
+    let next_minor = minor
+        .checked_add(1)
+        .map(|minor| Version::new(major, minor, 0));
+    // the first version past the given components, e.g. 1.3.0 for 1.2
+    let past_given = match (comparator.minor, comparator.patch) {
+        (None, _) => next_major.clone(),
+        (Some(_), None) => next_minor.clone(),
+        (Some(_), Some(patch)) => patch
+            .checked_add(1)
+            .map(|patch| Version::new(major, minor, patch)),
+    };
+    let at = |version: Version| BoundPoint::at(version);
+    // an overflowing upper limit leaves the interval unbounded above
+    let before = |version: Option<Version>| {
+        version.map_or_else(BoundPoint::pos_infinity, BoundPoint::before)
+    };
+
+    let (start, end) = match comparator.op {
+        Op::Exact | Op::Wildcard => match comparator.patch {
+            Some(_) => (at(lowest.clone()), at(lowest)),
+            None => (at(lowest), before(past_given)),
+        },
+        Op::Greater => match comparator.patch {
+            Some(_) => (BoundPoint::after(lowest), BoundPoint::pos_infinity()),
+            // nothing lies past the largest representable version
+            None => match past_given {
+                Some(version) => (at(version), BoundPoint::pos_infinity()),
+                None => return Ok(None),
+            },
+        },
+        Op::GreaterEq => (at(lowest), BoundPoint::pos_infinity()),
+        Op::Less => (BoundPoint::neg_infinity(), BoundPoint::before(lowest)),
+        Op::LessEq => match comparator.patch {
+            Some(_) => (BoundPoint::neg_infinity(), at(lowest)),
+            None => (BoundPoint::neg_infinity(), before(past_given)),
+        },
+        Op::Tilde => match comparator.minor {
+            Some(_) => (at(lowest), before(next_minor)),
+            None => (at(lowest), before(next_major)),
+        },
+        Op::Caret => match (major, comparator.minor, comparator.patch) {
+            (0, Some(0), Some(_)) => (at(lowest), before(past_given)),
+            (0, Some(_), _) => (at(lowest), before(next_minor)),
+            _ => (at(lowest), before(next_major)),
+        },
+        _ => return Err(VersionReqError::UnknownOperator),
+    };
+    Ok(Some(Interval { start, end }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use rstest::rstest;
+
+    fn version(text: &str) -> Version {
+        Version::parse(text).unwrap()
+    }
+
+    fn set(req: &str) -> IntervalSet<Version> {
+        IntervalSet::try_from(&VersionReq::parse(req).unwrap()).unwrap()
+    }
+
+    fn range(start: &str, end: &str) -> Vec<Interval<Version>> {
+        vec![Interval::from_to(version(start), version(end), IntervalType::EndOpen).unwrap()]
+    }
+
+    #[rstest]
+    #[case(">=1.2.0, <2.0.0", range("1.2.0", "2.0.0"))]
+    #[case("^1.2.3", range("1.2.3", "2.0.0"))]
+    #[case("^1.2", range("1.2.0", "2.0.0"))]
+    #[case("^1", range("1.0.0", "2.0.0"))]
+    #[case("^0.2.3", range("0.2.3", "0.3.0"))]
+    #[case("^0.0.3", range("0.0.3", "0.0.4"))]
+    #[case("^0.0", range("0.0.0", "0.1.0"))]
+    #[case("^0", range("0.0.0", "1.0.0"))]
+    #[case("~1.2.3", range("1.2.3", "1.3.0"))]
+    #[case("~1.2", range("1.2.0", "1.3.0"))]
+    #[case("~1", range("1.0.0", "2.0.0"))]
+    #[case("1.2.*", range("1.2.0", "1.3.0"))]
+    #[case("=1.2", range("1.2.0", "1.3.0"))]
+    #[case("=1.0", range("1.0.0", "1.1.0"))]
+    #[case(">1.2", vec![Interval::since_inclusive(version("1.3.0"))])]
+    #[case(">1.2.3", vec![Interval::since_exclusive(version("1.2.3"))])]
+    #[case("<=1.2", vec![Interval::until_exclusive(version("1.3.0"))])]
+    #[case("<=1.2.3", vec![Interval::until_inclusive(version("1.2.3"))])]
+    #[case("<1.2", vec![Interval::until_exclusive(version("1.2.0"))])]
+    #[case("=1.2.3", vec![Interval::from_to(version("1.2.3"), version("1.2.3"), IntervalType::Close).unwrap()])]
+    #[case(">=2.0.0, <1.0.0", vec![])]
+    fn test_from_version_req(#[case] req: &str, #[case] expected: Vec<Interval<Version>>) {
+        assert_eq!(set(req).into_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_matches_agrees_with_semver() {
+        let reqs = [
+            "^1.2.3",
+            "~0.4",
+            ">=1.0.0, <1.5.0",
+            "<=2",
+            ">1",
+            "=0.0.7",
+            "*",
+            "1.*",
+            "^0.0",
+        ];
+        let versions = [
+            "0.0.0",
+            "0.0.7",
+            "0.0.8",
+            "0.1.0",
+            "0.4.0",
+            "0.4.9",
+            "0.5.0",
+            "1.0.0",
+            "1.2.2",
+            "1.2.3",
+            "1.4.99",
+            "1.5.0",
+            "2.0.0",
+            "2.9.9",
+            "3.0.0",
+            "1.2.3+build",
+            "2.0.0+build",
+            "1.3.0-alpha",
+        ];
+        for req in reqs {
+            let parsed = VersionReq::parse(req).unwrap();
+            let set = IntervalSet::try_from(&parsed).unwrap();
+            for text in versions {
+                let version = version(text);
+                assert_eq!(
+                    set.matches(&version),
+                    parsed.matches(&version),
+                    "{} {}",
+                    req,
+                    text
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_nothing_past_the_largest_version() {
+        assert!(set(&format!(">{}", u64::MAX)).is_empty());
+    }
+
+    #[test]
+    fn test_prerelease_requirements_are_rejected() {
+        assert_eq!(
+            IntervalSet::try_from(&VersionReq::parse(">=1.0.0-alpha").unwrap()),
+            Err(VersionReqError::Prerelease)
+        );
+    }
+
+    #[test]
+    fn test_overflowing_upper_limit_is_unbounded() {
+        assert_eq!(
+            set(&format!("^{}", u64::MAX))
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![Interval::since_inclusive(Version::new(u64::MAX, 0, 0))]
+        );
+    }
+
+    #[rstest]
+    #[case(">=1.2.0, <2.0.0")]
+    #[case(">1.2.3, <=1.9.0")]
+    #[case("<1.0.0")]
+    #[case("*")]
+    fn test_to_version_reqs_round_trip(#[case] req: &str) {
+        let set = set(req);
+        let reqs = set.to_version_reqs();
+        assert_eq!(reqs.len(), 1);
+        assert_eq!(IntervalSet::try_from(&reqs[0]).unwrap(), set);
+    }
+
+    #[test]
+    fn test_to_version_reqs_of_union() {
+        let mut set = set("^1.2");
+        set.insert(Interval::since_inclusive(version("3.0.0")));
+        assert_eq!(
+            set.to_version_reqs(),
+            vec![
+                VersionReq::parse(">=1.2.0, <2.0.0").unwrap(),
+                VersionReq::parse(">=3.0.0").unwrap()
+            ]
+        );
+        assert_eq!(IntervalSet::<Version>::new().to_version_reqs(), vec![]);
+    }
+}
@@ -0,0 +1,219 @@
+//! Serde support, enabled by the `serde` feature.
+//!
+//! An [`Interval`] serializes as its `start` and `end` bound points.
+//! An [`IntervalSet`] serializes as the sequence of its members in
+//! ascending order. Deserializing a set accepts any sequence of intervals
+//! and normalizes it; use [`strict`] to reject input that is not already
+//! normalized instead.
+
+use crate::bound_point::BoundPoint;
+use crate::interval::{Interval, IntervalError};
+use crate::interval_set::IntervalSet;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Debug;
+
+#[derive(Deserialize)]
+struct RawInterval<T: Ord> {
+    start: BoundPoint<T>,
+    end: BoundPoint<T>,
+}
+
+impl<'de, T: Ord + Deserialize<'de>> Deserialize<'de> for Interval<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawInterval { start, end } = RawInterval::deserialize(deserializer)?;
+        Interval::try_new(start, end).map_err(|error| match error {
+            IntervalError::StartMustBeMinorThanEnd => {
+                D::Error::custom("interval start must not be greater than its end")
+            }
+            IntervalError::InvalidProximity => {
+                D::Error::custom("interval start must not be `Before` nor its end `After`")
+            }
+            IntervalError::Unbounded
+            | IntervalError::NoWeight
+            | IntervalError::NonPositivePeriod
+            | IntervalError::Overflow
+            | IntervalError::Contradiction(..) => {
+                unreachable!("try_new never returns {:?}", error)
+            }
+        })
+    }
+}
+
+impl<T: Ord + Serialize> Serialize for IntervalSet<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Ord + Deserialize<'de>> Deserialize<'de> for IntervalSet<T> {
+    /// Accepts the intervals in any order, overlapping or not,
+    /// and normalizes them.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<Interval<T>>::deserialize(deserializer).map(IntervalSet::from)
+    }
+}
+
+/// Strict (de)serialization of an [`IntervalSet`], for use with
+/// `#[serde(with = "my_interval::serde_strict")]`.
+///
+/// Deserialization rejects input whose members are empty, out of order,
+/// overlapping or flush with each other, naming the offending pair,
+/// instead of silently normalizing it.
+pub mod strict {
+    use super::*;
+
+    pub fn serialize<T, S>(set: &IntervalSet<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Ord + Serialize,
+        S: Serializer,
+    {
+        set.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<IntervalSet<T>, D::Error>
+    where
+        T: Ord + Debug + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let members = Vec::<Interval<T>>::deserialize(deserializer)?;
+        if let Some(empty) = members.iter().find(|member| member.is_empty()) {
+            return Err(D::Error::custom(format!("member {:?} is empty", empty)));
+        }
+        if let Some(pair) = members
+            .windows(2)
+            .find(|pair| pair[0].end >= pair[1].start || pair[0].end.meets(&pair[1].start))
+        {
+            return Err(D::Error::custom(format!(
+                "members {:?} and {:?} are not disjoint and in ascending order",
+                pair[0], pair[1]
+            )));
+        }
+        Ok(IntervalSet::from(members))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use rstest::rstest;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Calendar {
+        #[serde(with = "strict")]
+        availability: IntervalSet<i32>,
+    }
+
+    fn closed(start: i32, end: i32) -> String {
+        format!(
+            r#"{{"start":{{"Finite":[{},"At"]}},"end":{{"Finite":[{},"At"]}}}}"#,
+            start, end
+        )
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(1, 3, IntervalType::Close).unwrap())]
+    #[case(Interval::from_to(1, 3, IntervalType::Open).unwrap())]
+    #[case(Interval::from_to(1, 1, IntervalType::EndOpen).unwrap())]
+    #[case(Interval::until_exclusive(3))]
+    #[case(Interval::since_inclusive(3))]
+    fn test_interval_round_trip(#[case] interval: Interval<i32>) {
+        let json = serde_json::to_string(&interval).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Interval<i32>>(&json).unwrap(),
+            interval
+        );
+    }
+
+    #[test]
+    fn test_interval_format() {
+        let json =
+            serde_json::to_string(&Interval::from_to(1, 3, IntervalType::StartOpen).unwrap())
+                .unwrap();
+        assert_eq!(
+            json,
+            r#"{"start":{"Finite":[1,"After"]},"end":{"Finite":[3,"At"]}}"#
+        );
+        let json = serde_json::to_string(&Interval::until_exclusive(3)).unwrap();
+        assert_eq!(
+            json,
+            r#"{"start":"NegInfinity","end":{"Finite":[3,"Before"]}}"#
+        );
+    }
+
+    #[rstest]
+    #[case(r#"{"start":{"Finite":[3,"At"]},"end":{"Finite":[1,"At"]}}"#)]
+    #[case(r#"{"start":"PosInfinity","end":"PosInfinity"}"#)]
+    #[case(r#"{"start":"NegInfinity","end":"NegInfinity"}"#)]
+    #[case(r#"{"start":{"Finite":[3,"At"]},"end":"NegInfinity"}"#)]
+    fn test_interval_rejects_inverted_bounds(#[case] json: &str) {
+        assert!(serde_json::from_str::<Interval<i32>>(json).is_err());
+    }
+
+    #[rstest]
+    #[case(r#"{"start":{"Finite":[1,"Before"]},"end":{"Finite":[3,"At"]}}"#)]
+    #[case(r#"{"start":{"Finite":[1,"At"]},"end":{"Finite":[3,"After"]}}"#)]
+    fn test_interval_rejects_invalid_proximity(#[case] json: &str) {
+        assert!(serde_json::from_str::<Interval<i32>>(json).is_err());
+    }
+
+    #[test]
+    fn test_set_round_trip() {
+        let set = IntervalSet::from(vec![
+            Interval::until_exclusive(0),
+            Interval::from_to(1, 3, IntervalType::EndOpen).unwrap(),
+            Interval::from_to(3, 5, IntervalType::Open).unwrap(),
+            Interval::since_inclusive(10),
+        ]);
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(
+            serde_json::from_str::<IntervalSet<i32>>(&json).unwrap(),
+            set
+        );
+        assert_eq!(
+            serde_json::to_string(&IntervalSet::<i32>::new()).unwrap(),
+            "[]"
+        );
+    }
+
+    #[test]
+    fn test_set_normalizes_on_deserialize() {
+        let json = format!("[{},{}]", closed(1, 5), closed(3, 8));
+        let set: IntervalSet<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            set,
+            IntervalSet::from(vec![Interval::from_to(1, 8, IntervalType::Close).unwrap()])
+        );
+
+        let json = format!("[{},{}]", closed(10, 12), closed(1, 5));
+        let set: IntervalSet<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_set_rejects_invalid_member() {
+        let json = format!("[{}]", closed(5, 1));
+        assert!(serde_json::from_str::<IntervalSet<i32>>(&json).is_err());
+    }
+
+    #[test]
+    fn test_strict_accepts_normalized_input() {
+        let json = format!(r#"{{"availability":[{},{}]}}"#, closed(1, 3), closed(5, 8));
+        let calendar: Calendar = serde_json::from_str(&json).unwrap();
+        assert_eq!(calendar.availability.len(), 2);
+        assert_eq!(serde_json::to_string(&calendar).unwrap(), json);
+    }
+
+    #[rstest]
+    #[case(format!(r#"{{"availability":[{},{}]}}"#, closed(1, 5), closed(3, 8)), "members")]
+    #[case(format!(r#"{{"availability":[{},{}]}}"#, closed(5, 8), closed(1, 3)), "members")]
+    #[case(format!(r#"{{"availability":[{},{}]}}"#, closed(1, 3), r#"{"start":{"Finite":[3,"After"]},"end":{"Finite":[5,"At"]}}"#), "members")]
+    #[case(r#"{"availability":[{"start":{"Finite":[3,"After"]},"end":{"Finite":[3,"Before"]}}]}"#.to_string(), "is empty")]
+    fn test_strict_rejects_non_normalized_input(#[case] json: String, #[case] message: &str) {
+        let error = serde_json::from_str::<Calendar>(&json)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains(message), "unexpected error: {}", error);
+    }
+}
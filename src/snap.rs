@@ -0,0 +1,255 @@
+use crate::bound_point::{BoundPoint, BoundProximity};
+use crate::interval::{Interval, IntervalError};
+use std::ops::{Add, Range, Rem, Sub};
+
+/// Aligning interval bounds to a grid of multiples of `grid`, offset by
+/// an origin. `T::default()` is taken as zero.
+///
+/// A bound already on the grid is left as it is. A bound that has to
+/// move becomes inclusive, so snapping `[9:07, 9:50)` outward to
+/// 15-minute slots yields `[9:00, 10:00]`. Infinite bounds never move.
+/// The grid must be positive, and snapping next to the limits of `T`
+/// overflows like the arithmetic it uses.
+impl<T> Interval<T>
+where
+    T: Ord + Clone + Default + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+{
+    /// Rounds the start down and the end up to multiples of `grid`,
+    /// giving the smallest aligned interval containing this one.
+    pub fn snap_outward(&self, grid: T) -> Interval<T> {
+        self.snap_outward_with_origin(grid, T::default())
+    }
+
+    /// Like [`Interval::snap_outward`], with the grid points at
+    /// `origin + k * grid` instead of `k * grid`.
+    pub fn snap_outward_with_origin(&self, grid: T, origin: T) -> Interval<T> {
+        Interval {
+            start: snap(&self.start, &grid, &origin, Direction::Down),
+            end: snap(&self.end, &grid, &origin, Direction::Up),
+        }
+    }
+
+    /// Rounds the start up and the end down to multiples of `grid`,
+    /// giving the largest aligned interval within this one, or `None`
+    /// when no such interval is left.
+    pub fn snap_inward(&self, grid: T) -> Option<Interval<T>> {
+        self.snap_inward_with_origin(grid, T::default())
+    }
+
+    /// Like [`Interval::snap_inward`], with the grid points at
+    /// `origin + k * grid` instead of `k * grid`.
+    pub fn snap_inward_with_origin(&self, grid: T, origin: T) -> Option<Interval<T>> {
+        if self.is_empty() {
+            return None;
+        }
+        let snapped = Interval {
+            start: snap(&self.start, &grid, &origin, Direction::Up),
+            end: snap(&self.end, &grid, &origin, Direction::Down),
+        };
+        (!snapped.is_empty()).then_some(snapped)
+    }
+}
+
+/// Booking whole slots of integers: slot `k` is
+/// `[origin + k * period, origin + (k + 1) * period)`, so slots before
+/// the origin have negative indices.
+impl<T> Interval<T>
+where
+    T: Ord + Copy + Into<i128> + TryFrom<i128>,
+{
+    /// Returns the smallest run of whole slots covering every value of
+    /// the interval, as the half-open interval they span and the range
+    /// of their indices.
+    ///
+    /// An exclusive end on a slot boundary does not claim the slot
+    /// starting there: `[0, 30)` with period `15` takes slots `0..2`,
+    /// while `[0, 30]` takes `0..3`. An empty interval takes no slot, and
+    /// yields an empty span and range at the slot holding its start.
+    ///
+    /// Fails with [`IntervalError::NonPositivePeriod`] when the period is
+    /// not positive, with [`IntervalError::Unbounded`] when the interval
+    /// is unbounded, and with [`IntervalError::Overflow`] when the span
+    /// does not fit in `T` or an index in `i64`.
+    pub fn to_slot_range(
+        &self,
+        origin: T,
+        period: T,
+    ) -> Result<(Interval<T>, Range<i64>), IntervalError> {
+        let (origin, period) = (origin.into(), period.into());
+        if period <= 0 {
+            return Err(IntervalError::NonPositivePeriod);
+        }
+        let (Some((start, _)), Some((end, end_proximity))) =
+            (self.start.into_finite(), self.end.into_finite())
+        else {
+            return Err(IntervalError::Unbounded);
+        };
+        let first = start
+            .into()
+            .checked_sub(origin)
+            .ok_or(IntervalError::Overflow)?
+            .div_euclid(period);
+        let offset = end
+            .into()
+            .checked_sub(origin)
+            .ok_or(IntervalError::Overflow)?;
+        let after_last = match end_proximity {
+            // the slots up to the one starting at or above the end
+            BoundProximity::Before => offset
+                .checked_neg()
+                .and_then(|negated| negated.div_euclid(period).checked_neg()),
+            _ => offset.div_euclid(period).checked_add(1),
+        }
+        .ok_or(IntervalError::Overflow)?;
+        let after_last = if self.is_empty() { first } else { after_last };
+
+        let boundary = |slot: i128| {
+            slot.checked_mul(period)
+                .and_then(|offset| offset.checked_add(origin))
+                .and_then(|value| T::try_from(value).ok())
+                .ok_or(IntervalError::Overflow)
+        };
+        let index = |slot: i128| i64::try_from(slot).map_err(|_| IntervalError::Overflow);
+        let span = Interval {
+            start: BoundPoint::at(boundary(first)?),
+            end: BoundPoint::before(boundary(after_last)?),
+        };
+        Ok((span, index(first)?..index(after_last)?))
+    }
+}
+
+enum Direction {
+    Down,
+    Up,
+}
+
+fn snap<T>(point: &BoundPoint<T>, grid: &T, origin: &T, direction: Direction) -> BoundPoint<T>
+where
+    T: Ord + Clone + Default + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+{
+    assert!(*grid > T::default(), "grid must be positive");
+    let Some(value) = point.finite_value() else {
+        return point.clone();
+    };
+    // distance above the grid point below, made non-negative since `%`
+    // keeps the sign of negative values
+    let mut offset = (value.clone() - origin.clone()) % grid.clone();
+    if offset < T::default() {
+        offset = offset + grid.clone();
+    }
+    if offset == T::default() {
+        return point.clone();
+    }
+    let below = value.clone() - offset;
+    match direction {
+        Direction::Down => BoundPoint::at(below),
+        Direction::Up => BoundPoint::at(below + grid.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Interval::from_to(7, 50, IntervalType::EndOpen).unwrap(), Interval::from_to(0, 60, IntervalType::Close).unwrap())]
+    // bounds on the grid keep their proximity
+    #[case(Interval::from_to(15, 60, IntervalType::Open).unwrap(), Interval::from_to(15, 60, IntervalType::Open).unwrap())]
+    #[case(Interval::from_to(15, 50, IntervalType::Open).unwrap(), Interval::from_to(15, 60, IntervalType::StartOpen).unwrap())]
+    #[case(Interval::from_to(-20, -1, IntervalType::Close).unwrap(), Interval::from_to(-30, 0, IntervalType::Close).unwrap())]
+    #[case(Interval::from_to(-30, -15, IntervalType::Close).unwrap(), Interval::from_to(-30, -15, IntervalType::Close).unwrap())]
+    #[case(Interval::since_exclusive(1), Interval::since_inclusive(0))]
+    #[case(Interval::until_exclusive(1), Interval::until_inclusive(15))]
+    fn test_snap_outward(#[case] interval: Interval<i32>, #[case] expected: Interval<i32>) {
+        assert_eq!(interval.snap_outward(15), expected);
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(7, 50, IntervalType::EndOpen).unwrap(), Some(Interval::from_to(15, 45, IntervalType::Close).unwrap()))]
+    #[case(Interval::from_to(15, 60, IntervalType::EndOpen).unwrap(), Some(Interval::from_to(15, 60, IntervalType::EndOpen).unwrap()))]
+    #[case(Interval::from_to(-20, -1, IntervalType::Close).unwrap(), Some(Interval::from_to(-15, -15, IntervalType::Close).unwrap()))]
+    // no grid point inside
+    #[case(Interval::from_to(1, 14, IntervalType::Close).unwrap(), None)]
+    #[case(Interval::from_to(15, 15, IntervalType::EndOpen).unwrap(), None)]
+    #[case(Interval::from_to(15, 15, IntervalType::Close).unwrap(), Some(Interval::from_to(15, 15, IntervalType::Close).unwrap()))]
+    #[case(Interval::since_exclusive(1), Some(Interval::since_inclusive(15)))]
+    fn test_snap_inward(#[case] interval: Interval<i32>, #[case] expected: Option<Interval<i32>>) {
+        assert_eq!(interval.snap_inward(15), expected);
+    }
+
+    #[rstest]
+    // grid points at ..., -8, 7, 22, 37, ...
+    #[case(Interval::from_to(10, 30, IntervalType::EndOpen).unwrap(), Interval::from_to(7, 37, IntervalType::Close).unwrap(), Some(Interval::from_to(22, 22, IntervalType::Close).unwrap()))]
+    #[case(Interval::from_to(-10, 7, IntervalType::EndOpen).unwrap(), Interval::from_to(-23, 7, IntervalType::EndOpen).unwrap(), Some(Interval::from_to(-8, 7, IntervalType::EndOpen).unwrap()))]
+    fn test_snap_with_origin(
+        #[case] interval: Interval<i32>,
+        #[case] outward: Interval<i32>,
+        #[case] inward: Option<Interval<i32>>,
+    ) {
+        assert_eq!(interval.snap_outward_with_origin(15, 7), outward);
+        assert_eq!(interval.snap_inward_with_origin(15, 7), inward);
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(0, 30, IntervalType::EndOpen).unwrap(), Interval::from_to(0, 30, IntervalType::EndOpen).unwrap(), 0..2)]
+    #[case(Interval::from_to(0, 30, IntervalType::Close).unwrap(), Interval::from_to(0, 45, IntervalType::EndOpen).unwrap(), 0..3)]
+    #[case(Interval::from_to(10, 31, IntervalType::Open).unwrap(), Interval::from_to(0, 45, IntervalType::EndOpen).unwrap(), 0..3)]
+    #[case(Interval::from_to(15, 29, IntervalType::StartOpen).unwrap(), Interval::from_to(15, 30, IntervalType::EndOpen).unwrap(), 1..2)]
+    // entirely before the origin
+    #[case(Interval::from_to(-40, -15, IntervalType::EndOpen).unwrap(), Interval::from_to(-45, -15, IntervalType::EndOpen).unwrap(), -3..-1)]
+    #[case(Interval::from_to(-1, -1, IntervalType::Close).unwrap(), Interval::from_to(-15, 0, IntervalType::EndOpen).unwrap(), -1..0)]
+    // empty intervals take no slot
+    #[case(Interval::from_to(20, 20, IntervalType::EndOpen).unwrap(), Interval::from_to(15, 15, IntervalType::EndOpen).unwrap(), 1..1)]
+    #[case(Interval::from_to(30, 30, IntervalType::Open).unwrap(), Interval::from_to(30, 30, IntervalType::EndOpen).unwrap(), 2..2)]
+    fn test_to_slot_range(
+        #[case] interval: Interval<i32>,
+        #[case] span: Interval<i32>,
+        #[case] slots: Range<i64>,
+    ) {
+        assert_eq!(interval.to_slot_range(0, 15), Ok((span, slots)));
+    }
+
+    #[test]
+    fn test_to_slot_range_with_origin() {
+        // slots at ..., -8, 7, 22, ...
+        let request = Interval::from_to(-8, 22, IntervalType::EndOpen).unwrap();
+        let span = Interval::from_to(-8, 22, IntervalType::EndOpen).unwrap();
+        assert_eq!(request.to_slot_range(7, 15), Ok((span, -1..1)));
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(0, 30, IntervalType::Close).unwrap(), 0, IntervalError::NonPositivePeriod)]
+    #[case(Interval::from_to(0, 30, IntervalType::Close).unwrap(), -15, IntervalError::NonPositivePeriod)]
+    #[case(Interval::since_inclusive(0), 15, IntervalError::Unbounded)]
+    #[case(Interval::until_exclusive(0), 15, IntervalError::Unbounded)]
+    #[case(Interval::from_to(0, i32::MAX, IntervalType::Close).unwrap(), 15, IntervalError::Overflow)]
+    fn test_to_slot_range_errors(
+        #[case] interval: Interval<i32>,
+        #[case] period: i32,
+        #[case] error: IntervalError,
+    ) {
+        assert_eq!(interval.to_slot_range(0, period), Err(error));
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(i128::MIN, 0, IntervalType::Close).unwrap(), 1)]
+    #[case(Interval::from_to(0, i128::MAX, IntervalType::Close).unwrap(), -1)]
+    #[case(Interval::from_to(-1, i128::MAX, IntervalType::EndOpen).unwrap(), i128::MIN)]
+    #[case(Interval::from_to(0, 10, IntervalType::Close).unwrap(), i128::MAX)]
+    fn test_to_slot_range_i128_overflow(#[case] interval: Interval<i128>, #[case] origin: i128) {
+        assert_eq!(
+            interval.to_slot_range(origin, 7),
+            Err(IntervalError::Overflow)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "grid must be positive")]
+    fn test_zero_grid_panics() {
+        Interval::from_to(1, 2, IntervalType::Close)
+            .unwrap()
+            .snap_outward(0);
+    }
+}
@@ -0,0 +1,659 @@
+//! [`TemporalPoint`] for `DateTime<Tz>`, `NaiveDateTime` and `NaiveDate`,
+//! [`Discrete`] for `NaiveDate`, stepping one day at a time, and calendar
+//! constructors building canonical half-open intervals for
+//! `DateTime<Utc>` and `NaiveDateTime`, and splitting `DateTime`
+//! intervals at the midnights of a time zone.
+//!
+//! A `NaiveDateTime` carries no time zone: its days and months run from
+//! wall-clock midnight to midnight, so when it holds local times a day
+//! across a daylight saving change still spans exactly 24 hours.
+
+use crate::bound_point::BoundPoint;
+use crate::discrete::Discrete;
+use crate::interval::Interval;
+use crate::temporal::{half_open, Sealed, TemporalPoint};
+use chrono::{
+    DateTime, Datelike, Days, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+};
+
+impl<Tz: TimeZone> Sealed for DateTime<Tz> {}
+
+impl<Tz: TimeZone> TemporalPoint for DateTime<Tz> {
+    type Duration = Duration;
+
+    fn add_duration(&self, duration: Duration) -> Self {
+        self.clone() + duration
+    }
+
+    fn diff(&self, earlier: &Self) -> Duration {
+        self.clone() - earlier.clone()
+    }
+}
+
+impl Sealed for NaiveDateTime {}
+
+impl TemporalPoint for NaiveDateTime {
+    type Duration = Duration;
+
+    fn add_duration(&self, duration: Duration) -> Self {
+        *self + duration
+    }
+
+    fn diff(&self, earlier: &Self) -> Duration {
+        *self - *earlier
+    }
+}
+
+impl Sealed for NaiveDate {}
+
+impl TemporalPoint for NaiveDate {
+    type Duration = Duration;
+
+    fn add_duration(&self, duration: Duration) -> Self {
+        *self + duration
+    }
+
+    fn diff(&self, earlier: &Self) -> Duration {
+        *self - *earlier
+    }
+}
+
+impl Interval<DateTime<Utc>> {
+    /// Returns `[00:00 of date, 00:00 of the next day)` in UTC.
+    pub fn day_of(date: NaiveDate) -> Self {
+        let (start, end) = day_bounds(date);
+        half_open(start.and_utc(), end.map(|end| end.and_utc()))
+    }
+
+    /// Returns the UTC interval from the first of the month containing
+    /// `date` up to, but excluding, the first of the following month.
+    pub fn month_of(date: NaiveDate) -> Self {
+        let (start, end) = month_bounds(date);
+        half_open(start.and_utc(), end.map(|end| end.and_utc()))
+    }
+
+    /// Returns how long both intervals overlap, or zero when they are
+    /// disjoint.
+    ///
+    /// An overlap unbounded on either side saturates at `Duration::MAX`.
+    pub fn overlap_duration(&self, other: &Interval<DateTime<Utc>>) -> Duration {
+        match self.intersection(other) {
+            Some(overlap) => overlap.duration().unwrap_or(Duration::MAX),
+            None => Duration::zero(),
+        }
+    }
+
+    /// Returns `true` if the current time, read from `Utc::now()`, lies
+    /// within the interval.
+    pub fn contains_now(&self) -> bool {
+        self.contains(Utc::now())
+    }
+
+    /// Returns how much of the interval lies at or after `t`.
+    ///
+    /// When `t` comes before the interval starts, that is the whole
+    /// duration, not the wait until the start plus the duration. Like
+    /// [`overlap_duration`](Self::overlap_duration), this is zero once
+    /// `t` is past the end, which for an exclusive end includes `t` equal
+    /// to it, and saturates at `Duration::MAX` when the interval is
+    /// unbounded above.
+    pub fn remaining_from(&self, t: DateTime<Utc>) -> Duration {
+        self.overlap_duration(&Interval::since_inclusive(t))
+    }
+}
+
+impl<Z: TimeZone> Interval<DateTime<Z>> {
+    /// Splits the interval at every midnight of `tz` it spans, returning
+    /// the pieces in `tz`, or `None` when the interval is unbounded.
+    ///
+    /// Interior pieces are whole half-open days from one midnight to the
+    /// next; the first and last pieces keep the original bounds. Days are
+    /// cut at the actual local midnights, so a day across a daylight
+    /// saving change lasts 23 or 25 hours. When a transition skips
+    /// midnight, the day starts at the first local time that exists.
+    pub fn split_by_day<Tz: TimeZone>(&self, tz: &Tz) -> Option<Vec<Interval<DateTime<Tz>>>> {
+        self.split_at_midnights(tz, |date| date.succ_opt())
+    }
+
+    /// Splits the interval at the midnight starting every month of `tz`
+    /// it spans, like [`split_by_day`](Self::split_by_day).
+    pub fn split_by_month<Tz: TimeZone>(&self, tz: &Tz) -> Option<Vec<Interval<DateTime<Tz>>>> {
+        self.split_at_midnights(tz, |date| {
+            let (_, next) = month_bounds(date);
+            next.map(|next| next.date())
+        })
+    }
+
+    /// Cuts the interval in `tz` at the start of `next(date)`, starting
+    /// from the date of its start and stopping past its end.
+    fn split_at_midnights<Tz: TimeZone>(
+        &self,
+        tz: &Tz,
+        next: impl Fn(NaiveDate) -> Option<NaiveDate>,
+    ) -> Option<Vec<Interval<DateTime<Tz>>>> {
+        let (Some(first), Some(_)) = (self.start.finite_value(), self.end.finite_value()) else {
+            return None;
+        };
+        let mut date = first.with_timezone(tz).date_naive();
+        let mut pieces = Vec::new();
+        if self.is_empty() {
+            return Some(pieces);
+        }
+        let mut start = self.start.clone().map(|value| value.with_timezone(tz));
+        let end = self.end.clone().map(|value| value.with_timezone(tz));
+        while let Some(following) = next(date) {
+            let midnight = start_of_day(tz, following);
+            if end.cmp_value(&midnight).is_lt() {
+                break;
+            }
+            let piece = Interval {
+                start: std::mem::replace(&mut start, BoundPoint::at(midnight.clone())),
+                end: BoundPoint::before(midnight),
+            };
+            if !piece.is_empty() {
+                pieces.push(piece);
+            }
+            date = following;
+        }
+        pieces.push(Interval { start, end });
+        Some(pieces)
+    }
+}
+
+/// The first instant of `date` in `tz`: its local midnight, or the end of
+/// a transition skipping midnight. An ambiguous midnight resolves to the
+/// earlier instant.
+fn start_of_day<Tz: TimeZone>(tz: &Tz, date: NaiveDate) -> DateTime<Tz> {
+    let mut local = date.and_time(NaiveTime::MIN);
+    loop {
+        if let Some(start) = tz.from_local_datetime(&local).earliest() {
+            return start;
+        }
+        // offsets in use are whole multiples of a quarter hour
+        local += Duration::minutes(15);
+    }
+}
+
+impl Discrete for NaiveDate {
+    fn succ(&self) -> Option<Self> {
+        self.succ_opt()
+    }
+
+    fn pred(&self) -> Option<Self> {
+        self.pred_opt()
+    }
+
+    fn steps_to(&self, later: &Self) -> Option<u128> {
+        (later >= self).then(|| later.signed_duration_since(*self).num_days() as u128)
+    }
+
+    fn forward(&self, steps: u128) -> Option<Self> {
+        self.checked_add_days(Days::new(steps.try_into().ok()?))
+    }
+}
+
+impl Interval<NaiveDateTime> {
+    /// Returns `[00:00 of date, 00:00 of the next day)`.
+    pub fn day_of(date: NaiveDate) -> Self {
+        let (start, end) = day_bounds(date);
+        half_open(start, end)
+    }
+
+    /// Returns the interval from the first of the month containing `date`
+    /// up to, but excluding, the first of the following month.
+    pub fn month_of(date: NaiveDate) -> Self {
+        let (start, end) = month_bounds(date);
+        half_open(start, end)
+    }
+}
+
+/// Midnight of `date` and of the following day, if representable.
+fn day_bounds(date: NaiveDate) -> (NaiveDateTime, Option<NaiveDateTime>) {
+    let end = date.succ_opt().map(|next| next.and_time(NaiveTime::MIN));
+    (date.and_time(NaiveTime::MIN), end)
+}
+
+/// Midnight of the first of the month and of the following month,
+/// if representable.
+fn month_bounds(date: NaiveDate) -> (NaiveDateTime, Option<NaiveDateTime>) {
+    let first = date.with_day(1).expect("every month has a first day");
+    let end = first
+        .checked_add_months(Months::new(1))
+        .map(|next| next.and_time(NaiveTime::MIN));
+    (first.and_time(NaiveTime::MIN), end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::{IntervalError, IntervalType};
+    use chrono::{FixedOffset, LocalResult};
+    use rstest::rstest;
+
+    /// A zone switching from one fixed offset to another at a UTC instant,
+    /// standing in for a daylight saving transition.
+    #[derive(Debug, Clone)]
+    struct Switching {
+        at: NaiveDateTime,
+        before: FixedOffset,
+        after: FixedOffset,
+    }
+
+    impl Switching {
+        fn new(at: DateTime<Utc>, before_hours: i32, after_hours: i32) -> Self {
+            Switching {
+                at: at.naive_utc(),
+                before: FixedOffset::east_opt(before_hours * 3600).unwrap(),
+                after: FixedOffset::east_opt(after_hours * 3600).unwrap(),
+            }
+        }
+    }
+
+    impl TimeZone for Switching {
+        type Offset = FixedOffset;
+
+        fn from_offset(offset: &FixedOffset) -> Self {
+            Switching {
+                at: NaiveDateTime::MAX,
+                before: *offset,
+                after: *offset,
+            }
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&local.and_time(NaiveTime::MIN))
+        }
+
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            let before = *local - self.before < self.at;
+            let after = *local - self.after >= self.at;
+            match (before, after) {
+                (true, true) if self.before != self.after => {
+                    LocalResult::Ambiguous(self.before, self.after)
+                }
+                (true, _) => LocalResult::Single(self.before),
+                (false, true) => LocalResult::Single(self.after),
+                (false, false) => LocalResult::None,
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+            self.offset_from_utc_datetime(&utc.and_time(NaiveTime::MIN))
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            if *utc < self.at {
+                self.before
+            } else {
+                self.after
+            }
+        }
+    }
+
+    fn in_utc<Tz: TimeZone>(pieces: Vec<Interval<DateTime<Tz>>>) -> Vec<Interval<DateTime<Utc>>> {
+        pieces
+            .into_iter()
+            .map(|piece| Interval {
+                start: piece.start.map(|value| value.with_timezone(&Utc)),
+                end: piece.end.map(|value| value.with_timezone(&Utc)),
+            })
+            .collect()
+    }
+
+    fn end_open(start: DateTime<Utc>, end: DateTime<Utc>) -> Interval<DateTime<Utc>> {
+        Interval::from_to(start, end, IntervalType::EndOpen).unwrap()
+    }
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap()
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_for_duration() {
+        let interval = Interval::for_duration(utc(2024, 3, 1, 9), Duration::hours(2)).unwrap();
+        assert_eq!(
+            interval,
+            Interval::from_to(
+                utc(2024, 3, 1, 9),
+                utc(2024, 3, 1, 11),
+                IntervalType::EndOpen
+            )
+            .unwrap()
+        );
+        assert!(!interval.contains(utc(2024, 3, 1, 11)));
+        assert_eq!(
+            Interval::for_duration(utc(2024, 3, 1, 9), Duration::hours(-1)),
+            Err(IntervalError::StartMustBeMinorThanEnd)
+        );
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(utc(2024, 3, 1, 9), utc(2024, 3, 2, 11), IntervalType::Close).unwrap(), Some(Duration::hours(26)))]
+    #[case(Interval::from_to(utc(2024, 3, 1, 9), utc(2024, 3, 1, 9), IntervalType::Open).unwrap(), Some(Duration::zero()))]
+    #[case(Interval::since_inclusive(utc(2024, 3, 1, 9)), None)]
+    #[case(Interval::until_exclusive(utc(2024, 3, 1, 9)), None)]
+    fn test_duration(
+        #[case] interval: Interval<DateTime<Utc>>,
+        #[case] expected: Option<Duration>,
+    ) {
+        assert_eq!(interval.duration(), expected);
+    }
+
+    #[test]
+    fn test_shift_by_keeps_inclusiveness() {
+        let interval = Interval::from_to(
+            utc(2024, 3, 1, 9),
+            utc(2024, 3, 1, 17),
+            IntervalType::StartOpen,
+        )
+        .unwrap();
+        assert_eq!(
+            interval.shift_by(Duration::days(1)),
+            Interval::from_to(
+                utc(2024, 3, 2, 9),
+                utc(2024, 3, 2, 17),
+                IntervalType::StartOpen
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            Interval::until_inclusive(utc(2024, 3, 1, 9)).shift_by(Duration::hours(-9)),
+            Interval::until_inclusive(utc(2024, 3, 1, 0))
+        );
+    }
+
+    #[rstest]
+    #[case(utc(2024, 3, 1, 11), utc(2024, 3, 1, 20), Duration::hours(6))]
+    #[case(utc(2024, 3, 1, 5), utc(2024, 3, 1, 11), Duration::hours(2))]
+    #[case(utc(2024, 3, 1, 17), utc(2024, 3, 1, 20), Duration::zero())]
+    #[case(utc(2024, 3, 1, 18), utc(2024, 3, 1, 20), Duration::zero())]
+    #[case(utc(2024, 3, 1, 0), utc(2024, 3, 2, 0), Duration::hours(8))]
+    fn test_overlap_duration(
+        #[case] start: DateTime<Utc>,
+        #[case] end: DateTime<Utc>,
+        #[case] expected: Duration,
+    ) {
+        let shift = Interval::from_to(
+            utc(2024, 3, 1, 9),
+            utc(2024, 3, 1, 17),
+            IntervalType::EndOpen,
+        )
+        .unwrap();
+        let other = Interval::from_to(start, end, IntervalType::Close).unwrap();
+        assert_eq!(shift.overlap_duration(&other), expected);
+        assert_eq!(other.overlap_duration(&shift), expected);
+    }
+
+    #[test]
+    fn test_overlap_duration_unbounded() {
+        let since = Interval::since_inclusive(utc(2024, 3, 1, 9));
+        assert_eq!(
+            since.overlap_duration(&Interval::<DateTime<Utc>>::day_of(date(2024, 3, 1))),
+            Duration::hours(15)
+        );
+        assert_eq!(
+            since.overlap_duration(&Interval::since_exclusive(utc(2024, 3, 2, 0))),
+            Duration::MAX
+        );
+    }
+
+    #[test]
+    fn test_contains_now() {
+        let now = Utc::now();
+        assert!(
+            Interval::for_duration(now - Duration::hours(1), Duration::hours(2))
+                .unwrap()
+                .contains_now()
+        );
+        assert!(!Interval::until_exclusive(now).contains_now());
+        assert!(Interval::<DateTime<Utc>>::since_inclusive(now).contains_now());
+    }
+
+    #[rstest]
+    #[case(utc(2024, 3, 1, 6), Duration::hours(8))]
+    #[case(utc(2024, 3, 1, 9), Duration::hours(8))]
+    #[case(utc(2024, 3, 1, 12), Duration::hours(5))]
+    #[case(utc(2024, 3, 1, 17), Duration::zero())]
+    #[case(utc(2024, 3, 1, 20), Duration::zero())]
+    fn test_remaining_from(#[case] t: DateTime<Utc>, #[case] expected: Duration) {
+        let shift = Interval::for_duration(utc(2024, 3, 1, 9), Duration::hours(8)).unwrap();
+        assert_eq!(shift.remaining_from(t), expected);
+    }
+
+    #[test]
+    fn test_remaining_from_inclusive_end_and_unbounded() {
+        let closed =
+            Interval::from_to(utc(2024, 3, 1, 9), utc(2024, 3, 1, 17), IntervalType::Close)
+                .unwrap();
+        assert_eq!(closed.remaining_from(utc(2024, 3, 1, 17)), Duration::zero());
+        assert_eq!(
+            Interval::since_inclusive(utc(2024, 3, 1, 9)).remaining_from(utc(2024, 3, 1, 12)),
+            Duration::MAX
+        );
+        assert_eq!(
+            Interval::until_exclusive(utc(2024, 3, 1, 17)).remaining_from(utc(2024, 3, 1, 12)),
+            Duration::hours(5)
+        );
+    }
+
+    #[test]
+    fn test_split_by_day_in_utc() {
+        let interval =
+            Interval::from_to(utc(2024, 3, 1, 9), utc(2024, 3, 3, 0), IntervalType::StartOpen)
+                .unwrap();
+        assert_eq!(
+            in_utc(interval.split_by_day(&Utc).unwrap()),
+            vec![
+                Interval::from_to(utc(2024, 3, 1, 9), utc(2024, 3, 2, 0), IntervalType::Open)
+                    .unwrap(),
+                end_open(utc(2024, 3, 2, 0), utc(2024, 3, 3, 0)),
+                Interval::from_to(utc(2024, 3, 3, 0), utc(2024, 3, 3, 0), IntervalType::Close)
+                    .unwrap(),
+            ]
+        );
+        let within = end_open(utc(2024, 3, 1, 9), utc(2024, 3, 2, 0));
+        assert_eq!(in_utc(within.split_by_day(&Utc).unwrap()), vec![within]);
+    }
+
+    #[test]
+    fn test_split_by_day_across_daylight_saving_changes() {
+        // clocks go forward at 01:00 UTC on 31 March, from UTC+1 to UTC+2
+        let spring = Switching::new(utc(2024, 3, 31, 1), 1, 2);
+        let interval = end_open(utc(2024, 3, 30, 12), utc(2024, 4, 1, 12));
+        let pieces = interval.split_by_day(&spring).unwrap();
+        assert_eq!(
+            pieces.iter().map(|piece| piece.duration().unwrap()).collect::<Vec<_>>(),
+            vec![Duration::hours(11), Duration::hours(23), Duration::hours(14)]
+        );
+        assert_eq!(
+            in_utc(pieces)[1],
+            end_open(utc(2024, 3, 30, 23), utc(2024, 3, 31, 22))
+        );
+
+        // and back at 01:00 UTC on 27 October
+        let autumn = Switching::new(utc(2024, 10, 27, 1), 2, 1);
+        let interval = end_open(utc(2024, 10, 26, 22), utc(2024, 10, 27, 23));
+        assert_eq!(
+            in_utc(interval.split_by_day(&autumn).unwrap()),
+            vec![interval]
+        );
+        assert_eq!(interval.duration(), Some(Duration::hours(25)));
+    }
+
+    #[test]
+    fn test_split_by_day_when_midnight_is_skipped() {
+        // clocks jump from 00:00 to 01:00 local time, from UTC-3 to UTC-2
+        let zone = Switching::new(utc(2024, 11, 3, 3), -3, -2);
+        let interval = end_open(utc(2024, 11, 2, 12), utc(2024, 11, 3, 12));
+        assert_eq!(
+            in_utc(interval.split_by_day(&zone).unwrap()),
+            vec![
+                end_open(utc(2024, 11, 2, 12), utc(2024, 11, 3, 3)),
+                end_open(utc(2024, 11, 3, 3), utc(2024, 11, 3, 12)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_by_day_unbounded_or_empty() {
+        assert_eq!(
+            Interval::since_inclusive(utc(2024, 3, 1, 9)).split_by_day(&Utc),
+            None
+        );
+        let empty =
+            Interval::from_to(utc(2024, 3, 1, 9), utc(2024, 3, 1, 9), IntervalType::EndOpen)
+                .unwrap();
+        assert_eq!(empty.split_by_day(&Utc), Some(vec![]));
+    }
+
+    #[test]
+    fn test_split_by_month() {
+        let interval = end_open(utc(2024, 1, 15, 0), utc(2024, 3, 10, 0));
+        let eastern = FixedOffset::east_opt(3 * 3600).unwrap();
+        assert_eq!(
+            in_utc(interval.split_by_month(&eastern).unwrap()),
+            vec![
+                end_open(utc(2024, 1, 15, 0), utc(2024, 1, 31, 21)),
+                end_open(utc(2024, 1, 31, 21), utc(2024, 2, 29, 21)),
+                end_open(utc(2024, 2, 29, 21), utc(2024, 3, 10, 0)),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(date(2024, 3, 1), utc(2024, 3, 1, 0), utc(2024, 3, 2, 0))]
+    #[case(date(2024, 2, 29), utc(2024, 2, 29, 0), utc(2024, 3, 1, 0))]
+    #[case(date(2024, 12, 31), utc(2024, 12, 31, 0), utc(2025, 1, 1, 0))]
+    fn test_day_of(
+        #[case] date: NaiveDate,
+        #[case] start: DateTime<Utc>,
+        #[case] end: DateTime<Utc>,
+    ) {
+        let day = Interval::<DateTime<Utc>>::day_of(date);
+        assert_eq!(
+            day,
+            Interval::from_to(start, end, IntervalType::EndOpen).unwrap()
+        );
+        assert_eq!(day.duration(), Some(Duration::days(1)));
+        assert_eq!(
+            Interval::<NaiveDateTime>::day_of(date),
+            Interval::from_to(start.naive_utc(), end.naive_utc(), IntervalType::EndOpen).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(date(2024, 2, 14), utc(2024, 2, 1, 0), utc(2024, 3, 1, 0), 29)]
+    #[case(date(2023, 2, 28), utc(2023, 2, 1, 0), utc(2023, 3, 1, 0), 28)]
+    #[case(date(2024, 12, 1), utc(2024, 12, 1, 0), utc(2025, 1, 1, 0), 31)]
+    fn test_month_of(
+        #[case] date: NaiveDate,
+        #[case] start: DateTime<Utc>,
+        #[case] end: DateTime<Utc>,
+        #[case] days: i64,
+    ) {
+        let month = Interval::<DateTime<Utc>>::month_of(date);
+        assert_eq!(
+            month,
+            Interval::from_to(start, end, IntervalType::EndOpen).unwrap()
+        );
+        assert_eq!(month.duration(), Some(Duration::days(days)));
+        assert_eq!(
+            Interval::<NaiveDateTime>::month_of(date),
+            Interval::from_to(start.naive_utc(), end.naive_utc(), IntervalType::EndOpen).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_last_representable_day_is_unbounded() {
+        assert_eq!(
+            Interval::<NaiveDateTime>::day_of(NaiveDate::MAX),
+            Interval::since_inclusive(NaiveDate::MAX.and_time(NaiveTime::MIN))
+        );
+    }
+
+    #[rstest]
+    // month and year boundaries
+    #[case(date(2024, 1, 30), date(2024, 2, 2), IntervalType::EndOpen, vec![date(2024, 1, 30), date(2024, 1, 31), date(2024, 2, 1)])]
+    #[case(date(2023, 12, 31), date(2024, 1, 1), IntervalType::Close, vec![date(2023, 12, 31), date(2024, 1, 1)])]
+    // leap and common years
+    #[case(date(2024, 2, 28), date(2024, 3, 1), IntervalType::Close, vec![date(2024, 2, 28), date(2024, 2, 29), date(2024, 3, 1)])]
+    #[case(date(2023, 2, 28), date(2023, 3, 1), IntervalType::Close, vec![date(2023, 2, 28), date(2023, 3, 1)])]
+    #[case(date(2024, 1, 31), date(2024, 2, 1), IntervalType::Open, vec![])]
+    fn test_dates(
+        #[case] start: NaiveDate,
+        #[case] end: NaiveDate,
+        #[case] interval_type: IntervalType,
+        #[case] expected: Vec<NaiveDate>,
+    ) {
+        let interval = Interval::from_to(start, end, interval_type).unwrap();
+        let mut dates = Vec::new();
+        for date in interval {
+            dates.push(date);
+        }
+        assert_eq!(dates, expected);
+        assert_eq!(interval.count_values(), Some(expected.len() as u128));
+        assert_eq!(interval.first_value(), expected.first().copied());
+        assert_eq!(interval.last_value(), expected.last().copied());
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(date(2024, 1, 31), date(2024, 2, 29), IntervalType::StartOpen).unwrap(),
+           Some(Interval::from_to(date(2024, 2, 1), date(2024, 3, 1), IntervalType::EndOpen).unwrap()))]
+    #[case(Interval::from_to(date(2023, 2, 1), date(2023, 3, 1), IntervalType::EndOpen).unwrap(),
+           Some(Interval::from_to(date(2023, 2, 1), date(2023, 3, 1), IntervalType::EndOpen).unwrap()))]
+    #[case(Interval::from_to(date(2024, 1, 31), date(2024, 2, 1), IntervalType::Open).unwrap(), None)]
+    fn test_canonicalize_dates(
+        #[case] interval: Interval<NaiveDate>,
+        #[case] expected: Option<Interval<NaiveDate>>,
+    ) {
+        assert_eq!(interval.canonicalize(), expected);
+    }
+
+    #[test]
+    fn test_dates_at_calendar_limits() {
+        let last_days = Interval::from_to(
+            NaiveDate::MAX.pred_opt().unwrap(),
+            NaiveDate::MAX,
+            IntervalType::Close,
+        )
+        .unwrap();
+        assert_eq!(
+            last_days.into_iter().collect::<Vec<_>>(),
+            vec![NaiveDate::MAX.pred_opt().unwrap(), NaiveDate::MAX]
+        );
+        assert_eq!(
+            last_days.canonicalize(),
+            Some(last_days),
+            "the last date stays inclusive"
+        );
+        assert_eq!(
+            Interval::since_inclusive(NaiveDate::MAX).into_iter().collect::<Vec<_>>(),
+            vec![NaiveDate::MAX]
+        );
+        assert_eq!(Interval::since_exclusive(NaiveDate::MAX).first_value(), None);
+        assert_eq!(Interval::until_exclusive(NaiveDate::MIN).last_value(), None);
+    }
+
+    #[rstest]
+    #[case(2023, 365)]
+    #[case(2024, 366)]
+    #[case(1900, 365)]
+    #[case(2000, 366)]
+    fn test_count_days_in_year(#[case] year: i32, #[case] expected: u128) {
+        let interval =
+            Interval::from_to(date(year, 1, 1), date(year + 1, 1, 1), IntervalType::EndOpen)
+                .unwrap();
+        assert_eq!(interval.count_values(), Some(expected));
+    }
+
+    #[test]
+    fn test_consecutive_days_are_flush() {
+        let monday = Interval::<DateTime<Utc>>::day_of(date(2024, 3, 4));
+        let tuesday = Interval::<DateTime<Utc>>::day_of(date(2024, 3, 5));
+        assert!(monday.end.meets(&tuesday.start));
+    }
+}
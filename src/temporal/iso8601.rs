@@ -0,0 +1,297 @@
+//! ISO 8601 time interval text, such as `2024-01-01T00:00:00Z/P1M`.
+//!
+//! The date-times are read in the RFC 3339 profile of ISO 8601 and
+//! converted to UTC. Parsed intervals are half-open, `[start, end)`, as
+//! is conventional for ISO 8601 intervals.
+
+use crate::interval::{Interval, IntervalError, IntervalType};
+use chrono::{DateTime, Days, Months, SecondsFormat, TimeDelta, Utc};
+use std::fmt::{self, Display, Formatter};
+
+/// Error returned by [`parse_iso8601`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseIso8601Error {
+    /// The text has no `/` between start and end.
+    MissingSeparator,
+
+    /// A side could not be parsed as an RFC 3339 date-time; holds its text.
+    InvalidDateTime(String),
+
+    /// A side could not be parsed as a duration; holds its text.
+    InvalidDuration(String),
+
+    /// Both sides are durations, which leaves the interval unanchored.
+    MissingDateTime,
+
+    /// Applying the duration leaves the range of `DateTime<Utc>`.
+    OutOfRange,
+
+    /// The end comes before the start.
+    Invalid(IntervalError),
+}
+
+impl Display for ParseIso8601Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseIso8601Error::MissingSeparator => {
+                write!(f, "interval must be written as 'start/end'")
+            }
+            ParseIso8601Error::InvalidDateTime(text) => {
+                write!(f, "invalid RFC 3339 date-time '{}'", text)
+            }
+            ParseIso8601Error::InvalidDuration(text) => write!(
+                f,
+                "invalid duration '{}': only whole numbers of Y, M, W, D, H, M and S, in that order, are supported",
+                text
+            ),
+            ParseIso8601Error::MissingDateTime => {
+                write!(f, "interval needs a start or an end date-time")
+            }
+            ParseIso8601Error::OutOfRange => write!(f, "interval bound out of range"),
+            ParseIso8601Error::Invalid(error) => write!(f, "invalid interval: {:?}", error),
+        }
+    }
+}
+
+impl std::error::Error for ParseIso8601Error {}
+
+/// Parses an ISO 8601 interval in one of the forms `start/end`,
+/// `start/duration` or `duration/end` into `[start, end)`.
+///
+/// Durations are limited to whole numbers of each designator, each at
+/// most once and in the standard order, as in `P1Y2M10DT2H30M` or
+/// `P2W`. Years and months are calendar units:
+/// `2024-01-31T00:00:00Z/P1M` ends on the last day of February.
+pub fn parse_iso8601(text: &str) -> Result<Interval<DateTime<Utc>>, ParseIso8601Error> {
+    let (start, end) = text
+        .trim()
+        .split_once('/')
+        .ok_or(ParseIso8601Error::MissingSeparator)?;
+    let (start, end) = match (start.starts_with('P'), end.starts_with('P')) {
+        (false, false) => (parse_date_time(start)?, parse_date_time(end)?),
+        (false, true) => {
+            let start = parse_date_time(start)?;
+            let end = parse_duration(end)?
+                .add_to(start)
+                .ok_or(ParseIso8601Error::OutOfRange)?;
+            (start, end)
+        }
+        (true, false) => {
+            let end = parse_date_time(end)?;
+            let start = parse_duration(start)?
+                .sub_from(end)
+                .ok_or(ParseIso8601Error::OutOfRange)?;
+            (start, end)
+        }
+        (true, true) => return Err(ParseIso8601Error::MissingDateTime),
+    };
+    Interval::from_to(start, end, IntervalType::EndOpen).map_err(ParseIso8601Error::Invalid)
+}
+
+impl Interval<DateTime<Utc>> {
+    /// Formats the interval as ISO 8601 `start/end`, or returns `None`
+    /// when a side is unbounded.
+    ///
+    /// The text form has no notion of inclusiveness and is read back as
+    /// `[start, end)` by [`parse_iso8601`], whatever the proximities here.
+    pub fn to_iso8601(&self) -> Option<String> {
+        let start = self.start.finite_value()?;
+        let end = self.end.finite_value()?;
+        Some(format!(
+            "{}/{}",
+            start.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            end.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        ))
+    }
+}
+
+fn parse_date_time(text: &str) -> Result<DateTime<Utc>, ParseIso8601Error> {
+    DateTime::parse_from_rfc3339(text)
+        .map(|date_time| date_time.with_timezone(&Utc))
+        .map_err(|_| ParseIso8601Error::InvalidDateTime(text.to_string()))
+}
+
+/// A duration split into its calendar part, whose length depends on
+/// where it is applied, and its exact part.
+#[derive(Debug, Default, PartialEq)]
+struct IsoDuration {
+    months: u32,
+    days: u64,
+    exact: TimeDelta,
+}
+
+impl IsoDuration {
+    fn add_to(&self, date_time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        date_time
+            .checked_add_months(Months::new(self.months))?
+            .checked_add_days(Days::new(self.days))?
+            .checked_add_signed(self.exact)
+    }
+
+    fn sub_from(&self, date_time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        date_time
+            .checked_sub_signed(self.exact)?
+            .checked_sub_days(Days::new(self.days))?
+            .checked_sub_months(Months::new(self.months))
+    }
+}
+
+fn parse_duration(text: &str) -> Result<IsoDuration, ParseIso8601Error> {
+    let invalid = || ParseIso8601Error::InvalidDuration(text.to_string());
+    let designators = text.strip_prefix('P').ok_or_else(invalid)?;
+    let (date_part, time_part) = match designators.split_once('T') {
+        Some((date_part, time_part)) if !time_part.is_empty() => (date_part, Some(time_part)),
+        Some(_) => return Err(invalid()),
+        None => (designators, None),
+    };
+    if date_part.is_empty() && time_part.is_none() {
+        return Err(invalid());
+    }
+
+    let mut duration = IsoDuration::default();
+    for (number, designator) in components(date_part, "YMWD").ok_or_else(invalid)? {
+        let months = |factor: u64| -> Option<u32> {
+            duration
+                .months
+                .checked_add(u32::try_from(number.checked_mul(factor)?).ok()?)
+        };
+        let days = |factor: u64| duration.days.checked_add(number.checked_mul(factor)?);
+        match designator {
+            'Y' => duration.months = months(12).ok_or_else(invalid)?,
+            'M' => duration.months = months(1).ok_or_else(invalid)?,
+            'W' => duration.days = days(7).ok_or_else(invalid)?,
+            'D' => duration.days = days(1).ok_or_else(invalid)?,
+            _ => return Err(invalid()),
+        }
+    }
+    for (number, designator) in components(time_part.unwrap_or(""), "HMS").ok_or_else(invalid)? {
+        let seconds = match designator {
+            'H' => number.checked_mul(3600),
+            'M' => number.checked_mul(60),
+            'S' => Some(number),
+            _ => None,
+        };
+        let delta = seconds
+            .and_then(|seconds| i64::try_from(seconds).ok())
+            .and_then(TimeDelta::try_seconds)
+            .ok_or_else(invalid)?;
+        duration.exact = duration.exact.checked_add(&delta).ok_or_else(invalid)?;
+    }
+    Ok(duration)
+}
+
+/// Splits text like `1Y2M10D` into `(1, 'Y'), (2, 'M'), (10, 'D')`,
+/// or returns `None` unless it is a sequence of whole numbers each
+/// followed by one of `designators`, each at most once and in that order.
+fn components(text: &str, designators: &str) -> Option<Vec<(u64, char)>> {
+    let mut components = Vec::new();
+    let mut rest = text;
+    let mut allowed = designators;
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let number = rest[..digits].parse().ok()?;
+        let designator = rest[digits..].chars().next()?;
+        allowed = &allowed[allowed.find(designator)? + designator.len_utf8()..];
+        components.push((number, designator));
+        rest = &rest[digits + designator.len_utf8()..];
+    }
+    Some(components)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rstest::rstest;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    fn half_open(start: DateTime<Utc>, end: DateTime<Utc>) -> Interval<DateTime<Utc>> {
+        Interval::from_to(start, end, IntervalType::EndOpen).unwrap()
+    }
+
+    #[rstest]
+    #[case(
+        "2024-01-01T00:00:00Z/2024-02-01T00:00:00Z",
+        half_open(utc(2024, 1, 1, 0, 0), utc(2024, 2, 1, 0, 0))
+    )]
+    #[case(
+        "2024-01-01T00:00:00Z/P1M",
+        half_open(utc(2024, 1, 1, 0, 0), utc(2024, 2, 1, 0, 0))
+    )]
+    #[case(
+        "P1M/2024-02-01T00:00:00Z",
+        half_open(utc(2024, 1, 1, 0, 0), utc(2024, 2, 1, 0, 0))
+    )]
+    // calendar months clamp to the end of shorter months
+    #[case(
+        "2024-01-31T00:00:00Z/P1M",
+        half_open(utc(2024, 1, 31, 0, 0), utc(2024, 2, 29, 0, 0))
+    )]
+    #[case(
+        "2024-03-01T09:00:00Z/PT1H30M",
+        half_open(utc(2024, 3, 1, 9, 0), utc(2024, 3, 1, 10, 30))
+    )]
+    #[case("2024-03-01T09:00:00Z/P1Y2M3DT4H5M6S", half_open(utc(2024, 3, 1, 9, 0), utc(2025, 5, 4, 13, 5) + TimeDelta::seconds(6)))]
+    #[case(
+        "2024-03-01T00:00:00Z/P2W",
+        half_open(utc(2024, 3, 1, 0, 0), utc(2024, 3, 15, 0, 0))
+    )]
+    // offsets are converted to UTC
+    #[case(
+        "2024-03-01T09:00:00+02:00/2024-03-01T10:00:00+02:00",
+        half_open(utc(2024, 3, 1, 7, 0), utc(2024, 3, 1, 8, 0))
+    )]
+    #[case(
+        " 2024-03-01T09:00:00Z/PT0S ",
+        half_open(utc(2024, 3, 1, 9, 0), utc(2024, 3, 1, 9, 0))
+    )]
+    fn test_parse_iso8601(#[case] text: &str, #[case] expected: Interval<DateTime<Utc>>) {
+        assert_eq!(parse_iso8601(text), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("2024-01-01T00:00:00Z", ParseIso8601Error::MissingSeparator)]
+    #[case("2024-01-01/P1D", ParseIso8601Error::InvalidDateTime("2024-01-01".to_string()))]
+    #[case("2024-01-01T00:00:00Z/P1.5M", ParseIso8601Error::InvalidDuration("P1.5M".to_string()))]
+    #[case("2024-01-01T00:00:00Z/P", ParseIso8601Error::InvalidDuration("P".to_string()))]
+    #[case("2024-01-01T00:00:00Z/P1DT", ParseIso8601Error::InvalidDuration("P1DT".to_string()))]
+    #[case("2024-01-01T00:00:00Z/P1H", ParseIso8601Error::InvalidDuration("P1H".to_string()))]
+    #[case("2024-01-01T00:00:00Z/P1D1Y", ParseIso8601Error::InvalidDuration("P1D1Y".to_string()))]
+    #[case("2024-01-01T00:00:00Z/PT1S1M", ParseIso8601Error::InvalidDuration("PT1S1M".to_string()))]
+    #[case("2024-01-01T00:00:00Z/P1D18446744073709551615D", ParseIso8601Error::InvalidDuration("P1D18446744073709551615D".to_string()))]
+    #[case("2024-01-01T00:00:00Z/P1W18446744073709551615D", ParseIso8601Error::InvalidDuration("P1W18446744073709551615D".to_string()))]
+    #[case("P1D/P1D", ParseIso8601Error::MissingDateTime)]
+    #[case(
+        "2024-02-01T00:00:00Z/2024-01-01T00:00:00Z",
+        ParseIso8601Error::Invalid(IntervalError::StartMustBeMinorThanEnd)
+    )]
+    #[case("2024-01-01T00:00:00Z/P999999999Y", ParseIso8601Error::InvalidDuration("P999999999Y".to_string()))]
+    #[case("2024-01-01T00:00:00Z/P99999999D", ParseIso8601Error::OutOfRange)]
+    fn test_parse_iso8601_errors(#[case] text: &str, #[case] expected: ParseIso8601Error) {
+        assert_eq!(parse_iso8601(text), Err(expected));
+    }
+
+    #[test]
+    fn test_invalid_duration_message_names_supported_subset() {
+        let error = parse_iso8601("2024-01-01T00:00:00Z/P0.5Y").unwrap_err();
+        assert!(error.to_string().contains("whole numbers"));
+    }
+
+    #[rstest]
+    #[case(
+        half_open(utc(2024, 1, 1, 0, 0), utc(2024, 2, 1, 0, 0)),
+        Some("2024-01-01T00:00:00Z/2024-02-01T00:00:00Z")
+    )]
+    #[case(Interval::since_inclusive(utc(2024, 1, 1, 0, 0)), None)]
+    fn test_to_iso8601(#[case] interval: Interval<DateTime<Utc>>, #[case] expected: Option<&str>) {
+        let text = interval.to_iso8601();
+        assert_eq!(text.as_deref(), expected);
+        if let Some(text) = text {
+            assert_eq!(parse_iso8601(&text), Ok(interval));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Duration arithmetic for intervals over date and time types.
+//!
+//! The interval math is written once against [`TemporalPoint`]; the
+//! `chrono` and `time` features only provide the adapter impls and the
+//! calendar constructors specific to each crate.
+
+use crate::bound_point::BoundPoint;
+use crate::interval::{Interval, IntervalError, IntervalType};
+
+#[cfg(feature = "chrono")]
+mod chrono_impl;
+#[cfg(feature = "chrono")]
+mod iso8601;
+#[cfg(feature = "time")]
+mod time_impl;
+
+#[cfg(feature = "chrono")]
+pub use iso8601::{parse_iso8601, ParseIso8601Error};
+pub(crate) use sealed::Sealed;
+
+mod sealed {
+    /// Keeps [`TemporalPoint`](super::TemporalPoint) implementable only
+    /// within this crate.
+    pub trait Sealed {}
+}
+
+/// A point in time that a duration can be added to, and that yields
+/// a duration when subtracted from another point.
+///
+/// The trait is sealed: it names the types the interval methods below
+/// accept, and only the `chrono` and `time` adapters in this crate
+/// implement it.
+pub trait TemporalPoint: sealed::Sealed + Ord + Clone {
+    type Duration: Copy;
+
+    /// Returns the point `duration` after this one.
+    ///
+    /// Panics if the result leaves the representable range.
+    fn add_duration(&self, duration: Self::Duration) -> Self;
+
+    /// Returns the duration from `earlier` to this point.
+    fn diff(&self, earlier: &Self) -> Self::Duration;
+}
+
+impl<T: TemporalPoint> Interval<T> {
+    /// Returns the half-open interval `[start, start + duration)`.
+    ///
+    /// Fails when `duration` is negative.
+    pub fn for_duration(start: T, duration: T::Duration) -> Result<Self, IntervalError> {
+        let end = start.add_duration(duration);
+        Interval::from_to(start, end, IntervalType::EndOpen)
+    }
+
+    /// Returns the time elapsed from the start to the end value,
+    /// or `None` when either side is unbounded.
+    pub fn duration(&self) -> Option<T::Duration> {
+        let start = self.start.finite_value()?;
+        let end = self.end.finite_value()?;
+        Some(end.diff(start))
+    }
+
+    /// Moves both bounds by `duration`, keeping their inclusiveness.
+    ///
+    /// Panics if a bound leaves the representable range, as adding
+    /// the duration to the value directly would.
+    pub fn shift_by(&self, duration: T::Duration) -> Self {
+        Interval {
+            start: self.start.clone().map(|value| value.add_duration(duration)),
+            end: self.end.clone().map(|value| value.add_duration(duration)),
+        }
+    }
+}
+
+/// `[start, end)`, or `[start, +inf)` at the end of the calendar.
+fn half_open<T: Ord>(start: T, end: Option<T>) -> Interval<T> {
+    Interval {
+        start: BoundPoint::at(start),
+        end: end.map_or_else(BoundPoint::pos_infinity, BoundPoint::before),
+    }
+}
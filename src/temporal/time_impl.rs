@@ -0,0 +1,238 @@
+//! [`TemporalPoint`] for `OffsetDateTime`, `PrimitiveDateTime` and `Date`,
+//! and calendar constructors building canonical half-open intervals
+//! for `OffsetDateTime` in UTC and `PrimitiveDateTime`.
+//!
+//! A `PrimitiveDateTime` carries no offset, with the same wall-clock
+//! caveats as chrono's `NaiveDateTime`.
+
+use crate::interval::Interval;
+use crate::temporal::{half_open, Sealed, TemporalPoint};
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime};
+
+impl Sealed for OffsetDateTime {}
+
+impl TemporalPoint for OffsetDateTime {
+    type Duration = Duration;
+
+    fn add_duration(&self, duration: Duration) -> Self {
+        *self + duration
+    }
+
+    fn diff(&self, earlier: &Self) -> Duration {
+        *self - *earlier
+    }
+}
+
+impl Sealed for PrimitiveDateTime {}
+
+impl TemporalPoint for PrimitiveDateTime {
+    type Duration = Duration;
+
+    fn add_duration(&self, duration: Duration) -> Self {
+        *self + duration
+    }
+
+    fn diff(&self, earlier: &Self) -> Duration {
+        *self - *earlier
+    }
+}
+
+impl Sealed for Date {}
+
+impl TemporalPoint for Date {
+    type Duration = Duration;
+
+    fn add_duration(&self, duration: Duration) -> Self {
+        *self + duration
+    }
+
+    fn diff(&self, earlier: &Self) -> Duration {
+        *self - *earlier
+    }
+}
+
+impl Interval<OffsetDateTime> {
+    /// Returns `[00:00 of date, 00:00 of the next day)` in UTC.
+    pub fn day_of(date: Date) -> Self {
+        let (start, end) = day_bounds(date);
+        half_open(start.assume_utc(), end.map(PrimitiveDateTime::assume_utc))
+    }
+
+    /// Returns the UTC interval from the first of the month containing
+    /// `date` up to, but excluding, the first of the following month.
+    pub fn month_of(date: Date) -> Self {
+        let (start, end) = month_bounds(date);
+        half_open(start.assume_utc(), end.map(PrimitiveDateTime::assume_utc))
+    }
+}
+
+impl Interval<PrimitiveDateTime> {
+    /// Returns `[00:00 of date, 00:00 of the next day)`.
+    pub fn day_of(date: Date) -> Self {
+        let (start, end) = day_bounds(date);
+        half_open(start, end)
+    }
+
+    /// Returns the interval from the first of the month containing `date`
+    /// up to, but excluding, the first of the following month.
+    pub fn month_of(date: Date) -> Self {
+        let (start, end) = month_bounds(date);
+        half_open(start, end)
+    }
+}
+
+/// Midnight of `date` and of the following day, if representable.
+fn day_bounds(date: Date) -> (PrimitiveDateTime, Option<PrimitiveDateTime>) {
+    (date.midnight(), date.next_day().map(Date::midnight))
+}
+
+/// Midnight of the first of the month and of the following month,
+/// if representable.
+fn month_bounds(date: Date) -> (PrimitiveDateTime, Option<PrimitiveDateTime>) {
+    let first = date.replace_day(1).expect("every month has a first day");
+    let (year, month) = match date.month() {
+        time::Month::December => (date.year() + 1, time::Month::January),
+        month => (date.year(), month.next()),
+    };
+    let end = Date::from_calendar_date(year, month, 1).ok();
+    (first.midnight(), end.map(Date::midnight))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::{IntervalError, IntervalType};
+    use rstest::rstest;
+    use time::Month;
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    fn utc(year: i32, month: Month, day: u8, hour: u8) -> OffsetDateTime {
+        date(year, month, day)
+            .with_hms(hour, 0, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    #[test]
+    fn test_for_duration() {
+        let interval =
+            Interval::for_duration(utc(2024, Month::March, 1, 9), Duration::hours(2)).unwrap();
+        assert_eq!(
+            interval,
+            Interval::from_to(
+                utc(2024, Month::March, 1, 9),
+                utc(2024, Month::March, 1, 11),
+                IntervalType::EndOpen
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            Interval::for_duration(utc(2024, Month::March, 1, 9), Duration::hours(-1)),
+            Err(IntervalError::StartMustBeMinorThanEnd)
+        );
+    }
+
+    #[rstest]
+    #[case(Interval::from_to(utc(2024, Month::March, 1, 9), utc(2024, Month::March, 2, 11), IntervalType::Close).unwrap(), Some(Duration::hours(26)))]
+    #[case(Interval::since_inclusive(utc(2024, Month::March, 1, 9)), None)]
+    fn test_duration(
+        #[case] interval: Interval<OffsetDateTime>,
+        #[case] expected: Option<Duration>,
+    ) {
+        assert_eq!(interval.duration(), expected);
+    }
+
+    #[test]
+    fn test_date_points() {
+        let week = Interval::for_duration(date(2024, Month::March, 4), Duration::days(7)).unwrap();
+        assert_eq!(week.duration(), Some(Duration::days(7)));
+        assert!(week.contains(date(2024, Month::March, 10)));
+        assert!(!week.contains(date(2024, Month::March, 11)));
+    }
+
+    #[test]
+    fn test_shift_by_keeps_inclusiveness() {
+        let interval = Interval::from_to(
+            utc(2024, Month::March, 1, 9),
+            utc(2024, Month::March, 1, 17),
+            IntervalType::StartOpen,
+        )
+        .unwrap();
+        assert_eq!(
+            interval.shift_by(Duration::days(1)),
+            Interval::from_to(
+                utc(2024, Month::March, 2, 9),
+                utc(2024, Month::March, 2, 17),
+                IntervalType::StartOpen
+            )
+            .unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(
+        date(2024, Month::February, 29),
+        utc(2024, Month::February, 29, 0),
+        utc(2024, Month::March, 1, 0)
+    )]
+    #[case(
+        date(2024, Month::December, 31),
+        utc(2024, Month::December, 31, 0),
+        utc(2025, Month::January, 1, 0)
+    )]
+    fn test_day_of(#[case] date: Date, #[case] start: OffsetDateTime, #[case] end: OffsetDateTime) {
+        let day = Interval::<OffsetDateTime>::day_of(date);
+        assert_eq!(
+            day,
+            Interval::from_to(start, end, IntervalType::EndOpen).unwrap()
+        );
+        assert_eq!(day.duration(), Some(Duration::days(1)));
+        assert_eq!(
+            Interval::<PrimitiveDateTime>::day_of(date),
+            Interval::from_to(
+                PrimitiveDateTime::new(start.date(), start.time()),
+                PrimitiveDateTime::new(end.date(), end.time()),
+                IntervalType::EndOpen
+            )
+            .unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(
+        date(2024, Month::February, 14),
+        utc(2024, Month::February, 1, 0),
+        utc(2024, Month::March, 1, 0),
+        29
+    )]
+    #[case(
+        date(2024, Month::December, 1),
+        utc(2024, Month::December, 1, 0),
+        utc(2025, Month::January, 1, 0),
+        31
+    )]
+    fn test_month_of(
+        #[case] date: Date,
+        #[case] start: OffsetDateTime,
+        #[case] end: OffsetDateTime,
+        #[case] days: i64,
+    ) {
+        let month = Interval::<OffsetDateTime>::month_of(date);
+        assert_eq!(
+            month,
+            Interval::from_to(start, end, IntervalType::EndOpen).unwrap()
+        );
+        assert_eq!(month.duration(), Some(Duration::days(days)));
+    }
+
+    #[test]
+    fn test_last_representable_day_is_unbounded() {
+        assert_eq!(
+            Interval::<PrimitiveDateTime>::day_of(Date::MAX),
+            Interval::since_inclusive(Date::MAX.midnight())
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Helpers shared by the unit tests of several modules.
+
+use crate::bound_point::{BoundPoint, BoundProximity};
+use crate::interval::{Interval, IntervalType};
+use crate::interval_set::IntervalSet;
+
+/// A small deterministic xorshift generator, so randomized tests
+/// are reproducible without pulling in an external crate.
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Rng(seed.max(1))
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a value in `low..high`.
+    pub(crate) fn range(&mut self, low: i32, high: i32) -> i32 {
+        low + (self.next_u64() % (high - low) as u64) as i32
+    }
+
+    pub(crate) fn interval_type(&mut self) -> IntervalType {
+        match self.next_u64() % 4 {
+            0 => IntervalType::Open,
+            1 => IntervalType::StartOpen,
+            2 => IntervalType::EndOpen,
+            _ => IntervalType::Close,
+        }
+    }
+
+    /// Returns an interval of any shape, including unbounded and empty
+    /// ones, with finite bounds in `low..high`.
+    pub(crate) fn interval(&mut self, low: i32, high: i32) -> Interval<i32> {
+        let a = self.range(low, high);
+        let b = self.range(low, high);
+        let (start, end) = (a.min(b), a.max(b));
+        match self.next_u64() % 10 {
+            0 => Interval::until_exclusive(end),
+            1 => Interval::until_inclusive(end),
+            2 => Interval::since_exclusive(start),
+            3 => Interval::since_inclusive(start),
+            _ => Interval::from_to(start, end, self.interval_type()).unwrap(),
+        }
+    }
+}
+
+/// Whether `interval` contains the value `half_steps / 2`. Probing every
+/// integer `half_steps` in a range therefore also probes the gaps between
+/// integer bounds, which tells `(1, 2)` apart from an empty interval.
+pub(crate) fn contains_half_step(interval: &Interval<i32>, half_steps: i32) -> bool {
+    let doubled = Interval {
+        start: doubled(&interval.start),
+        end: doubled(&interval.end),
+    };
+    doubled.contains(half_steps)
+}
+
+fn doubled(point: &BoundPoint<i32>) -> BoundPoint<i32> {
+    match (point.finite_value(), point.proximity()) {
+        (Some(value), Some(BoundProximity::Before)) => BoundPoint::before(value * 2),
+        (Some(value), Some(BoundProximity::At)) => BoundPoint::at(value * 2),
+        (Some(value), _) => BoundPoint::after(value * 2),
+        (None, _) if *point == BoundPoint::neg_infinity() => BoundPoint::neg_infinity(),
+        (None, _) => BoundPoint::pos_infinity(),
+    }
+}
+
+/// Builds a set by inserting `members` one at a time.
+pub(crate) fn set(members: Vec<Interval<i32>>) -> IntervalSet<i32> {
+    let mut set = IntervalSet::new();
+    for member in members {
+        set.insert(member);
+    }
+    set
+}
+
+pub(crate) fn interval(start: i32, end: i32, interval_type: IntervalType) -> Interval<i32> {
+    Interval::from_to(start, end, interval_type).unwrap()
+}
+
+pub(crate) fn closed(start: i32, end: i32) -> Interval<i32> {
+    interval(start, end, IntervalType::Close)
+}
+
+pub(crate) fn end_open(start: i32, end: i32) -> Interval<i32> {
+    interval(start, end, IntervalType::EndOpen)
+}
+
+pub(crate) fn open(start: i32, end: i32) -> Interval<i32> {
+    interval(start, end, IntervalType::Open)
+}
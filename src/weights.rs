@@ -0,0 +1,212 @@
+use crate::bound_point::BoundPoint;
+use crate::interval::{Interval, IntervalError};
+
+/// Splitting an interval of integers into contiguous pieces whose
+/// lengths follow a list of weights, for proration.
+///
+/// Lengths are computed in `i128`, which holds the product of any
+/// 64-bit length and `u32` weight exactly. Only an `i128` interval can
+/// exceed it, and then fails with [`IntervalError::Overflow`].
+impl<T> Interval<T>
+where
+    T: Ord + Copy + Into<i128> + TryFrom<i128>,
+{
+    /// Splits the interval into one piece per weight, in order, the
+    /// length of each proportional to its weight, so that the pieces
+    /// exactly tile the interval: no gap, no overlap, and lengths adding
+    /// up to the length of the interval.
+    ///
+    /// Exact shares are rarely integers, so lengths are rounded with the
+    /// largest remainder method: each piece first gets its share rounded
+    /// down, then the units left over go one each to the pieces with the
+    /// largest fractional remainders, ties going to the earlier piece.
+    /// `[0, 10)` split by `[1, 1, 1]` gives lengths `4, 3, 3`.
+    ///
+    /// Pieces meet at half-open cuts such as `[0, 4)` and `[4, 7)`. The
+    /// first piece of positive length keeps the start bound of the
+    /// interval and the last one its end bound, so an exclusive start or
+    /// inclusive end is kept. Every other piece of zero length, including
+    /// the piece of each zero weight, is the empty interval `[c, c)` at
+    /// its cut `c`, so pieces stay aligned with their weights by index.
+    /// An interval of zero length, such as `[5, 5]`, goes whole to the
+    /// first piece of positive weight.
+    ///
+    /// Fails with [`IntervalError::Unbounded`] when the interval is
+    /// unbounded, with [`IntervalError::NoWeight`] when no weight is
+    /// positive, and with [`IntervalError::Overflow`] when the length of
+    /// the interval times a weight does not fit an `i128`.
+    pub fn split_by_weights(&self, weights: &[u32]) -> Result<Vec<Interval<T>>, IntervalError> {
+        let (Some(&start), Some(&end)) = (self.start.finite_value(), self.end.finite_value())
+        else {
+            return Err(IntervalError::Unbounded);
+        };
+        let total: i128 = weights.iter().map(|&weight| i128::from(weight)).sum();
+        if total == 0 {
+            return Err(IntervalError::NoWeight);
+        }
+        let length = end
+            .into()
+            .checked_sub(start.into())
+            .ok_or(IntervalError::Overflow)?;
+
+        let mut shares: Vec<i128> = Vec::with_capacity(weights.len());
+        let mut remainders: Vec<(i128, usize)> = Vec::with_capacity(weights.len());
+        for (index, &weight) in weights.iter().enumerate() {
+            let product = length
+                .checked_mul(i128::from(weight))
+                .ok_or(IntervalError::Overflow)?;
+            shares.push(product / total);
+            remainders.push((product % total, index));
+        }
+        let left_over = length - shares.iter().sum::<i128>();
+        remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        for &(_, index) in remainders.iter().take(left_over as usize) {
+            shares[index] += 1;
+        }
+
+        // the pieces keeping the bounds of the interval; when it has no
+        // length, a single piece takes it whole
+        let (first, last) = if length == 0 {
+            let owner = weights.iter().position(|&weight| weight > 0);
+            (owner, owner)
+        } else {
+            (
+                shares.iter().position(|&share| share > 0),
+                shares.iter().rposition(|&share| share > 0),
+            )
+        };
+        let mut cut = start.into();
+        let mut pieces = Vec::with_capacity(weights.len());
+        for (index, share) in shares.into_iter().enumerate() {
+            let from = value_at(cut);
+            cut += share;
+            let to = value_at(cut);
+            pieces.push(Interval {
+                start: if first == Some(index) {
+                    self.start
+                } else {
+                    BoundPoint::at(from)
+                },
+                end: if last == Some(index) {
+                    self.end
+                } else {
+                    BoundPoint::before(to)
+                },
+            });
+        }
+        Ok(pieces)
+    }
+}
+
+/// Converts a cut back to `T`; every cut lies between the bounds.
+fn value_at<T: TryFrom<i128>>(cut: i128) -> T {
+    match T::try_from(cut) {
+        Ok(value) => value,
+        Err(_) => unreachable!("cuts lie between the bounds of the interval"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::IntervalType;
+    use crate::test_support::{contains_half_step, end_open, Rng};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(end_open(0, 10), &[1, 1, 1], vec![end_open(0, 4), end_open(4, 7), end_open(7, 10)])]
+    #[case(end_open(0, 10), &[3, 1], vec![end_open(0, 8), end_open(8, 10)])]
+    #[case(end_open(0, 10), &[1, 0, 1], vec![end_open(0, 5), end_open(5, 5), end_open(5, 10)])]
+    #[case(end_open(0, 10), &[0, 1], vec![end_open(0, 0), end_open(0, 10)])]
+    #[case(end_open(0, 2), &[1, 1, 1], vec![end_open(0, 1), end_open(1, 2), end_open(2, 2)])]
+    #[case(Interval::from_to(0, 10, IntervalType::Close).unwrap(), &[1, 1, 0],
+           vec![end_open(0, 5), Interval::from_to(5, 10, IntervalType::Close).unwrap(), end_open(10, 10)])]
+    #[case(Interval::from_to(0, 10, IntervalType::Open).unwrap(), &[1],
+           vec![Interval::from_to(0, 10, IntervalType::Open).unwrap()])]
+    #[case(Interval::from_to(5, 5, IntervalType::Close).unwrap(), &[0, 1, 1],
+           vec![end_open(5, 5), Interval::from_to(5, 5, IntervalType::Close).unwrap(), end_open(5, 5)])]
+    #[case(Interval::from_to(0, 1, IntervalType::StartOpen).unwrap(), &[0, 2, 3, 0],
+           vec![end_open(0, 0), end_open(0, 0), Interval::from_to(0, 1, IntervalType::StartOpen).unwrap(), end_open(1, 1)])]
+    fn test_split_by_weights(
+        #[case] interval: Interval<i32>,
+        #[case] weights: &[u32],
+        #[case] expected: Vec<Interval<i32>>,
+    ) {
+        assert_eq!(interval.split_by_weights(weights), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(Interval::since_inclusive(0), &[1], IntervalError::Unbounded)]
+    #[case(Interval::until_exclusive(0), &[1], IntervalError::Unbounded)]
+    #[case(end_open(0, 10), &[], IntervalError::NoWeight)]
+    #[case(end_open(0, 10), &[0, 0], IntervalError::NoWeight)]
+    fn test_split_by_weights_errors(
+        #[case] interval: Interval<i32>,
+        #[case] weights: &[u32],
+        #[case] expected: IntervalError,
+    ) {
+        assert_eq!(interval.split_by_weights(weights), Err(expected));
+    }
+
+    #[test]
+    fn test_split_by_weights_at_type_limits() {
+        let whole = Interval::from_to(i64::MIN, i64::MAX, IntervalType::Close).unwrap();
+        let pieces = whole.split_by_weights(&[u32::MAX, 1, u32::MAX]).unwrap();
+        assert_eq!(pieces[0].start, BoundPoint::at(i64::MIN));
+        assert_eq!(pieces[2].end, BoundPoint::at(i64::MAX));
+        let total: i128 = pieces
+            .iter()
+            .map(|piece| i128::from(piece.length().unwrap()))
+            .sum();
+        assert_eq!(total, i128::from(i64::MAX) - i128::from(i64::MIN));
+    }
+
+    #[test]
+    fn test_split_by_weights_i128_overflow() {
+        let whole = Interval::from_to(i128::MIN, i128::MAX, IntervalType::Close).unwrap();
+        assert_eq!(whole.split_by_weights(&[1]), Err(IntervalError::Overflow));
+        let half = Interval::from_to(0, i128::MAX, IntervalType::Close).unwrap();
+        assert_eq!(half.split_by_weights(&[2, 1]), Err(IntervalError::Overflow));
+        assert_eq!(half.split_by_weights(&[1]), Ok(vec![half]));
+    }
+
+    #[test]
+    fn test_split_by_weights_tiles_exactly() {
+        let mut rng = Rng::new(420);
+        for _ in 0..1000 {
+            let start = rng.range(-20, 20);
+            let end = rng.range(start, 30);
+            let interval = Interval::from_to(start, end, rng.interval_type()).unwrap();
+            let weights: Vec<u32> = (0..rng.range(1, 6))
+                .map(|_| rng.range(0, 4) as u32)
+                .collect();
+            let Ok(pieces) = interval.split_by_weights(&weights) else {
+                assert!(weights.iter().all(|&weight| weight == 0));
+                continue;
+            };
+            assert_eq!(pieces.len(), weights.len());
+            let lengths: Vec<i32> = pieces.iter().map(|piece| piece.length().unwrap()).collect();
+            assert_eq!(lengths.iter().sum::<i32>(), end - start);
+
+            // every length is its exact share rounded down or up
+            let total: u32 = weights.iter().sum();
+            for (length, &weight) in lengths.iter().zip(&weights) {
+                let exact = f64::from(end - start) * f64::from(weight) / f64::from(total);
+                assert!((f64::from(*length) - exact).abs() < 1.0, "{:?}", weights);
+            }
+
+            // every value of the interval lies in exactly one piece
+            for half_steps in 2 * start - 2..=2 * end + 2 {
+                let owners = pieces
+                    .iter()
+                    .filter(|piece| contains_half_step(piece, half_steps))
+                    .count();
+                let expected = usize::from(contains_half_step(&interval, half_steps));
+                assert_eq!(owners, expected, "{:?}, {:?}", interval, weights);
+            }
+            for (piece, &weight) in pieces.iter().zip(&weights) {
+                assert!(weight > 0 || piece.is_empty());
+            }
+        }
+    }
+}